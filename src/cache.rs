@@ -4,6 +4,8 @@ use std::fs;
 use std::io;
 use std::path::PathBuf;
 
+use crate::error::{Error, Result};
+
 /// Cache manifest tracking downloaded state data
 #[derive(Serialize, Deserialize, Default)]
 pub struct CacheManifest {
@@ -100,6 +102,25 @@ pub fn list_cached_states() -> io::Result<Vec<(String, StateCache)>> {
     Ok(states)
 }
 
+/// Resolves a `--state` argument into a list of state codes.
+///
+/// Accepts a comma-separated list of codes, or `all` to select every
+/// state currently in the cache.
+pub fn resolve_states(state_input: &str) -> Result<Vec<String>> {
+    if state_input.to_lowercase() == "all" {
+        let cached = list_cached_states()?;
+        if cached.is_empty() {
+            return Err(Error::NoCachedStates);
+        }
+        return Ok(cached.into_iter().map(|(state, _)| state).collect());
+    }
+
+    Ok(state_input
+        .split(',')
+        .map(|s| s.trim().to_string())
+        .collect())
+}
+
 /// Extracts the region name from a URL (e.g., "us_south" from the URL)
 fn extract_region_name(region_url: &str) -> String {
     region_url
@@ -131,7 +152,6 @@ pub enum CachedRegion {
 }
 
 /// Checks if a regional data is cached (either as ZIP or directory)
-#[allow(dead_code)]
 pub fn is_region_cached(region_url: &str) -> io::Result<bool> {
     Ok(get_cached_region(region_url)?.is_some())
 }
@@ -160,7 +180,6 @@ pub fn save_region_zip(region_url: &str, data: &[u8]) -> io::Result<PathBuf> {
 }
 
 /// Loads a regional ZIP file from the cache
-#[allow(dead_code)]
 pub fn load_region_zip(region_url: &str) -> io::Result<Vec<u8>> {
     let zip_path = get_region_zip_path(region_url)?;
     fs::read(zip_path)
@@ -288,6 +307,12 @@ mod tests {
         assert!(!result.unwrap());
     }
 
+    #[test]
+    fn test_resolve_states_comma_separated() {
+        let states = resolve_states("IL, CA,TX").unwrap();
+        assert_eq!(states, vec!["IL", "CA", "TX"]);
+    }
+
     #[test]
     fn test_state_cache_path_contains_state_code() {
         let path = get_state_cache_path("NY").unwrap();
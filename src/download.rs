@@ -2,13 +2,13 @@ use crate::cache::{self, CachedRegion, StateCache};
 use crate::generators::addresses::Address;
 use crate::regions;
 use rand::seq::SliceRandom;
+use rand::Rng;
 use std::fs::{self, File};
 use std::io::{self, Cursor, Read, Write};
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 /// Default limit for addresses per state
-#[allow(dead_code)]
 pub const DEFAULT_LIMIT: usize = 10_000;
 
 /// Downloads address data for specified states from OpenAddresses.io.
@@ -18,15 +18,17 @@ pub const DEFAULT_LIMIT: usize = 10_000;
 /// * `limit` - Maximum number of addresses per state
 /// * `force` - If true, re-download even if already cached
 /// * `quiet` - If true, suppress progress output
+/// * `rng` - Random number generator used to sample addresses per state
 ///
 /// # Returns
 /// * `Ok(())` - If all downloads succeeded
 /// * `Err(io::Error)` - If validation or download failed
-pub fn download_states(
+pub fn download_states<R: Rng + ?Sized>(
     states: &[String],
     limit: usize,
     force: bool,
     quiet: bool,
+    rng: &mut R,
 ) -> io::Result<()> {
     // Validate all states first
     for state in states {
@@ -65,9 +67,9 @@ pub fn download_states(
         return Ok(());
     }
 
-    // Group states by region to minimize downloads
-    let mut regions_map: std::collections::HashMap<&'static str, Vec<String>> =
-        std::collections::HashMap::new();
+    // Group states by region to minimize downloads (ordered so seeded runs are reproducible)
+    let mut regions_map: std::collections::BTreeMap<&'static str, Vec<String>> =
+        std::collections::BTreeMap::new();
 
     for state in &states_to_download {
         if let Some(region_url) = regions::get_region_url(state) {
@@ -95,13 +97,13 @@ pub fn download_states(
                         println!("Using cached ZIP: {}", zip_path.display());
                     }
                     let zip_data = fs::read(zip_path)?;
-                    extract_state_from_zip(&zip_data, &state, limit)?
+                    extract_state_from_zip(&zip_data, &state, limit, rng)?
                 }
                 Some(CachedRegion::Directory(dir_path)) => {
                     if !quiet {
                         println!("Using cached directory: {}", dir_path.display());
                     }
-                    extract_state_from_directory(dir_path, &state, limit)?
+                    extract_state_from_directory(dir_path, &state, limit, rng)?
                 }
                 None => {
                     if !quiet {
@@ -118,7 +120,7 @@ pub fn download_states(
                         println!("Cached regional ZIP to: {}", zip_path.display());
                     }
 
-                    extract_state_from_zip(&zip_data, &state, limit)?
+                    extract_state_from_zip(&zip_data, &state, limit, rng)?
                 }
             };
 
@@ -173,7 +175,11 @@ pub fn print_cache_list() -> io::Result<()> {
         println!("  https://data.openaddresses.io/openaddr-collected-us_midwest.zip");
         println!("  https://data.openaddresses.io/openaddr-collected-us_west.zip");
         println!("\nThen place in the cache directory as ZIP or extracted folder:");
-        println!("  {}/us_south.zip  OR  {}/us_south/", cache_dir.display(), cache_dir.display());
+        println!(
+            "  {}/us_south.zip  OR  {}/us_south/",
+            cache_dir.display(),
+            cache_dir.display()
+        );
         println!("  (If your browser auto-extracts, the folder works too!)");
         return Ok(());
     }
@@ -289,7 +295,12 @@ fn download_region(url: &str, quiet: bool) -> io::Result<Vec<u8>> {
 }
 
 /// Extracts addresses for a specific state from a regional ZIP file.
-fn extract_state_from_zip(zip_data: &[u8], state: &str, limit: usize) -> io::Result<Vec<Address>> {
+fn extract_state_from_zip<R: Rng + ?Sized>(
+    zip_data: &[u8],
+    state: &str,
+    limit: usize,
+    rng: &mut R,
+) -> io::Result<Vec<Address>> {
     let cursor = Cursor::new(zip_data);
     let mut archive = zip::ZipArchive::new(cursor)
         .map_err(|e| io::Error::other(format!("Invalid zip file: {}", e)))?;
@@ -326,15 +337,16 @@ fn extract_state_from_zip(zip_data: &[u8], state: &str, limit: usize) -> io::Res
         }
     }
 
-    shuffle_and_limit(&mut all_addresses, limit);
+    shuffle_and_limit(&mut all_addresses, limit, rng);
     Ok(all_addresses)
 }
 
 /// Extracts addresses for a specific state from an extracted regional directory.
-fn extract_state_from_directory(
+fn extract_state_from_directory<R: Rng + ?Sized>(
     dir_path: &Path,
     state: &str,
     limit: usize,
+    rng: &mut R,
 ) -> io::Result<Vec<Address>> {
     let state_lower = state.to_lowercase();
     let state_upper = state.to_uppercase();
@@ -374,12 +386,14 @@ fn extract_state_from_directory(
 
     let mut all_addresses: Vec<Address> = Vec::new();
 
-    // Read all CSV files in the state directory
-    for entry in fs::read_dir(&state_dir)? {
-        let entry = entry?;
-        let path = entry.path();
+    // Read all CSV files in the state directory, in a stable order
+    let mut paths = fs::read_dir(&state_dir)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<io::Result<Vec<PathBuf>>>()?;
+    paths.sort();
 
-        if path.is_file() && path.extension().map_or(false, |e| e == "csv") {
+    for path in paths {
+        if path.is_file() && path.extension().is_some_and(|e| e == "csv") {
             let contents = fs::read_to_string(&path)?;
             let mut addresses = parse_openaddresses_csv(&contents)?;
             // Fill in state if missing (OpenAddresses data often omits it)
@@ -392,7 +406,7 @@ fn extract_state_from_directory(
         }
     }
 
-    shuffle_and_limit(&mut all_addresses, limit);
+    shuffle_and_limit(&mut all_addresses, limit, rng);
     Ok(all_addresses)
 }
 
@@ -424,9 +438,8 @@ fn find_state_directory(base: &Path, state: &str) -> io::Result<Option<PathBuf>>
 }
 
 /// Shuffles addresses and truncates to limit.
-fn shuffle_and_limit(addresses: &mut Vec<Address>, limit: usize) {
-    let mut rng = rand::thread_rng();
-    addresses.shuffle(&mut rng);
+fn shuffle_and_limit<R: Rng + ?Sized>(addresses: &mut Vec<Address>, limit: usize, rng: &mut R) {
+    addresses.shuffle(rng);
 
     if addresses.len() > limit {
         addresses.truncate(limit);
@@ -0,0 +1,88 @@
+use std::fmt;
+use std::io;
+
+/// Errors returned by the rust-faker library.
+#[derive(Debug)]
+pub enum Error {
+    /// An I/O failure while reading input, writing output or touching the cache
+    Io(io::Error),
+    /// A malformed CSV record or header
+    Csv(csv::Error),
+    /// An argument outside its valid range (count, error rate, ...)
+    InvalidArgument(String),
+    /// A state was requested from the cache but has not been downloaded
+    StateNotCached(String),
+    /// `--state all` was requested but nothing is cached
+    NoCachedStates,
+}
+
+/// Convenience alias for results returned by the library.
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "{}", e),
+            Error::Csv(e) => write!(f, "{}", e),
+            Error::InvalidArgument(msg) => write!(f, "{}", msg),
+            Error::StateNotCached(state) => write!(
+                f,
+                "State '{}' not cached. Run 'rust-faker download {}' first.",
+                state, state
+            ),
+            Error::NoCachedStates => write!(
+                f,
+                "No states specified or cached. Run 'rust-faker download <STATE>' first."
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::Csv(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<csv::Error> for Error {
+    fn from(e: csv::Error) -> Self {
+        Error::Csv(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_state_not_cached_message() {
+        let err = Error::StateNotCached("IL".to_string());
+        assert_eq!(
+            err.to_string(),
+            "State 'IL' not cached. Run 'rust-faker download IL' first."
+        );
+    }
+
+    #[test]
+    fn test_invalid_argument_message() {
+        let err = Error::InvalidArgument("Count must be greater than 0".to_string());
+        assert_eq!(err.to_string(), "Count must be greater than 0");
+    }
+
+    #[test]
+    fn test_from_io_error_keeps_source() {
+        let err: Error = io::Error::new(io::ErrorKind::NotFound, "missing").into();
+        assert!(matches!(err, Error::Io(_)));
+        assert!(std::error::Error::source(&err).is_some());
+    }
+}
@@ -2,13 +2,22 @@ use fake::faker::address::en::*;
 use fake::Fake;
 use rand::seq::SliceRandom;
 use rand::Rng;
+use serde::Serialize;
 use std::collections::HashMap;
+use std::fmt;
 use std::fs::File;
-use std::io::{self, BufRead, BufReader};
+use std::io::{self, BufRead, BufReader, Read};
 
 use crate::cache;
-
-#[derive(Debug, Clone, PartialEq)]
+use crate::error::{Error, Result};
+use crate::generators::streets;
+use crate::generators::typos::{self, TypoModel};
+use crate::generators::{traced, unicode, NamedPattern, Traced};
+use crate::geography;
+use crate::output::STDIO_PATH;
+use crate::profile::VarianceProfile;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct Address {
     pub address1: String,
     pub address2: String,
@@ -45,26 +54,46 @@ impl Address {
     }
 }
 
-pub fn generate_clean_address() -> Address {
-    let mut rng = rand::thread_rng();
-
-    let street_number: u32 = (1..9999).fake();
-    let street_name: String = StreetName().fake();
-    let street_suffix: String = StreetSuffix().fake();
-    let address1 = format!("{} {} {}", street_number, street_name, street_suffix);
+/// Generates an address with no variance applied.
+///
+/// The city, state and ZIP are taken together from the bundled
+/// [`geography`] table, so they always describe a real place.
+pub fn generate_clean_address<R: Rng + ?Sized>(rng: &mut R) -> Address {
+    let street_number: u32 = (1..9999).fake_with_rng(rng);
+    // Some streets are numbered ("5th Avenue") and some carry a directional
+    let street_name: String = if rng.gen_bool(0.1) {
+        streets::numeric_ordinal(rng.gen_range(1..=40))
+    } else {
+        StreetName().fake_with_rng(rng)
+    };
+    let street_suffix: String = StreetSuffix().fake_with_rng(rng);
+    let address1 = if rng.gen_bool(0.15) {
+        let directional = streets::random_directional(rng);
+        format!(
+            "{} {} {} {}",
+            street_number, directional, street_name, street_suffix
+        )
+    } else {
+        format!("{} {} {}", street_number, street_name, street_suffix)
+    };
 
     // 50% chance of having a secondary address
     let address2 = if rng.gen_bool(0.5) {
-        SecondaryAddress().fake()
+        SecondaryAddress().fake_with_rng(rng)
     } else {
         String::new()
     };
 
-    let city: String = CityName().fake();
-    let state: String = StateAbbr().fake();
-    let zip: String = ZipCode().fake();
+    // City, state and ZIP come from one real location so they agree
+    let location = geography::random_location(rng);
 
-    Address::new(address1, address2, city, state, zip)
+    Address::new(
+        address1,
+        address2,
+        location.city.to_string(),
+        location.state.to_string(),
+        location.zip.to_string(),
+    )
 }
 
 /// Generates a vector of addresses with configurable variance.
@@ -76,56 +105,121 @@ pub fn generate_clean_address() -> Address {
 /// # Arguments
 /// * `count` - Number of addresses to generate
 /// * `error_rate` - Probability (0.0 to 1.0) of applying variance to each address
+/// * `patterns` - Variance patterns that may be applied
+/// * `rng` - Random number generator; seed it to reproduce a dataset
 ///
 /// # Panics
 /// Panics if `error_rate` is outside the range [0.0, 1.0]
 ///
 /// # Examples
 /// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rust_faker::generators::addresses::{generate_addresses, VariancePattern};
+/// use rust_faker::generators::NamedPattern;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+///
 /// // Generate 10 clean addresses
-/// let clean_addresses = generate_addresses(10, 0.0);
+/// let clean_addresses = generate_addresses(10, 0.0, VariancePattern::ALL, &mut rng);
 ///
-/// // Generate 10 addresses with 30% variance
-/// let varied_addresses = generate_addresses(10, 0.3);
+/// // Generate 10 addresses with 30% variance, only abbreviating suffixes
+/// let abbreviated = generate_addresses(10, 0.3, &[VariancePattern::AbbreviateSuffix], &mut rng);
 /// ```
-pub fn generate_addresses(count: usize, error_rate: f64) -> Vec<Address> {
-    let mut rng = rand::thread_rng();
-    let mut addresses = Vec::with_capacity(count);
-
-    for _ in 0..count {
-        let clean_address = generate_clean_address();
-
-        // Apply variance based on error rate
-        let address = if rng.gen_bool(error_rate) {
-            apply_address_variance(clean_address)
-        } else {
-            clean_address
-        };
-
-        addresses.push(address);
-    }
+pub fn generate_addresses<R: Rng + ?Sized>(
+    count: usize,
+    error_rate: f64,
+    patterns: &[VariancePattern],
+    rng: &mut R,
+) -> Vec<Address> {
+    let profile = VarianceProfile::uniform(patterns);
+    traced(rng, generate_clean_address, |address, rng| {
+        vary_address(address, error_rate, &profile, rng)
+    })
+    .take(count)
+    .map(|traced| traced.record)
+    .collect()
+}
 
-    addresses
+/// Applies profile variance to a single address: record-level variance with
+/// probability `error_rate`, then any per-field error rates.
+pub fn vary_address<R: Rng + ?Sized>(
+    address: Address,
+    error_rate: f64,
+    profile: &VarianceProfile<VariancePattern>,
+    rng: &mut R,
+) -> Traced<Address> {
+    profile.vary(address, error_rate, rng, apply_pattern)
 }
 
 /// Applies variance to a vector of addresses based on error rate.
 ///
 /// This is useful when loading addresses from an external source
 /// and applying variance patterns to them.
-pub fn apply_variance_to_addresses(addresses: Vec<Address>, error_rate: f64) -> Vec<Address> {
-    let mut rng = rand::thread_rng();
+pub fn apply_variance_to_addresses<R: Rng + ?Sized>(
+    addresses: Vec<Address>,
+    error_rate: f64,
+    patterns: &[VariancePattern],
+    rng: &mut R,
+) -> Vec<Address> {
+    let profile = VarianceProfile::uniform(patterns);
+    trace_variance_to_addresses(addresses, error_rate, &profile, rng)
+        .into_iter()
+        .map(|traced| traced.record)
+        .collect()
+}
+
+/// Applies variance like [`apply_variance_to_addresses`] from a weighted
+/// [`VarianceProfile`], keeping each original address and the variances
+/// applied to it.
+pub fn trace_variance_to_addresses<R: Rng + ?Sized>(
+    addresses: Vec<Address>,
+    error_rate: f64,
+    profile: &VarianceProfile<VariancePattern>,
+    rng: &mut R,
+) -> Vec<Traced<Address>> {
     addresses
         .into_iter()
-        .map(|addr| {
-            if rng.gen_bool(error_rate) {
-                apply_address_variance(addr)
-            } else {
-                addr
-            }
-        })
+        .map(|addr| vary_address(addr, error_rate, profile, rng))
         .collect()
 }
 
+/// A non-fatal problem found while loading addresses from a CSV file.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LoadWarning {
+    /// No column could be mapped to the named field
+    MissingColumn(&'static str),
+    /// More records were requested than the file contains
+    InsufficientRecords { requested: usize, available: usize },
+}
+
+impl fmt::Display for LoadWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadWarning::MissingColumn("address1") => write!(
+                f,
+                "No address1/street column found. Looking for 'address', 'street', 'address1', or 'number'+'street'"
+            ),
+            LoadWarning::MissingColumn(field) => write!(f, "No {} column found", field),
+            LoadWarning::InsufficientRecords {
+                requested,
+                available,
+            } => write!(
+                f,
+                "Requested {} addresses but only {} available. Using all available.",
+                requested, available
+            ),
+        }
+    }
+}
+
+/// Addresses loaded from a CSV file, along with any warnings raised while loading.
+#[derive(Debug, Clone, Default)]
+pub struct LoadedAddresses {
+    pub addresses: Vec<Address>,
+    pub warnings: Vec<LoadWarning>,
+}
+
 /// Detects the delimiter used in a CSV line by checking frequency of common delimiters.
 fn detect_delimiter(line: &str) -> u8 {
     let comma_count = line.matches(',').count();
@@ -164,220 +258,268 @@ fn map_column_name(name: &str) -> Option<&'static str> {
     }
 }
 
-/// Loads addresses from a CSV file with flexible column mapping.
+/// Streams addresses from a CSV file one record at a time.
 ///
 /// Supports various CSV formats including OpenAddresses.io exports.
 /// Auto-detects delimiter (comma, pipe, tab) and maps column names
-/// case-insensitively.
+/// case-insensitively. Completely empty rows are skipped. Memory use is
+/// constant regardless of file size.
 ///
-/// # Arguments
-/// * `path` - Path to the CSV file
-/// * `count` - Optional number of addresses to load (randomly sampled if less than available)
+/// # Examples
+/// ```no_run
+/// use rust_faker::generators::addresses::AddressReader;
 ///
-/// # Returns
-/// A vector of Address structs loaded from the file
-pub fn load_addresses_from_csv(path: &str, count: Option<usize>) -> io::Result<Vec<Address>> {
-    let file = File::open(path)?;
-    let reader = BufReader::new(file);
-    let mut lines = reader.lines();
+/// let reader = AddressReader::open("real_addresses.csv")?;
+/// for address in reader {
+///     println!("{}", address?.city);
+/// }
+/// # Ok::<(), rust_faker::Error>(())
+/// ```
+pub struct AddressReader {
+    records: csv::StringRecordsIntoIter<Box<dyn Read>>,
+    column_map: HashMap<&'static str, usize>,
+    has_number_and_street: bool,
+    warnings: Vec<LoadWarning>,
+}
 
-    // Read and parse header
-    let header_line = lines
-        .next()
-        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Empty CSV file"))??;
+impl AddressReader {
+    /// Opens a CSV file and maps its header to address fields.
+    ///
+    /// A `path` of [`STDIO_PATH`] reads from standard input.
+    pub fn open(path: &str) -> Result<Self> {
+        let input: Box<dyn Read> = if path == STDIO_PATH {
+            Box::new(io::stdin())
+        } else {
+            Box::new(File::open(path)?)
+        };
+        let mut input = BufReader::new(input);
+
+        // Read and parse header
+        let mut header_line = String::new();
+        if input.read_line(&mut header_line)? == 0 {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "Empty CSV file").into());
+        }
 
-    let delimiter = detect_delimiter(&header_line);
+        let delimiter = detect_delimiter(header_line.trim_end_matches(['\r', '\n']));
 
-    // Build CSV reader with detected delimiter
-    let mut csv_reader = csv::ReaderBuilder::new()
-        .delimiter(delimiter)
-        .has_headers(true)
-        .from_path(path)?;
+        // Build CSV reader with detected delimiter, replaying the header line
+        // already consumed from the input
+        let source: Box<dyn Read> = Box::new(io::Cursor::new(header_line).chain(input));
+        let mut csv_reader = csv::ReaderBuilder::new()
+            .delimiter(delimiter)
+            .has_headers(true)
+            .from_reader(source);
 
-    // Map headers to indices
-    let headers = csv_reader.headers()?.clone();
-    let mut column_map: HashMap<&'static str, usize> = HashMap::new();
+        // Map headers to indices
+        let headers = csv_reader.headers()?.clone();
+        let mut column_map: HashMap<&'static str, usize> = HashMap::new();
 
-    for (idx, header) in headers.iter().enumerate() {
-        if let Some(mapped_name) = map_column_name(header) {
-            column_map.insert(mapped_name, idx);
+        for (idx, header) in headers.iter().enumerate() {
+            if let Some(mapped_name) = map_column_name(header) {
+                column_map.insert(mapped_name, idx);
+            }
         }
-    }
 
-    // Check for required columns
-    let has_address1 = column_map.contains_key("address1");
-    let has_number_and_street =
-        column_map.contains_key("number") && column_map.contains_key("address1");
+        // Check for required columns
+        let has_address1 = column_map.contains_key("address1");
+        let has_number_and_street =
+            column_map.contains_key("number") && column_map.contains_key("address1");
 
-    if !has_address1 && !column_map.contains_key("number") {
-        eprintln!(
-            "Warning: No address1/street column found. Looking for 'address', 'street', 'address1', or 'number'+'street'"
-        );
-    }
-    if !column_map.contains_key("city") {
-        eprintln!("Warning: No city column found");
-    }
-    if !column_map.contains_key("state") {
-        eprintln!("Warning: No state column found");
-    }
-    if !column_map.contains_key("zip") {
-        eprintln!("Warning: No zip column found");
+        let mut warnings = Vec::new();
+        if !has_address1 && !column_map.contains_key("number") {
+            warnings.push(LoadWarning::MissingColumn("address1"));
+        }
+        for field in ["city", "state", "zip"] {
+            if !column_map.contains_key(field) {
+                warnings.push(LoadWarning::MissingColumn(field));
+            }
+        }
+
+        Ok(Self {
+            records: csv_reader.into_records(),
+            column_map,
+            has_number_and_street,
+            warnings,
+        })
     }
 
-    // Parse records
-    let mut addresses: Vec<Address> = Vec::new();
+    /// Problems found in the header, such as unmapped fields.
+    pub fn warnings(&self) -> &[LoadWarning] {
+        &self.warnings
+    }
 
-    for result in csv_reader.records() {
-        let record = result?;
+    fn field(&self, record: &csv::StringRecord, name: &str) -> String {
+        self.column_map
+            .get(name)
+            .and_then(|&idx| record.get(idx))
+            .unwrap_or("")
+            .trim()
+            .to_string()
+    }
 
+    fn parse(&self, record: &csv::StringRecord) -> Option<Address> {
         // Build address1 - either from address1 field or number + street combination
-        let address1 = if has_number_and_street {
-            let number = column_map
-                .get("number")
-                .and_then(|&idx| record.get(idx))
-                .unwrap_or("")
-                .trim();
-            let street = column_map
-                .get("address1")
-                .and_then(|&idx| record.get(idx))
-                .unwrap_or("")
-                .trim();
+        let address1 = if self.has_number_and_street {
+            let number = self.field(record, "number");
+            let street = self.field(record, "address1");
             if !number.is_empty() && !street.is_empty() {
                 format!("{} {}", number, street)
             } else if !street.is_empty() {
-                street.to_string()
+                street
             } else {
-                number.to_string()
+                number
             }
         } else {
-            column_map
-                .get("address1")
-                .and_then(|&idx| record.get(idx))
-                .unwrap_or("")
-                .trim()
-                .to_string()
+            self.field(record, "address1")
         };
 
-        let address2 = column_map
-            .get("address2")
-            .and_then(|&idx| record.get(idx))
-            .unwrap_or("")
-            .trim()
-            .to_string();
-
-        let city = column_map
-            .get("city")
-            .and_then(|&idx| record.get(idx))
-            .unwrap_or("")
-            .trim()
-            .to_string();
-
-        let state = column_map
-            .get("state")
-            .and_then(|&idx| record.get(idx))
-            .unwrap_or("")
-            .trim()
-            .to_string();
-
-        let zip = column_map
-            .get("zip")
-            .and_then(|&idx| record.get(idx))
-            .unwrap_or("")
-            .trim()
-            .to_string();
+        let address2 = self.field(record, "address2");
+        let city = self.field(record, "city");
+        let state = self.field(record, "state");
+        let zip = self.field(record, "zip");
 
         // Skip completely empty records
         if address1.is_empty() && city.is_empty() && state.is_empty() && zip.is_empty() {
-            continue;
+            return None;
         }
 
-        addresses.push(Address::new(address1, address2, city, state, zip));
+        Some(Address::new(address1, address2, city, state, zip))
     }
+}
 
-    let total_loaded = addresses.len();
-
-    // Handle count
-    if let Some(requested_count) = count {
-        if requested_count >= total_loaded {
-            if requested_count > total_loaded {
-                eprintln!(
-                    "Warning: Requested {} addresses but only {} available. Using all available.",
-                    requested_count, total_loaded
-                );
+impl Iterator for AddressReader {
+    type Item = Result<Address>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let record = match self.records.next()? {
+                Ok(record) => record,
+                Err(e) => return Some(Err(e.into())),
+            };
+            if let Some(address) = self.parse(&record) {
+                return Some(Ok(address));
             }
-            // Return all addresses
+        }
+    }
+}
+
+/// Draws a uniform random sample of up to `count` addresses from a stream.
+///
+/// Uses reservoir sampling, so memory is bounded by `count` rather than the
+/// size of the stream. Returns the sample and the total number of addresses
+/// seen. When the stream holds no more than `count` addresses they are all
+/// returned in their original order; otherwise the sample is shuffled.
+pub fn sample_addresses<I, R>(
+    addresses: I,
+    count: usize,
+    rng: &mut R,
+) -> Result<(Vec<Address>, usize)>
+where
+    I: IntoIterator<Item = Result<Address>>,
+    R: Rng + ?Sized,
+{
+    let mut sample = Vec::new();
+    let mut seen = 0;
+
+    for address in addresses {
+        let address = address?;
+        seen += 1;
+        if sample.len() < count {
+            sample.push(address);
         } else {
-            // Shuffle and take requested count
-            let mut rng = rand::thread_rng();
-            addresses.shuffle(&mut rng);
-            addresses.truncate(requested_count);
+            let slot = rng.gen_range(0..seen);
+            if slot < count {
+                sample[slot] = address;
+            }
         }
     }
 
-    Ok(addresses)
+    if seen > count {
+        sample.shuffle(rng);
+    }
+    Ok((sample, seen))
 }
 
-/// Loads addresses from the cache for specified states
-pub fn load_addresses_from_cache(
-    states: &[String],
+/// Loads addresses from a CSV file with flexible column mapping.
+///
+/// See [`AddressReader`] for the supported formats; use it directly to
+/// stream a file without holding it in memory.
+///
+/// # Arguments
+/// * `path` - Path to the CSV file, or `-` for standard input
+/// * `count` - Optional number of addresses to load (randomly sampled if less than available)
+/// * `rng` - Random number generator used for sampling
+///
+/// # Returns
+/// The loaded addresses plus any non-fatal warnings (missing columns,
+/// fewer records than requested)
+pub fn load_addresses_from_csv<R: Rng + ?Sized>(
+    path: &str,
     count: Option<usize>,
-) -> io::Result<Vec<Address>> {
-    let mut all_addresses: Vec<Address> = Vec::new();
+    rng: &mut R,
+) -> Result<LoadedAddresses> {
+    let reader = AddressReader::open(path)?;
+    let mut warnings = reader.warnings().to_vec();
+
+    let addresses = match count {
+        Some(requested) => {
+            let (addresses, available) = sample_addresses(reader, requested, rng)?;
+            if requested > available {
+                warnings.push(LoadWarning::InsufficientRecords {
+                    requested,
+                    available,
+                });
+            }
+            addresses
+        }
+        None => reader.collect::<Result<Vec<_>>>()?,
+    };
+
+    Ok(LoadedAddresses {
+        addresses,
+        warnings,
+    })
+}
+
+/// Opens a streaming reader over the cached addresses of each state, in order.
+///
+/// Fails with [`Error::StateNotCached`] before reading anything if any state
+/// has not been downloaded.
+pub fn stream_addresses_from_cache(
+    states: &[String],
+) -> Result<impl Iterator<Item = Result<Address>>> {
+    let mut readers = Vec::with_capacity(states.len());
 
     for state in states {
         let state_upper = state.to_uppercase();
 
         if !cache::is_state_cached(&state_upper)? {
-            return Err(io::Error::new(
-                io::ErrorKind::NotFound,
-                format!(
-                    "State '{}' not cached. Run 'rust-faker download {}' first.",
-                    state_upper, state_upper
-                ),
-            ));
+            return Err(Error::StateNotCached(state_upper));
         }
 
         let cache_path = cache::get_state_cache_path(&state_upper)?;
-        let addresses = load_addresses_from_csv(cache_path.to_str().unwrap(), None)?;
-        all_addresses.extend(addresses);
+        readers.push(AddressReader::open(&cache_path.to_string_lossy())?);
     }
 
-    // Handle count
-    if let Some(requested_count) = count {
-        if requested_count < all_addresses.len() {
-            let mut rng = rand::thread_rng();
-            all_addresses.shuffle(&mut rng);
-            all_addresses.truncate(requested_count);
-        }
-    }
-
-    Ok(all_addresses)
+    Ok(readers.into_iter().flatten())
 }
 
-fn abbreviate_street_suffix(suffix: &str) -> String {
-    match suffix {
-        "Street" => "St",
-        "Avenue" => "Ave",
-        "Road" => "Rd",
-        "Boulevard" => "Blvd",
-        "Drive" => "Dr",
-        "Lane" => "Ln",
-        "Parkway" => "Pkwy",
-        "Court" => "Ct",
-        "Circle" => "Cir",
-        "Way" => "Way",
-        "Place" => "Pl",
-        "Square" => "Sq",
-        "Trail" => "Trl",
-        "Terrace" => "Ter",
-        _ => suffix,
+/// Loads addresses from the cache for specified states
+pub fn load_addresses_from_cache<R: Rng + ?Sized>(
+    states: &[String],
+    count: Option<usize>,
+    rng: &mut R,
+) -> Result<Vec<Address>> {
+    let addresses = stream_addresses_from_cache(states)?;
+
+    match count {
+        Some(requested) => Ok(sample_addresses(addresses, requested, rng)?.0),
+        None => addresses.collect(),
     }
-    .to_string()
 }
 
-fn generate_po_box() -> String {
-    let mut rng = rand::thread_rng();
-    let box_number: u32 = (1..9999).fake();
+fn generate_po_box<R: Rng + ?Sized>(rng: &mut R) -> String {
+    let box_number: u32 = (1..9999).fake_with_rng(rng);
 
     let formats = [
         format!("PO Box {}", box_number),
@@ -388,8 +530,7 @@ fn generate_po_box() -> String {
     formats[rng.gen_range(0..formats.len())].clone()
 }
 
-fn generate_apartment() -> String {
-    let mut rng = rand::thread_rng();
+fn generate_apartment<R: Rng + ?Sized>(rng: &mut R) -> String {
     let unit: String = format!(
         "{}{}",
         rng.gen_range(1..999),
@@ -413,87 +554,471 @@ fn generate_apartment() -> String {
     formats[rng.gen_range(0..formats.len())].clone()
 }
 
-fn apply_address_variance(mut address: Address) -> Address {
-    let mut rng = rand::thread_rng();
+/// Splits a ZIP code into its five digits and +4 extension, however the
+/// extension is separated ("60601-1234", "60601 1234", "606011234").
+/// Returns `None` for anything else, including ZIPs that lost a digit.
+fn split_zip(zip: &str) -> Option<(&str, Option<&str>)> {
+    let digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+    let five = zip.get(..5).filter(|five| digits(five))?;
+    let plus4 = match (zip.len(), zip.get(5..6)) {
+        (5, _) => None,
+        (9, _) => zip.get(5..),
+        (10, Some("-" | " ")) => zip.get(6..),
+        _ => return None,
+    };
+    match plus4 {
+        Some(plus4) if !digits(plus4) => None,
+        _ => Some((five, plus4)),
+    }
+}
 
-    // Apply 1-3 random variance patterns
-    let num_variances = rng.gen_range(1..=3);
+/// Writes a ZIP+4 with `separator` between its parts, adding a random +4
+/// extension to a five-digit ZIP.
+fn zip_plus4<R: Rng + ?Sized>(zip: &str, separator: &str, rng: &mut R) -> Option<String> {
+    let (five, plus4) = split_zip(zip)?;
+    let plus4 = plus4.map_or_else(|| format!("{:04}", rng.gen_range(1..=9999)), String::from);
+    Some(format!("{}{}{}", five, separator, plus4))
+}
 
-    for _ in 0..num_variances {
-        let variance_type = rng.gen_range(0..10);
+/// A ZIP read as a number, the way a spreadsheet or dataframe coerces an
+/// all-digit column: 02134 -> 2134.
+fn zip_as_number(zip: &str) -> Option<u64> {
+    if zip.is_empty() || !zip.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    zip.parse().ok()
+}
 
-        match variance_type {
-            0 => {
-                // Abbreviate street suffix
-                let parts: Vec<&str> = address.address1.split_whitespace().collect();
-                if let Some(&last) = parts.last() {
-                    let abbreviated = abbreviate_street_suffix(last);
-                    let mut new_parts = parts[..parts.len() - 1].to_vec();
-                    new_parts.push(&abbreviated);
-                    address.address1 = new_parts.join(" ");
-                }
+/// A single, individually selectable address variance pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VariancePattern {
+    /// "123 Main Street" -> "123 Main St", using the USPS Publication 28
+    /// standard abbreviation
+    AbbreviateSuffix,
+    /// "123 Main St" -> "123 Main Street"
+    ExpandSuffix,
+    /// A commonly used, non-standard suffix abbreviation: "Avenue" -> "Av"
+    NonstandardSuffix,
+    /// "123 North Main St" -> "123 N Main St"
+    AbbreviateDirectional,
+    /// "123 NW Main St" -> "123 Northwest Main St"
+    ExpandDirectional,
+    /// A pre-directional moved after the suffix, or a post-directional
+    /// before the street name: "123 N Main St" <-> "123 Main St N"
+    MoveDirectional,
+    /// An ordinal street name rewritten: "5th Ave" <-> "Fifth Ave"
+    Ordinal,
+    /// "123 Main St" -> "123 1/2 Main St"
+    FractionalHouseNumber,
+    /// "100 Main St" -> "100-102 Main St"
+    HouseNumberRange,
+    /// "123 Main St" -> "Main St"
+    RemoveHouseNumber,
+    /// address2 appended to address1: "123 Main St Apt 4"
+    UnitInAddress1,
+    /// Replace the street address with a PO Box and drop address2
+    PoBox,
+    /// Add an apartment/unit in one of several formats
+    AddUnit,
+    /// Blank the state
+    RemoveState,
+    /// Blank the ZIP code
+    RemoveZip,
+    /// A random +4 extension added: "60601" -> "60601-1234"
+    AddZip4,
+    /// The +4 extension dropped: "60601-1234" -> "60601"
+    RemoveZip4,
+    /// A ZIP+4 written with a space: "60601 1234"
+    Zip4Space,
+    /// A ZIP+4 written without a dash: "606011234"
+    Zip4NoDash,
+    /// Leading zeros dropped, as by a spreadsheet: "02134" -> "2134"
+    DropLeadingZeros,
+    /// The ZIP coerced to a float: "02134" -> "2134.0"
+    NumericZip,
+    /// The nearest other real ZIP in the same state swapped in: "02108" -> "02139"
+    NeighboringZip,
+    /// Blank the city
+    RemoveCity,
+    /// Uppercase address1 and city
+    Uppercase,
+    /// Double every space in address1
+    ExtraSpaces,
+    /// Add periods after abbreviated suffixes and directionals ("St" -> "St.")
+    AddPeriods,
+    /// Alternate the case of the city ("ChIcAgO")
+    MixedCaseCity,
+    /// One field stressed with Unicode look-alikes: a no-break space,
+    /// full-width digits, a homoglyph, a decomposed accent, ...
+    Unicode,
+    /// A doubled, transposed or missing character in the street address or city
+    Typo,
+    /// A neighbouring QWERTY key in the street address or city: "Mian St"
+    KeyboardTypo,
+    /// A sound-alike spelling in the street address or city: "Filadelphia"
+    PhoneticTypo,
+    /// An OCR misreading in the street address or city: "l23 Main St"
+    OcrTypo,
+    /// A speech-to-text mishearing in the street address or city:
+    /// "50 Maine St" for "15 Main St"
+    VoiceTypo,
+}
+
+impl NamedPattern for VariancePattern {
+    const ALL: &'static [Self] = &[
+        VariancePattern::AbbreviateSuffix,
+        VariancePattern::ExpandSuffix,
+        VariancePattern::NonstandardSuffix,
+        VariancePattern::AbbreviateDirectional,
+        VariancePattern::ExpandDirectional,
+        VariancePattern::MoveDirectional,
+        VariancePattern::Ordinal,
+        VariancePattern::FractionalHouseNumber,
+        VariancePattern::HouseNumberRange,
+        VariancePattern::RemoveHouseNumber,
+        VariancePattern::UnitInAddress1,
+        VariancePattern::PoBox,
+        VariancePattern::AddUnit,
+        VariancePattern::RemoveState,
+        VariancePattern::RemoveZip,
+        VariancePattern::AddZip4,
+        VariancePattern::RemoveZip4,
+        VariancePattern::Zip4Space,
+        VariancePattern::Zip4NoDash,
+        VariancePattern::DropLeadingZeros,
+        VariancePattern::NumericZip,
+        VariancePattern::NeighboringZip,
+        VariancePattern::RemoveCity,
+        VariancePattern::Uppercase,
+        VariancePattern::ExtraSpaces,
+        VariancePattern::AddPeriods,
+        VariancePattern::MixedCaseCity,
+        VariancePattern::Unicode,
+        VariancePattern::Typo,
+        VariancePattern::KeyboardTypo,
+        VariancePattern::PhoneticTypo,
+        VariancePattern::OcrTypo,
+        VariancePattern::VoiceTypo,
+    ];
+
+    fn name(&self) -> &'static str {
+        match self {
+            VariancePattern::AbbreviateSuffix => "abbreviate_suffix",
+            VariancePattern::ExpandSuffix => "expand_suffix",
+            VariancePattern::NonstandardSuffix => "nonstandard_suffix",
+            VariancePattern::AbbreviateDirectional => "abbreviate_directional",
+            VariancePattern::ExpandDirectional => "expand_directional",
+            VariancePattern::MoveDirectional => "move_directional",
+            VariancePattern::Ordinal => "ordinal",
+            VariancePattern::FractionalHouseNumber => "fractional_house_number",
+            VariancePattern::HouseNumberRange => "house_number_range",
+            VariancePattern::RemoveHouseNumber => "remove_house_number",
+            VariancePattern::UnitInAddress1 => "unit_in_address1",
+            VariancePattern::PoBox => "po_box",
+            VariancePattern::AddUnit => "add_unit",
+            VariancePattern::RemoveState => "remove_state",
+            VariancePattern::RemoveZip => "remove_zip",
+            VariancePattern::AddZip4 => "add_zip4",
+            VariancePattern::RemoveZip4 => "remove_zip4",
+            VariancePattern::Zip4Space => "zip4_space",
+            VariancePattern::Zip4NoDash => "zip4_no_dash",
+            VariancePattern::DropLeadingZeros => "drop_leading_zeros",
+            VariancePattern::NumericZip => "numeric_zip",
+            VariancePattern::NeighboringZip => "neighboring_zip",
+            VariancePattern::RemoveCity => "remove_city",
+            VariancePattern::Uppercase => "uppercase",
+            VariancePattern::ExtraSpaces => "extra_spaces",
+            VariancePattern::AddPeriods => "add_periods",
+            VariancePattern::MixedCaseCity => "mixed_case_city",
+            VariancePattern::Unicode => "unicode",
+            VariancePattern::Typo => "typo",
+            VariancePattern::KeyboardTypo => "keyboard_typo",
+            VariancePattern::PhoneticTypo => "phonetic_typo",
+            VariancePattern::OcrTypo => "ocr_typo",
+            VariancePattern::VoiceTypo => "voice_typo",
+        }
+    }
+
+    fn fields(&self) -> &'static [&'static str] {
+        match self {
+            VariancePattern::AbbreviateSuffix
+            | VariancePattern::ExpandSuffix
+            | VariancePattern::NonstandardSuffix
+            | VariancePattern::AbbreviateDirectional
+            | VariancePattern::ExpandDirectional
+            | VariancePattern::MoveDirectional
+            | VariancePattern::Ordinal
+            | VariancePattern::FractionalHouseNumber
+            | VariancePattern::HouseNumberRange
+            | VariancePattern::RemoveHouseNumber
+            | VariancePattern::ExtraSpaces
+            | VariancePattern::AddPeriods => &["address1"],
+            VariancePattern::PoBox | VariancePattern::UnitInAddress1 => &["address1", "address2"],
+            VariancePattern::AddUnit => &["address2"],
+            VariancePattern::RemoveState => &["state"],
+            VariancePattern::RemoveZip
+            | VariancePattern::AddZip4
+            | VariancePattern::RemoveZip4
+            | VariancePattern::Zip4Space
+            | VariancePattern::Zip4NoDash
+            | VariancePattern::DropLeadingZeros
+            | VariancePattern::NumericZip
+            | VariancePattern::NeighboringZip => &["zip"],
+            VariancePattern::RemoveCity | VariancePattern::MixedCaseCity => &["city"],
+            VariancePattern::Uppercase
+            | VariancePattern::Typo
+            | VariancePattern::KeyboardTypo
+            | VariancePattern::PhoneticTypo
+            | VariancePattern::OcrTypo
+            | VariancePattern::VoiceTypo => &["address1", "city"],
+            VariancePattern::Unicode => &["address1", "address2", "city", "zip"],
+        }
+    }
+}
+
+impl fmt::Display for VariancePattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Applies a typo from `model` to the street line or city, relabeling the
+/// change with the kind of typo applied.
+fn misspell<R: Rng + ?Sized>(
+    address: &mut Address,
+    model: TypoModel,
+    label: &mut &'static str,
+    rng: &mut R,
+) {
+    let fields = [&mut address.address1, &mut address.city];
+    if let Some(typo_label) = typos::misspell(fields, model, rng) {
+        *label = typo_label;
+    }
+}
+
+/// Applies a single variance pattern to an address in place.
+///
+/// Returns the ground-truth label of the change (`typo:<kind>` for typos,
+/// `unicode:<kind>` for Unicode stress), or `None` if the pattern had
+/// nothing to act on (e.g. abbreviating an already abbreviated suffix).
+pub fn apply_pattern<R: Rng + ?Sized>(
+    address: &mut Address,
+    pattern: VariancePattern,
+    rng: &mut R,
+) -> Option<String> {
+    let before = address.clone();
+    let mut label = pattern.name();
+
+    match pattern {
+        VariancePattern::AbbreviateSuffix
+        | VariancePattern::ExpandSuffix
+        | VariancePattern::NonstandardSuffix
+        | VariancePattern::AbbreviateDirectional
+        | VariancePattern::ExpandDirectional
+        | VariancePattern::MoveDirectional
+        | VariancePattern::Ordinal
+        | VariancePattern::FractionalHouseNumber
+        | VariancePattern::HouseNumberRange
+        | VariancePattern::RemoveHouseNumber => {
+            let line = &address.address1;
+            let varied = match pattern {
+                VariancePattern::AbbreviateSuffix => streets::abbreviate_suffix(line),
+                VariancePattern::ExpandSuffix => streets::expand_suffix(line),
+                VariancePattern::NonstandardSuffix => streets::nonstandard_suffix(line, rng),
+                VariancePattern::AbbreviateDirectional => streets::abbreviate_directionals(line),
+                VariancePattern::ExpandDirectional => streets::expand_directionals(line),
+                VariancePattern::MoveDirectional => streets::move_directional(line),
+                VariancePattern::Ordinal => streets::respell_ordinal(line),
+                VariancePattern::FractionalHouseNumber => streets::add_house_number_fraction(line),
+                VariancePattern::HouseNumberRange => streets::house_number_range(line, rng),
+                _ => streets::remove_house_number(line),
+            };
+            if let Some(varied) = varied {
+                address.address1 = varied;
             }
-            1 => {
-                // Replace with PO Box
-                address.address1 = generate_po_box();
+        }
+        VariancePattern::UnitInAddress1 => {
+            if !address.address2.is_empty() {
+                address.address1 = format!("{} {}", address.address1, address.address2);
                 address.address2 = String::new();
             }
-            2 => {
-                // Add apartment/unit
-                address.address2 = generate_apartment();
-            }
-            3 => {
-                // Remove state
-                address.state = String::new();
+        }
+        VariancePattern::PoBox => {
+            address.address1 = generate_po_box(rng);
+            address.address2 = String::new();
+        }
+        VariancePattern::AddUnit => {
+            address.address2 = generate_apartment(rng);
+        }
+        VariancePattern::RemoveState => {
+            address.state = String::new();
+        }
+        VariancePattern::RemoveZip => {
+            address.zip = String::new();
+        }
+        VariancePattern::AddZip4 => {
+            if let Some((_, None)) = split_zip(&address.zip) {
+                address.zip = zip_plus4(&address.zip, "-", rng).expect("ZIP was just split");
             }
-            4 => {
-                // Remove zip
-                address.zip = String::new();
+        }
+        VariancePattern::RemoveZip4 => {
+            if let Some((five, Some(_))) = split_zip(&address.zip) {
+                address.zip = five.to_string();
             }
-            5 => {
-                // Remove city
-                address.city = String::new();
+        }
+        VariancePattern::Zip4Space | VariancePattern::Zip4NoDash => {
+            let separator = if pattern == VariancePattern::Zip4Space {
+                " "
+            } else {
+                ""
+            };
+            if let Some(zip) = zip_plus4(&address.zip, separator, rng) {
+                address.zip = zip;
             }
-            6 => {
-                // All caps
-                address.address1 = address.address1.to_uppercase();
-                address.city = address.city.to_uppercase();
+        }
+        VariancePattern::DropLeadingZeros => {
+            if let Some(number) = zip_as_number(&address.zip) {
+                if number > 0 {
+                    address.zip = number.to_string();
+                }
             }
-            7 => {
-                // Add extra spaces
-                address.address1 = address.address1.replace(" ", "  ");
+        }
+        VariancePattern::NumericZip => {
+            if let Some(number) = zip_as_number(&address.zip) {
+                address.zip = format!("{}.0", number);
             }
-            8 => {
-                // Add periods inconsistently
-                if rng.gen_bool(0.5) {
-                    address.address1 = address.address1.replace("St", "St.");
-                    address.address1 = address.address1.replace("Ave", "Ave.");
-                }
+        }
+        VariancePattern::NeighboringZip => {
+            if let Some(neighbor) = geography::neighboring_zip(&address.zip, &address.state) {
+                address.zip = format!("{}{}", neighbor.zip, &address.zip[5..]);
             }
-            _ => {
-                // Mixed case
-                address.city = address
-                    .city
-                    .chars()
-                    .enumerate()
-                    .map(|(i, c)| {
-                        if i % 2 == 0 {
-                            c.to_uppercase().to_string()
-                        } else {
-                            c.to_lowercase().to_string()
-                        }
-                    })
-                    .collect();
+        }
+        VariancePattern::RemoveCity => {
+            address.city = String::new();
+        }
+        VariancePattern::Uppercase => {
+            address.address1 = address.address1.to_uppercase();
+            address.city = address.city.to_uppercase();
+        }
+        VariancePattern::ExtraSpaces => {
+            address.address1 = address.address1.replace(' ', "  ");
+        }
+        VariancePattern::AddPeriods => {
+            address.address1 = streets::add_periods(&address.address1);
+        }
+        VariancePattern::MixedCaseCity => {
+            address.city = address
+                .city
+                .chars()
+                .enumerate()
+                .map(|(i, c)| {
+                    if i % 2 == 0 {
+                        c.to_uppercase().to_string()
+                    } else {
+                        c.to_lowercase().to_string()
+                    }
+                })
+                .collect();
+        }
+        VariancePattern::Unicode => {
+            let fields = &mut [
+                &mut address.address1,
+                &mut address.address2,
+                &mut address.city,
+                &mut address.zip,
+            ];
+            if let Some(unicode_label) = unicode::stress(fields, rng) {
+                label = unicode_label;
             }
         }
+        VariancePattern::Typo => misspell(address, TypoModel::Basic, &mut label, rng),
+        VariancePattern::KeyboardTypo => misspell(address, TypoModel::Keyboard, &mut label, rng),
+        VariancePattern::PhoneticTypo => misspell(address, TypoModel::Phonetic, &mut label, rng),
+        VariancePattern::OcrTypo => misspell(address, TypoModel::Ocr, &mut label, rng),
+        VariancePattern::VoiceTypo => misspell(address, TypoModel::Voice, &mut label, rng),
     }
 
-    address
+    if *address == before {
+        None
+    } else {
+        Some(label.to_string())
+    }
+}
+
+/// Applies 1-3 random variance patterns, drawn from `patterns`, to an Address.
+pub fn apply_address_variance<R: Rng + ?Sized>(
+    address: Address,
+    patterns: &[VariancePattern],
+    rng: &mut R,
+) -> Address {
+    trace_address_variance(address, patterns, rng).record
+}
+
+/// Applies variance like [`apply_address_variance`], recording the clean
+/// address and the ordered list of variance operations that changed it.
+pub fn trace_address_variance<R: Rng + ?Sized>(
+    address: Address,
+    patterns: &[VariancePattern],
+    rng: &mut R,
+) -> Traced<Address> {
+    VarianceProfile::uniform(patterns).trace(address, rng, apply_pattern)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn numbered_addresses(n: usize) -> Vec<Result<Address>> {
+        (0..n)
+            .map(|i| {
+                Ok(Address::new(
+                    format!("{} Main St", i),
+                    String::new(),
+                    "Springfield".to_string(),
+                    "IL".to_string(),
+                    "62701".to_string(),
+                ))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_sample_addresses_bounded() {
+        let mut rng = StdRng::seed_from_u64(2);
+        let (sample, seen) = sample_addresses(numbered_addresses(1000), 10, &mut rng).unwrap();
+        assert_eq!(seen, 1000);
+        assert_eq!(sample.len(), 10);
+        let mut distinct: Vec<&str> = sample.iter().map(|a| a.address1.as_str()).collect();
+        distinct.sort();
+        distinct.dedup();
+        assert_eq!(distinct.len(), 10);
+    }
+
+    #[test]
+    fn test_sample_addresses_keeps_order_when_short() {
+        let mut rng = StdRng::seed_from_u64(2);
+        let (sample, seen) = sample_addresses(numbered_addresses(3), 10, &mut rng).unwrap();
+        assert_eq!(seen, 3);
+        let streets: Vec<&str> = sample.iter().map(|a| a.address1.as_str()).collect();
+        assert_eq!(streets, ["0 Main St", "1 Main St", "2 Main St"]);
+    }
+
+    #[test]
+    fn test_clean_address_city_state_zip_agree() {
+        let mut rng = StdRng::seed_from_u64(5);
+        for _ in 0..100 {
+            let addr = generate_clean_address(&mut rng);
+            assert!(
+                geography::locations_for_state(&addr.state)
+                    .iter()
+                    .any(|loc| loc.city == addr.city && loc.zip == addr.zip),
+                "{:?} is not a bundled location",
+                addr
+            );
+        }
+    }
 
     #[test]
     fn test_address_creation() {
@@ -529,7 +1054,7 @@ mod tests {
 
     #[test]
     fn test_generate_clean_address() {
-        let addr = generate_clean_address();
+        let addr = generate_clean_address(&mut rand::thread_rng());
         // Basic validation - clean addresses should have values in fields
         assert!(!addr.address1.is_empty());
         assert!(!addr.city.is_empty());
@@ -541,35 +1066,92 @@ mod tests {
     #[test]
     fn test_generate_clean_address_randomness() {
         // Generate multiple addresses and verify they're different
-        let addr1 = generate_clean_address();
-        let addr2 = generate_clean_address();
+        let mut rng = rand::thread_rng();
+        let addr1 = generate_clean_address(&mut rng);
+        let addr2 = generate_clean_address(&mut rng);
         // Very unlikely to generate identical addresses
         assert_ne!(addr1, addr2);
     }
 
     #[test]
-    fn test_abbreviate_street_suffix_known() {
-        assert_eq!(abbreviate_street_suffix("Street"), "St");
-        assert_eq!(abbreviate_street_suffix("Avenue"), "Ave");
-        assert_eq!(abbreviate_street_suffix("Road"), "Rd");
-        assert_eq!(abbreviate_street_suffix("Boulevard"), "Blvd");
+    fn test_variance_pattern_names_round_trip() {
+        for pattern in VariancePattern::ALL {
+            assert_eq!(VariancePattern::from_name(pattern.name()), Some(*pattern));
+        }
+        assert_eq!(VariancePattern::from_name("not_a_pattern"), None);
     }
 
     #[test]
-    fn test_abbreviate_street_suffix_unknown() {
-        // Unknown suffixes should return as-is
-        assert_eq!(abbreviate_street_suffix("Unknown"), "Unknown");
+    fn test_apply_pattern_targets_single_field() {
+        let clean = Address::new(
+            "123 Main Street".to_string(),
+            String::new(),
+            "Springfield".to_string(),
+            "IL".to_string(),
+            "62701".to_string(),
+        );
+        let mut rng = rand::thread_rng();
+
+        let mut addr = clean.clone();
+        let label = apply_pattern(&mut addr, VariancePattern::RemoveState, &mut rng);
+        assert_eq!(label.as_deref(), Some("remove_state"));
+        assert_eq!(addr.state, "");
+        assert_eq!(addr.city, clean.city);
+
+        let mut addr = clean.clone();
+        apply_pattern(&mut addr, VariancePattern::AbbreviateSuffix, &mut rng);
+        assert_eq!(addr.address1, "123 Main St");
+        let label = apply_pattern(&mut addr, VariancePattern::AddPeriods, &mut rng);
+        assert_eq!(label.as_deref(), Some("add_periods"));
+        assert_eq!(addr.address1, "123 Main St.");
+    }
+
+    #[test]
+    fn test_apply_pattern_no_op_has_no_label() {
+        let mut addr = Address::new(
+            "123 Main St".to_string(),
+            String::new(),
+            "Springfield".to_string(),
+            "IL".to_string(),
+            String::new(),
+        );
+        let label = apply_pattern(
+            &mut addr,
+            VariancePattern::RemoveZip,
+            &mut rand::thread_rng(),
+        );
+        assert_eq!(label, None);
+    }
+
+    #[test]
+    fn test_trace_address_variance_only_uses_selected_patterns() {
+        let clean = Address::new(
+            "123 Main Street".to_string(),
+            String::new(),
+            "Springfield".to_string(),
+            "IL".to_string(),
+            "62701".to_string(),
+        );
+        let mut rng = StdRng::seed_from_u64(9);
+
+        for _ in 0..20 {
+            let traced =
+                trace_address_variance(clean.clone(), &[VariancePattern::RemoveCity], &mut rng);
+            assert_eq!(traced.record.city, "");
+            assert_eq!(traced.record.state, "IL");
+            assert_eq!(traced.variances, vec!["remove_city"]);
+        }
     }
 
     #[test]
     fn test_generate_po_box() {
-        let po_box = generate_po_box();
+        let po_box = generate_po_box(&mut rand::thread_rng());
         assert!(po_box.contains("Box") || po_box.contains("BOX") || po_box.contains("POB"));
     }
 
     #[test]
     fn test_generate_apartment() {
-        let apt = generate_apartment();
+        let apt = generate_apartment(&mut rand::thread_rng());
         assert!(
             apt.contains("Apt")
                 || apt.contains("Apartment")
@@ -592,7 +1174,8 @@ mod tests {
 
         // Apply variance multiple times until we get abbreviation
         // (since variance is random, we test the function exists and runs)
-        let varied = apply_address_variance(clean.clone());
+        let varied =
+            apply_address_variance(clean.clone(), VariancePattern::ALL, &mut rand::thread_rng());
         assert!(!varied.address1.is_empty());
     }
 
@@ -606,20 +1189,42 @@ mod tests {
             "62701".to_string(),
         );
 
-        let varied = apply_address_variance(clean);
+        let varied = apply_address_variance(clean, VariancePattern::ALL, &mut rand::thread_rng());
         // Just verify it doesn't panic and returns something
         assert!(!varied.address1.is_empty());
     }
 
+    #[test]
+    fn test_trace_address_variance_records_clean_and_variances() {
+        let clean = Address::new(
+            "123 Main Street".to_string(),
+            String::new(),
+            "Springfield".to_string(),
+            "IL".to_string(),
+            "62701".to_string(),
+        );
+
+        let traced = trace_address_variance(
+            clean.clone(),
+            VariancePattern::ALL,
+            &mut StdRng::seed_from_u64(5),
+        );
+        assert_eq!(traced.clean, clean);
+        assert!(traced.variances.len() <= 3);
+        if traced.variances.is_empty() {
+            assert_eq!(traced.record, clean);
+        }
+    }
+
     #[test]
     fn test_generate_addresses_count() {
-        let addresses = generate_addresses(10, 0.0);
+        let addresses = generate_addresses(10, 0.0, VariancePattern::ALL, &mut rand::thread_rng());
         assert_eq!(addresses.len(), 10);
     }
 
     #[test]
     fn test_generate_addresses_zero_error_rate() {
-        let addresses = generate_addresses(5, 0.0);
+        let addresses = generate_addresses(5, 0.0, VariancePattern::ALL, &mut rand::thread_rng());
         // All should be clean (have all fields populated)
         for addr in addresses {
             assert!(!addr.address1.is_empty());
@@ -631,12 +1236,30 @@ mod tests {
 
     #[test]
     fn test_generate_addresses_full_error_rate() {
-        let addresses = generate_addresses(5, 1.0);
+        let addresses = generate_addresses(5, 1.0, VariancePattern::ALL, &mut rand::thread_rng());
         // All should have variance applied
         // Hard to test exactly, but verify we got addresses
         assert_eq!(addresses.len(), 5);
     }
 
+    #[test]
+    fn test_generate_addresses_same_seed_is_deterministic() {
+        let first =
+            generate_addresses(50, 0.5, VariancePattern::ALL, &mut StdRng::seed_from_u64(7));
+        let second =
+            generate_addresses(50, 0.5, VariancePattern::ALL, &mut StdRng::seed_from_u64(7));
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_generate_addresses_different_seeds_differ() {
+        let first =
+            generate_addresses(50, 0.5, VariancePattern::ALL, &mut StdRng::seed_from_u64(7));
+        let second =
+            generate_addresses(50, 0.5, VariancePattern::ALL, &mut StdRng::seed_from_u64(8));
+        assert_ne!(first, second);
+    }
+
     #[test]
     fn test_detect_delimiter_comma() {
         assert_eq!(detect_delimiter("a,b,c,d"), b',');
@@ -714,13 +1337,234 @@ mod tests {
         ];
 
         // With 0 error rate, addresses should be unchanged
-        let result = apply_variance_to_addresses(addresses.clone(), 0.0);
+        let result = apply_variance_to_addresses(
+            addresses.clone(),
+            0.0,
+            VariancePattern::ALL,
+            &mut rand::thread_rng(),
+        );
         assert_eq!(result.len(), 2);
         assert_eq!(result[0].address1, "123 Main St");
         assert_eq!(result[1].address1, "456 Oak Ave");
 
         // With 1.0 error rate, all addresses should have variance
-        let result = apply_variance_to_addresses(addresses, 1.0);
+        let result = apply_variance_to_addresses(
+            addresses,
+            1.0,
+            VariancePattern::ALL,
+            &mut rand::thread_rng(),
+        );
         assert_eq!(result.len(), 2);
     }
+
+    #[test]
+    fn test_apply_pattern_unicode_label() {
+        let clean = Address::new(
+            "123 Main St".to_string(),
+            String::new(),
+            "Chicago".to_string(),
+            "IL".to_string(),
+            "60601".to_string(),
+        );
+        let mut rng = StdRng::seed_from_u64(3);
+        for _ in 0..20 {
+            let mut address = clean.clone();
+            let label = apply_pattern(&mut address, VariancePattern::Unicode, &mut rng).unwrap();
+            assert!(label.starts_with("unicode:"), "{}", label);
+            assert_ne!(address, clean);
+            assert_eq!(address.state, "IL");
+        }
+    }
+
+    #[test]
+    fn test_apply_pattern_typo_models() {
+        let clean = Address::new(
+            "15 Main St".to_string(),
+            String::new(),
+            "Chicago".to_string(),
+            "IL".to_string(),
+            "60601".to_string(),
+        );
+        let mut rng = StdRng::seed_from_u64(5);
+        for (pattern, expected) in [
+            (VariancePattern::Typo, "typo:"),
+            (VariancePattern::KeyboardTypo, "typo:keyboard"),
+            (VariancePattern::PhoneticTypo, "typo:phonetic"),
+            (VariancePattern::OcrTypo, "typo:ocr"),
+            (VariancePattern::VoiceTypo, "typo:voice"),
+        ] {
+            let mut address = clean.clone();
+            let label = apply_pattern(&mut address, pattern, &mut rng).unwrap();
+            assert!(label.starts_with(expected), "{}", label);
+            assert_ne!(address, clean);
+            assert_eq!(
+                (address.state.as_str(), address.zip.as_str()),
+                ("IL", "60601")
+            );
+        }
+    }
+
+    #[test]
+    fn test_apply_pattern_street_line_variance() {
+        let clean = Address::new(
+            "123 North 5th Street".to_string(),
+            "Apt 4".to_string(),
+            "Chicago".to_string(),
+            "IL".to_string(),
+            "60601".to_string(),
+        );
+        let mut rng = StdRng::seed_from_u64(1);
+        for (pattern, expected) in [
+            (VariancePattern::AbbreviateSuffix, "123 North 5th St"),
+            (VariancePattern::AbbreviateDirectional, "123 N 5th Street"),
+            (VariancePattern::MoveDirectional, "123 5th Street North"),
+            (VariancePattern::Ordinal, "123 North Fifth Street"),
+            (
+                VariancePattern::FractionalHouseNumber,
+                "123 1/2 North 5th Street",
+            ),
+            (VariancePattern::RemoveHouseNumber, "North 5th Street"),
+        ] {
+            let mut address = clean.clone();
+            let label = apply_pattern(&mut address, pattern, &mut rng);
+            assert_eq!(label.as_deref(), Some(pattern.name()));
+            assert_eq!(address.address1, expected);
+        }
+
+        // Expansions have nothing to act on in a fully spelled-out line
+        for pattern in [
+            VariancePattern::ExpandSuffix,
+            VariancePattern::ExpandDirectional,
+        ] {
+            let mut address = clean.clone();
+            assert_eq!(apply_pattern(&mut address, pattern, &mut rng), None);
+        }
+    }
+
+    #[test]
+    fn test_street_line_variance_keeps_extra_spaces() {
+        let mut clean = Address::new(
+            "123 N 5th Street".to_string(),
+            "Apt 4".to_string(),
+            "Chicago".to_string(),
+            "IL".to_string(),
+            "60601".to_string(),
+        );
+        let mut rng = StdRng::seed_from_u64(1);
+        apply_pattern(&mut clean, VariancePattern::ExtraSpaces, &mut rng);
+        for pattern in [
+            VariancePattern::AbbreviateSuffix,
+            VariancePattern::NonstandardSuffix,
+            VariancePattern::ExpandDirectional,
+            VariancePattern::MoveDirectional,
+            VariancePattern::Ordinal,
+            VariancePattern::FractionalHouseNumber,
+            VariancePattern::HouseNumberRange,
+            VariancePattern::RemoveHouseNumber,
+            VariancePattern::AddPeriods,
+        ] {
+            let mut address = clean.clone();
+            assert!(apply_pattern(&mut address, pattern, &mut rng).is_some());
+            assert!(
+                address.address1.split("  ").all(|word| !word.contains(' ')),
+                "{}: {:?}",
+                pattern.name(),
+                address.address1
+            );
+        }
+    }
+
+    #[test]
+    fn test_apply_pattern_unit_in_address1() {
+        let mut address = Address::new(
+            "123 Main St".to_string(),
+            "Apt 4".to_string(),
+            "Chicago".to_string(),
+            "IL".to_string(),
+            "60601".to_string(),
+        );
+        let mut rng = StdRng::seed_from_u64(1);
+        let label = apply_pattern(&mut address, VariancePattern::UnitInAddress1, &mut rng);
+        assert_eq!(label.as_deref(), Some("unit_in_address1"));
+        assert_eq!(address.address1, "123 Main St Apt 4");
+        assert_eq!(address.address2, "");
+        // With the unit moved, there is nothing left to move
+        assert_eq!(
+            apply_pattern(&mut address, VariancePattern::UnitInAddress1, &mut rng),
+            None
+        );
+    }
+
+    fn vary_zip(zip: &str, pattern: VariancePattern) -> (String, Option<String>) {
+        let mut address = Address::new(
+            "123 Main St".to_string(),
+            String::new(),
+            "Boston".to_string(),
+            "MA".to_string(),
+            zip.to_string(),
+        );
+        let label = apply_pattern(&mut address, pattern, &mut StdRng::seed_from_u64(1));
+        (address.zip, label)
+    }
+
+    #[test]
+    fn test_split_zip() {
+        assert_eq!(split_zip("02134"), Some(("02134", None)));
+        assert_eq!(split_zip("02134-1234"), Some(("02134", Some("1234"))));
+        assert_eq!(split_zip("02134 1234"), Some(("02134", Some("1234"))));
+        assert_eq!(split_zip("021341234"), Some(("02134", Some("1234"))));
+        assert_eq!(split_zip("2134"), None);
+        assert_eq!(split_zip("02134-12"), None);
+        assert_eq!(split_zip("02134\u{a0}123"), None);
+    }
+
+    #[test]
+    fn test_zip4_patterns() {
+        let (zip, label) = vary_zip("02134", VariancePattern::AddZip4);
+        assert_eq!(label.as_deref(), Some("add_zip4"));
+        assert!(split_zip(&zip).unwrap().1.is_some() && zip.contains('-'));
+        assert_eq!(vary_zip("02134-1234", VariancePattern::AddZip4).1, None);
+
+        assert_eq!(
+            vary_zip("02134-1234", VariancePattern::RemoveZip4).0,
+            "02134"
+        );
+        assert_eq!(vary_zip("02134", VariancePattern::RemoveZip4).1, None);
+
+        assert_eq!(
+            vary_zip("02134-1234", VariancePattern::Zip4Space).0,
+            "02134 1234"
+        );
+        assert_eq!(
+            vary_zip("02134-1234", VariancePattern::Zip4NoDash).0,
+            "021341234"
+        );
+        assert_eq!(vary_zip("02134", VariancePattern::Zip4NoDash).0.len(), 9);
+        assert_eq!(vary_zip("021341234", VariancePattern::Zip4NoDash).1, None);
+    }
+
+    #[test]
+    fn test_zip_coercion_patterns() {
+        assert_eq!(
+            vary_zip("02134", VariancePattern::DropLeadingZeros),
+            ("2134".to_string(), Some("drop_leading_zeros".to_string()))
+        );
+        assert_eq!(vary_zip("60601", VariancePattern::DropLeadingZeros).1, None);
+        assert_eq!(vary_zip("02134", VariancePattern::NumericZip).0, "2134.0");
+        assert_eq!(vary_zip("60601", VariancePattern::NumericZip).0, "60601.0");
+        assert_eq!(vary_zip("60601-1234", VariancePattern::NumericZip).1, None);
+    }
+
+    #[test]
+    fn test_neighboring_zip_pattern_keeps_plus4() {
+        assert_eq!(
+            vary_zip("02108", VariancePattern::NeighboringZip),
+            ("02139".to_string(), Some("neighboring_zip".to_string()))
+        );
+        assert_eq!(
+            vary_zip("02108-1234", VariancePattern::NeighboringZip).0,
+            "02139-1234"
+        );
+        assert_eq!(vary_zip("", VariancePattern::NeighboringZip).1, None);
+    }
 }
@@ -0,0 +1,247 @@
+//! Duplicate clusters for entity-resolution benchmarking.
+//!
+//! A cluster is one clean entity emitted several times, each copy varied
+//! independently. Ground truth links the copies through an entity id, so a
+//! deduplication engine's output can be scored against it.
+
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+use crate::generators::Traced;
+
+/// A weighted distribution of cluster sizes (copies per entity).
+///
+/// Parsed from either a single size (`3`: every entity appears three
+/// times) or comma-separated `size:weight` pairs (`1:50,2:30,3:15,5:5`).
+///
+/// # Examples
+/// ```
+/// use rust_faker::generators::clusters::ClusterSizes;
+///
+/// let sizes: ClusterSizes = "1:50,2:30,3:20".parse().unwrap();
+/// assert_eq!(sizes.max_size(), 3);
+/// assert!("0".parse::<ClusterSizes>().is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ClusterSizes {
+    sizes: Vec<usize>,
+    index: WeightedIndex<f64>,
+}
+
+impl ClusterSizes {
+    /// Builds a distribution from `(size, weight)` pairs.
+    pub fn new(weighted: &[(usize, f64)]) -> Result<Self> {
+        if let Some((size, _)) = weighted.iter().find(|(size, _)| *size == 0) {
+            return Err(Error::InvalidArgument(format!(
+                "Cluster size must be at least 1, got {}",
+                size
+            )));
+        }
+        if let Some((size, weight)) = weighted
+            .iter()
+            .find(|(_, weight)| !weight.is_finite() || *weight < 0.0)
+        {
+            return Err(Error::InvalidArgument(format!(
+                "Cluster size {} has invalid weight {}",
+                size, weight
+            )));
+        }
+        let index =
+            WeightedIndex::new(weighted.iter().map(|(_, weight)| *weight)).map_err(|_| {
+                Error::InvalidArgument(
+                    "Cluster sizes need at least one positive weight".to_string(),
+                )
+            })?;
+
+        Ok(Self {
+            sizes: weighted.iter().map(|(size, _)| *size).collect(),
+            index,
+        })
+    }
+
+    /// Every entity appears exactly `size` times.
+    pub fn fixed(size: usize) -> Result<Self> {
+        Self::new(&[(size, 1.0)])
+    }
+
+    /// The largest size this distribution can produce.
+    pub fn max_size(&self) -> usize {
+        self.sizes.iter().copied().max().unwrap_or(1)
+    }
+
+    /// Draws a cluster size.
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
+        self.sizes[self.index.sample(rng)]
+    }
+}
+
+impl FromStr for ClusterSizes {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || {
+            Error::InvalidArgument(format!(
+                "Invalid cluster sizes '{}'. Use a size (3) or size:weight pairs (1:50,2:30,3:20)",
+                s
+            ))
+        };
+
+        let s = s.trim();
+        if let Ok(size) = s.parse::<usize>() {
+            return Self::fixed(size);
+        }
+
+        let weighted = s
+            .split(',')
+            .map(|pair| {
+                let (size, weight) = pair.split_once(':').ok_or_else(invalid)?;
+                let size = size.trim().parse().map_err(|_| invalid())?;
+                let weight = weight.trim().parse().map_err(|_| invalid())?;
+                Ok((size, weight))
+            })
+            .collect::<Result<Vec<(usize, f64)>>>()?;
+        Self::new(&weighted)
+    }
+}
+
+/// One copy of an entity.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Clustered<T> {
+    /// 0-based index of the entity within the batch that produced it
+    pub entity: usize,
+    pub traced: Traced<T>,
+}
+
+/// Generates `entities` clean records and emits each one a number of times
+/// drawn from `sizes`, passing every copy through `vary` separately.
+///
+/// Copies are shuffled so duplicates are not adjacent. Each copy's
+/// [`Traced::clean`] is the entity's clean record, so all copies of an
+/// entity share it.
+///
+/// # Examples
+/// ```
+/// use rust_faker::generators::clusters::{generate_clusters, ClusterSizes};
+/// use rust_faker::generators::create_rng;
+/// use rust_faker::generators::names::{generate_clean_name, vary_name, VariancePattern};
+/// use rust_faker::generators::NamedPattern;
+/// use rust_faker::profile::VarianceProfile;
+///
+/// let mut rng = create_rng(Some(42));
+/// let profile = VarianceProfile::uniform(VariancePattern::ALL);
+/// let rows = generate_clusters(
+///     10,
+///     &ClusterSizes::fixed(3).unwrap(),
+///     &mut rng,
+///     |rng| generate_clean_name(rng),
+///     |name, rng| vary_name(name, 0.8, &profile, rng),
+/// );
+/// assert_eq!(rows.len(), 30);
+/// ```
+pub fn generate_clusters<T, R, C, V>(
+    entities: usize,
+    sizes: &ClusterSizes,
+    rng: &mut R,
+    mut clean: C,
+    mut vary: V,
+) -> Vec<Clustered<T>>
+where
+    T: Clone,
+    R: Rng + ?Sized,
+    C: FnMut(&mut R) -> T,
+    V: FnMut(T, &mut R) -> Traced<T>,
+{
+    let mut rows = Vec::with_capacity(entities);
+    for entity in 0..entities {
+        let record = clean(rng);
+        for _ in 0..sizes.sample(rng) {
+            rows.push(Clustered {
+                entity,
+                traced: vary(record.clone(), rng),
+            });
+        }
+    }
+    rows.shuffle(rng);
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_parse_fixed_size() {
+        let sizes: ClusterSizes = "4".parse().unwrap();
+        let mut rng = StdRng::seed_from_u64(1);
+        assert!((0..20).all(|_| sizes.sample(&mut rng) == 4));
+    }
+
+    #[test]
+    fn test_parse_weighted_sizes() {
+        let sizes: ClusterSizes = "1:0, 2:1".parse().unwrap();
+        let mut rng = StdRng::seed_from_u64(1);
+        assert!((0..20).all(|_| sizes.sample(&mut rng) == 2));
+        assert_eq!(sizes.max_size(), 2);
+    }
+
+    #[test]
+    fn test_parse_invalid_sizes() {
+        for bad in ["", "0", "2:x", "1:50,2", "0:1", "1:-1", "1:0,2:0"] {
+            assert!(
+                bad.parse::<ClusterSizes>().is_err(),
+                "{:?} should fail",
+                bad
+            );
+        }
+    }
+
+    #[test]
+    fn test_clusters_share_clean_record() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut next = 0;
+        let rows = generate_clusters(
+            50,
+            &"1:1,2:1,3:1".parse().unwrap(),
+            &mut rng,
+            |_| {
+                next += 1;
+                next
+            },
+            |value, _| Traced {
+                record: value * 100,
+                clean: value,
+                variances: vec!["scaled".to_string()],
+            },
+        );
+
+        let mut sizes: HashMap<usize, usize> = HashMap::new();
+        for row in &rows {
+            assert_eq!(row.traced.clean, row.entity + 1);
+            *sizes.entry(row.entity).or_default() += 1;
+        }
+        assert_eq!(sizes.len(), 50);
+        assert!(sizes.values().all(|size| (1..=3).contains(size)));
+    }
+
+    #[test]
+    fn test_clusters_are_shuffled() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let rows = generate_clusters(
+            100,
+            &ClusterSizes::fixed(2).unwrap(),
+            &mut rng,
+            |_| (),
+            |record, _| Traced::unchanged(record),
+        );
+        let entities: Vec<usize> = rows.iter().map(|row| row.entity).collect();
+        let mut sorted = entities.clone();
+        sorted.sort();
+        assert_ne!(entities, sorted);
+    }
+}
@@ -0,0 +1,654 @@
+//! Company records: an organization name paired with an address.
+//!
+//! Business names are matched on more than spelling: the same company shows
+//! up as "Hamill Logistics Inc.", "THE HAMILL LOGISTICS, INCORPORATED" and
+//! "Hamill Logistics DBA Summit Freight". The name patterns reproduce those
+//! forms; the address is varied with the address patterns, as for people.
+
+use fake::faker::name::en::LastName;
+use fake::Fake;
+use rand::Rng;
+use serde::Serialize;
+use std::fmt;
+
+use crate::generators::addresses::{self, generate_clean_address, Address};
+use crate::generators::{prefixed, NamedPattern, Traced};
+use crate::profile::VarianceProfile;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Company {
+    pub company_name: String,
+    #[serde(flatten)]
+    pub address: Address,
+}
+
+impl Company {
+    pub fn to_record(&self) -> Vec<String> {
+        let mut record = vec![self.company_name.clone()];
+        record.extend(self.address.to_record());
+        record
+    }
+}
+
+/// Equivalent spellings of each legal form. The first spelling is the one
+/// clean names use.
+pub const LEGAL_SUFFIXES: &[&[&str]] = &[
+    &["Inc.", "Inc", "Incorporated"],
+    &["LLC", "L.L.C.", "Limited Liability Company"],
+    &["Corp.", "Corp", "Corporation"],
+    &["Co.", "Co", "Company"],
+    &["Ltd.", "Ltd", "Limited"],
+    &["LLP", "L.L.P."],
+];
+
+/// Words that end a company name and say what the company does.
+const INDUSTRY_WORDS: &[&str] = &[
+    "Associates",
+    "Capital",
+    "Construction",
+    "Consulting",
+    "Dental",
+    "Distribution",
+    "Engineering",
+    "Foods",
+    "Holdings",
+    "Industries",
+    "Insurance",
+    "Laboratories",
+    "Logistics",
+    "Management",
+    "Manufacturing",
+    "Motors",
+    "Partners",
+    "Plumbing",
+    "Realty",
+    "Services",
+    "Solutions",
+    "Supply",
+    "Systems",
+    "Technologies",
+];
+
+/// Invented brand words for names that are not built from a surname.
+const BRAND_WORDS: &[&str] = &[
+    "Atlas",
+    "Bluewater",
+    "Cardinal",
+    "Evergreen",
+    "Frontier",
+    "Granite",
+    "Harbor",
+    "Heritage",
+    "Keystone",
+    "Lakeside",
+    "Liberty",
+    "Mountain",
+    "Northstar",
+    "Pioneer",
+    "Prairie",
+    "Redwood",
+    "Riverbend",
+    "Summit",
+];
+
+/// Words that put a brand on a map.
+const REGION_WORDS: &[&str] = &[
+    "American",
+    "Atlantic",
+    "Central",
+    "General",
+    "International",
+    "Midwest",
+    "National",
+    "Pacific",
+    "Southern",
+    "United",
+];
+
+/// Common abbreviations of words in business names.
+const ABBREVIATIONS: &[(&str, &str)] = &[
+    ("American", "Amer"),
+    ("Associates", "Assoc"),
+    ("Brothers", "Bros"),
+    ("Construction", "Constr"),
+    ("Distribution", "Dist"),
+    ("Engineering", "Engrg"),
+    ("Holdings", "Hldgs"),
+    ("Industries", "Inds"),
+    ("Insurance", "Ins"),
+    ("International", "Intl"),
+    ("Laboratories", "Labs"),
+    ("Management", "Mgmt"),
+    ("Manufacturing", "Mfg"),
+    ("Mountain", "Mtn"),
+    ("National", "Natl"),
+    ("Services", "Svcs"),
+    ("Systems", "Sys"),
+    ("Technologies", "Tech"),
+];
+
+/// Markers that introduce a "doing business as" trade name.
+const DBA_MARKERS: &[&str] = &["DBA", "dba", "d/b/a", "D/B/A"];
+
+fn pick<R: Rng + ?Sized>(words: &[&'static str], rng: &mut R) -> &'static str {
+    words[rng.gen_range(0..words.len())]
+}
+
+/// A trade name such as "Summit Logistics".
+fn trade_name<R: Rng + ?Sized>(rng: &mut R) -> String {
+    format!("{} {}", pick(BRAND_WORDS, rng), pick(INDUSTRY_WORDS, rng))
+}
+
+/// Generates a clean company name such as "Hamill & Davis LLP" or
+/// "National Keystone Insurance Corp.". Most names carry the first
+/// spelling of a legal suffix; some have none.
+pub fn generate_clean_company_name<R: Rng + ?Sized>(rng: &mut R) -> String {
+    let base = match rng.gen_range(0..5) {
+        0 => {
+            let surname: String = LastName().fake_with_rng(rng);
+            format!("{} {}", surname, pick(INDUSTRY_WORDS, rng))
+        }
+        1 => {
+            let first: String = LastName().fake_with_rng(rng);
+            let second: String = LastName().fake_with_rng(rng);
+            format!("{} & {}", first, second)
+        }
+        2 => {
+            let surname: String = LastName().fake_with_rng(rng);
+            format!("{} Brothers {}", surname, pick(INDUSTRY_WORDS, rng))
+        }
+        3 => trade_name(rng),
+        _ => format!("{} {}", pick(REGION_WORDS, rng), trade_name(rng)),
+    };
+
+    if rng.gen_bool(0.85) {
+        let forms = LEGAL_SUFFIXES[rng.gen_range(0..LEGAL_SUFFIXES.len())];
+        format!("{} {}", base, forms[0])
+    } else {
+        base
+    }
+}
+
+pub fn generate_clean_company<R: Rng + ?Sized>(rng: &mut R) -> Company {
+    Company {
+        company_name: generate_clean_company_name(rng),
+        address: generate_clean_address(rng),
+    }
+}
+
+/// Per-component variance for company records.
+pub struct CompanyVariance {
+    pub names: VarianceProfile<VariancePattern>,
+    pub name_error_rate: f64,
+    pub addresses: VarianceProfile<addresses::VariancePattern>,
+    pub address_error_rate: f64,
+}
+
+impl CompanyVariance {
+    /// Every pattern, weighted equally, at one error rate.
+    pub fn uniform(error_rate: f64) -> Self {
+        Self {
+            names: VarianceProfile::uniform(VariancePattern::ALL),
+            name_error_rate: error_rate,
+            addresses: VarianceProfile::uniform(addresses::VariancePattern::ALL),
+            address_error_rate: error_rate,
+        }
+    }
+}
+
+/// Varies the name and address of a company independently.
+///
+/// Ground-truth variance names are prefixed with the component they
+/// changed, e.g. `company.legal_suffix` or `address.remove_zip`.
+pub fn vary_company<R: Rng + ?Sized>(
+    company: Company,
+    variance: &CompanyVariance,
+    rng: &mut R,
+) -> Traced<Company> {
+    let name = variance.names.vary(
+        company.company_name.clone(),
+        variance.name_error_rate,
+        rng,
+        apply_pattern,
+    );
+    let address = variance.addresses.vary(
+        company.address.clone(),
+        variance.address_error_rate,
+        rng,
+        addresses::apply_pattern,
+    );
+
+    Traced {
+        record: Company {
+            company_name: name.record,
+            address: address.record,
+        },
+        clean: company,
+        variances: prefixed("company", name.variances)
+            .chain(prefixed("address", address.variances))
+            .collect(),
+    }
+}
+
+/// A single, individually selectable company-name variance pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VariancePattern {
+    /// Another spelling of the legal form: "Inc." -> ", Incorporated"
+    LegalSuffix,
+    /// "Hamill Logistics Inc." -> "Hamill Logistics"
+    MissingLegalSuffix,
+    /// "The Hamill Logistics Inc."
+    LeadingThe,
+    /// "&" and "and" exchanged: "Hamill and Davis LLP"
+    Ampersand,
+    /// A trade name appended: "Hamill Logistics Inc. DBA Summit Foods"
+    DbaClause,
+    /// Periods, commas and apostrophes removed: "Hamill Logistics Inc"
+    StripPunctuation,
+    /// A word abbreviated: "National" -> "Natl"
+    Abbreviation,
+    /// A name of three or more words reduced to its initials: "NKI Corp."
+    Initialism,
+    /// "HAMILL LOGISTICS INC."
+    Uppercase,
+}
+
+impl NamedPattern for VariancePattern {
+    const ALL: &'static [Self] = &[
+        VariancePattern::LegalSuffix,
+        VariancePattern::MissingLegalSuffix,
+        VariancePattern::LeadingThe,
+        VariancePattern::Ampersand,
+        VariancePattern::DbaClause,
+        VariancePattern::StripPunctuation,
+        VariancePattern::Abbreviation,
+        VariancePattern::Initialism,
+        VariancePattern::Uppercase,
+    ];
+
+    fn name(&self) -> &'static str {
+        match self {
+            VariancePattern::LegalSuffix => "legal_suffix",
+            VariancePattern::MissingLegalSuffix => "missing_legal_suffix",
+            VariancePattern::LeadingThe => "leading_the",
+            VariancePattern::Ampersand => "ampersand",
+            VariancePattern::DbaClause => "dba_clause",
+            VariancePattern::StripPunctuation => "strip_punctuation",
+            VariancePattern::Abbreviation => "abbreviation",
+            VariancePattern::Initialism => "initialism",
+            VariancePattern::Uppercase => "uppercase",
+        }
+    }
+
+    fn fields(&self) -> &'static [&'static str] {
+        &["company_name"]
+    }
+}
+
+impl fmt::Display for VariancePattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Splits a company name into its base name, its legal suffix (with the
+/// separator before it) and its DBA clause (with the space before it).
+/// Missing parts are empty.
+fn name_parts(name: &str) -> (&str, &str, &str) {
+    let lower = name.to_ascii_lowercase();
+    let dba_at = [" dba ", " d/b/a "]
+        .iter()
+        .filter_map(|marker| lower.find(marker))
+        .min()
+        .unwrap_or(name.len());
+    let (company, dba) = name.split_at(dba_at);
+    let lower = &lower[..dba_at];
+
+    let suffix_at = LEGAL_SUFFIXES
+        .iter()
+        .flat_map(|forms| forms.iter())
+        .filter(|form| {
+            lower.len() > form.len() + 1
+                && lower.ends_with(&form.to_ascii_lowercase())
+                && lower[..lower.len() - form.len()].ends_with(' ')
+        })
+        .map(|form| company.len() - form.len() - 1)
+        .min();
+
+    match suffix_at {
+        Some(i) => {
+            let i = if company[..i].ends_with(',') {
+                i - 1
+            } else {
+                i
+            };
+            (&company[..i], &company[i..], dba)
+        }
+        None => (company, "", dba),
+    }
+}
+
+/// The spellings equivalent to a legal suffix, matched case-insensitively.
+fn legal_forms(suffix: &str) -> Option<&'static [&'static str]> {
+    LEGAL_SUFFIXES
+        .iter()
+        .copied()
+        .find(|forms| forms.iter().any(|form| form.eq_ignore_ascii_case(suffix)))
+}
+
+/// Applies a single variance pattern to a company name in place.
+///
+/// Returns the ground-truth label of the change, or `None` if the pattern
+/// had nothing to act on (e.g. changing the legal suffix of a name that has
+/// none).
+pub fn apply_pattern<R: Rng + ?Sized>(
+    name: &mut String,
+    pattern: VariancePattern,
+    rng: &mut R,
+) -> Option<String> {
+    let before = name.clone();
+    let (base, suffix, dba) = name_parts(&before);
+
+    match pattern {
+        VariancePattern::LegalSuffix => {
+            let current = suffix.trim_start_matches(',').trim();
+            if let Some(forms) = legal_forms(current) {
+                let others: Vec<&str> = forms
+                    .iter()
+                    .copied()
+                    .filter(|form| !form.eq_ignore_ascii_case(current))
+                    .collect();
+                let form = pick(&others, rng);
+                let separator = if rng.gen_bool(0.5) { ", " } else { " " };
+                *name = format!("{}{}{}{}", base, separator, form, dba);
+            }
+        }
+        VariancePattern::MissingLegalSuffix => {
+            *name = format!("{}{}", base, dba);
+        }
+        VariancePattern::LeadingThe => {
+            if !base.to_ascii_lowercase().starts_with("the ") {
+                let the = if base.chars().any(|c| c.is_lowercase()) {
+                    "The"
+                } else {
+                    "THE"
+                };
+                *name = format!("{} {}", the, name);
+            }
+        }
+        VariancePattern::Ampersand => {
+            let swapped = if base.contains(" & ") {
+                Some(base.replacen(" & ", " and ", 1))
+            } else if base.contains(" and ") {
+                Some(base.replacen(" and ", " & ", 1))
+            } else {
+                base.contains(" AND ")
+                    .then(|| base.replacen(" AND ", " & ", 1))
+            };
+            if let Some(base) = swapped {
+                *name = format!("{}{}{}", base, suffix, dba);
+            }
+        }
+        VariancePattern::DbaClause => {
+            if dba.is_empty() {
+                let marker = pick(DBA_MARKERS, rng);
+                name.push_str(&format!(" {} {}", marker, trade_name(rng)));
+            }
+        }
+        VariancePattern::StripPunctuation => {
+            name.retain(|c| !matches!(c, '.' | ',' | '\''));
+        }
+        VariancePattern::Abbreviation => {
+            let mut words: Vec<String> = base.split(' ').map(str::to_string).collect();
+            let candidates: Vec<(usize, &str)> = words
+                .iter()
+                .enumerate()
+                .filter_map(|(i, word)| {
+                    ABBREVIATIONS
+                        .iter()
+                        .find(|(full, _)| full.eq_ignore_ascii_case(word))
+                        .map(|(_, short)| (i, *short))
+                })
+                .collect();
+            if !candidates.is_empty() {
+                let (i, short) = candidates[rng.gen_range(0..candidates.len())];
+                let mut short = short.to_string();
+                if words[i].chars().all(|c| !c.is_lowercase()) {
+                    short = short.to_uppercase();
+                }
+                if rng.gen_bool(0.5) {
+                    short.push('.');
+                }
+                words[i] = short;
+                *name = format!("{}{}{}", words.join(" "), suffix, dba);
+            }
+        }
+        VariancePattern::Initialism => {
+            let words: Vec<&str> = base
+                .split(' ')
+                .filter(|word| {
+                    !word.is_empty()
+                        && !["&", "and", "the"].contains(&word.to_ascii_lowercase().as_str())
+                })
+                .collect();
+            if words.len() >= 3 {
+                let initials: String = words
+                    .iter()
+                    .filter_map(|word| word.chars().next())
+                    .flat_map(char::to_uppercase)
+                    .collect();
+                *name = format!("{}{}{}", initials, suffix, dba);
+            }
+        }
+        VariancePattern::Uppercase => {
+            *name = name.to_uppercase();
+        }
+    }
+
+    if *name == before {
+        None
+    } else {
+        Some(pattern.name().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn apply(value: &str, pattern: VariancePattern) -> (String, Option<String>) {
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut name = value.to_string();
+        let label = apply_pattern(&mut name, pattern, &mut rng);
+        (name, label)
+    }
+
+    #[test]
+    fn test_clean_names_use_canonical_suffixes() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let canonical: Vec<&str> = LEGAL_SUFFIXES.iter().map(|forms| forms[0]).collect();
+        for _ in 0..200 {
+            let name = generate_clean_company_name(&mut rng);
+            let (base, suffix, dba) = name_parts(&name);
+            assert!(!base.is_empty(), "{}", name);
+            assert!(dba.is_empty(), "{}", name);
+            if !suffix.is_empty() {
+                assert!(canonical.contains(&suffix.trim()), "{}", name);
+            }
+        }
+    }
+
+    #[test]
+    fn test_name_parts() {
+        assert_eq!(
+            name_parts("Hamill Logistics, Inc. DBA Summit Foods"),
+            ("Hamill Logistics", ", Inc.", " DBA Summit Foods")
+        );
+        assert_eq!(
+            name_parts("Hamill Logistics Limited Liability Company"),
+            ("Hamill Logistics", " Limited Liability Company", "")
+        );
+        assert_eq!(name_parts("Hamill & Davis"), ("Hamill & Davis", "", ""));
+        assert_eq!(name_parts("HAMILL CO"), ("HAMILL", " CO", ""));
+    }
+
+    #[test]
+    fn test_legal_suffix_uses_equivalent_form() {
+        let (value, label) = apply("Hamill Logistics LLC", VariancePattern::LegalSuffix);
+        assert!(
+            [
+                "Hamill Logistics L.L.C.",
+                "Hamill Logistics, L.L.C.",
+                "Hamill Logistics Limited Liability Company",
+                "Hamill Logistics, Limited Liability Company",
+            ]
+            .contains(&value.as_str()),
+            "{}",
+            value
+        );
+        assert_eq!(label.as_deref(), Some("legal_suffix"));
+        assert_eq!(
+            apply("Hamill & Davis", VariancePattern::LegalSuffix).1,
+            None
+        );
+    }
+
+    #[test]
+    fn test_missing_legal_suffix_keeps_dba() {
+        assert_eq!(
+            apply(
+                "Hamill Logistics Inc. dba Summit Foods",
+                VariancePattern::MissingLegalSuffix
+            )
+            .0,
+            "Hamill Logistics dba Summit Foods"
+        );
+        assert_eq!(
+            apply("Summit Foods", VariancePattern::MissingLegalSuffix).1,
+            None
+        );
+    }
+
+    #[test]
+    fn test_leading_the() {
+        assert_eq!(
+            apply("Summit Foods Inc.", VariancePattern::LeadingThe).0,
+            "The Summit Foods Inc."
+        );
+        assert_eq!(
+            apply("SUMMIT FOODS", VariancePattern::LeadingThe).0,
+            "THE SUMMIT FOODS"
+        );
+        assert_eq!(
+            apply("The Summit Foods", VariancePattern::LeadingThe).1,
+            None
+        );
+    }
+
+    #[test]
+    fn test_ampersand_swaps_both_ways() {
+        assert_eq!(
+            apply("Hamill & Davis LLP", VariancePattern::Ampersand).0,
+            "Hamill and Davis LLP"
+        );
+        assert_eq!(
+            apply("Hamill and Davis LLP", VariancePattern::Ampersand).0,
+            "Hamill & Davis LLP"
+        );
+        assert_eq!(apply("Summit Foods", VariancePattern::Ampersand).1, None);
+    }
+
+    #[test]
+    fn test_dba_clause_is_added_once() {
+        let (value, label) = apply("Hamill Logistics Inc.", VariancePattern::DbaClause);
+        let (base, suffix, dba) = name_parts(&value);
+        assert_eq!((base, suffix), ("Hamill Logistics", " Inc."));
+        assert!(!dba.is_empty(), "{}", value);
+        assert_eq!(label.as_deref(), Some("dba_clause"));
+        assert_eq!(apply(&value, VariancePattern::DbaClause).1, None);
+    }
+
+    #[test]
+    fn test_strip_punctuation() {
+        assert_eq!(
+            apply(
+                "O'Hara Logistics, L.L.C.",
+                VariancePattern::StripPunctuation
+            )
+            .0,
+            "OHara Logistics LLC"
+        );
+        assert_eq!(
+            apply("Summit Foods LLC", VariancePattern::StripPunctuation).1,
+            None
+        );
+    }
+
+    #[test]
+    fn test_abbreviation() {
+        let (value, label) = apply(
+            "National Keystone Insurance Corp.",
+            VariancePattern::Abbreviation,
+        );
+        assert!(
+            [
+                "Natl Keystone Insurance Corp.",
+                "Natl. Keystone Insurance Corp.",
+                "National Keystone Ins Corp.",
+                "National Keystone Ins. Corp.",
+            ]
+            .contains(&value.as_str()),
+            "{}",
+            value
+        );
+        assert_eq!(label.as_deref(), Some("abbreviation"));
+        assert_eq!(apply("Summit Foods", VariancePattern::Abbreviation).1, None);
+    }
+
+    #[test]
+    fn test_initialism() {
+        assert_eq!(
+            apply(
+                "National Keystone Insurance Corp.",
+                VariancePattern::Initialism
+            )
+            .0,
+            "NKI Corp."
+        );
+        assert_eq!(
+            apply("Hamill & Davis LLP", VariancePattern::Initialism).1,
+            None
+        );
+    }
+
+    #[test]
+    fn test_variances_are_prefixed_by_component() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let variance = CompanyVariance::uniform(1.0);
+        for _ in 0..50 {
+            let clean = generate_clean_company(&mut rng);
+            let traced = vary_company(clean, &variance, &mut rng);
+            for label in &traced.variances {
+                assert!(
+                    label.starts_with("company.") || label.starts_with("address."),
+                    "{}",
+                    label
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_zero_error_rate_keeps_companies_clean() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let variance = CompanyVariance::uniform(0.0);
+        for _ in 0..50 {
+            let clean = generate_clean_company(&mut rng);
+            let traced = vary_company(clean, &variance, &mut rng);
+            assert_eq!(traced.record, traced.clean);
+            assert!(traced.variances.is_empty());
+        }
+    }
+}
@@ -0,0 +1,631 @@
+//! Date-of-birth generation and variance.
+//!
+//! Birth dates are drawn from a weighted age distribution relative to a
+//! reference date and written as `MM/DD/YYYY`. The variance patterns
+//! reproduce the formats partners actually send: day and month swapped,
+//! two-digit years, ISO 8601, Excel serial numbers, impossible calendar
+//! dates and placeholder values.
+
+use chrono::{Datelike, Local, Months, NaiveDate};
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::Rng;
+use serde::Serialize;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+use crate::generators::{NamedPattern, Traced};
+use crate::profile::VarianceProfile;
+
+/// The `chrono` format of clean dates: `03/07/1985`.
+pub const DATE_FORMAT: &str = "%m/%d/%Y";
+
+/// The oldest age an [`AgeDistribution`] accepts.
+pub const MAX_AGE: u32 = 120;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Date {
+    pub date: String,
+}
+
+impl Date {
+    pub fn new(date: String) -> Self {
+        Self { date }
+    }
+
+    pub fn to_record(&self) -> Vec<String> {
+        vec![self.date.clone()]
+    }
+}
+
+/// A weighted distribution of ages, in whole years.
+///
+/// Parsed from either a single range (`18-90`: every age equally likely),
+/// a single age (`30`), or comma-separated `min-max:weight` pairs
+/// (`18-29:20,30-64:60,65-90:20`). Ages within a range are uniform.
+///
+/// # Examples
+/// ```
+/// use rust_faker::generators::dates::AgeDistribution;
+///
+/// let ages: AgeDistribution = "18-29:20,30-64:60,65-90:20".parse().unwrap();
+/// assert_eq!(ages.max_age(), 90);
+/// assert!("90-18".parse::<AgeDistribution>().is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct AgeDistribution {
+    ranges: Vec<(u32, u32)>,
+    index: WeightedIndex<f64>,
+}
+
+impl AgeDistribution {
+    /// Builds a distribution from `((min, max), weight)` pairs, where both
+    /// ends of each range are inclusive.
+    pub fn new(weighted: &[((u32, u32), f64)]) -> Result<Self> {
+        if let Some(((min, max), _)) = weighted
+            .iter()
+            .find(|((min, max), _)| min > max || *max > MAX_AGE)
+        {
+            return Err(Error::InvalidArgument(format!(
+                "Age range {}-{} must run from a younger to an older age, up to {}",
+                min, max, MAX_AGE
+            )));
+        }
+        if let Some(((min, max), weight)) = weighted
+            .iter()
+            .find(|(_, weight)| !weight.is_finite() || *weight < 0.0)
+        {
+            return Err(Error::InvalidArgument(format!(
+                "Age range {}-{} has invalid weight {}",
+                min, max, weight
+            )));
+        }
+        let index =
+            WeightedIndex::new(weighted.iter().map(|(_, weight)| *weight)).map_err(|_| {
+                Error::InvalidArgument("Age ranges need at least one positive weight".to_string())
+            })?;
+
+        Ok(Self {
+            ranges: weighted.iter().map(|(range, _)| *range).collect(),
+            index,
+        })
+    }
+
+    /// Every age from `min` to `max` (inclusive) equally likely.
+    pub fn uniform(min: u32, max: u32) -> Result<Self> {
+        Self::new(&[((min, max), 1.0)])
+    }
+
+    /// The oldest age this distribution can produce.
+    pub fn max_age(&self) -> u32 {
+        self.ranges.iter().map(|(_, max)| *max).max().unwrap_or(0)
+    }
+
+    /// Draws an age range, weighted.
+    fn sample_range<R: Rng + ?Sized>(&self, rng: &mut R) -> (u32, u32) {
+        self.ranges[self.index.sample(rng)]
+    }
+}
+
+impl Default for AgeDistribution {
+    /// Adults, weighted roughly like the US adult population:
+    /// `18-29:21,30-44:25,45-64:33,65-90:21`.
+    fn default() -> Self {
+        Self::new(&[
+            ((18, 29), 21.0),
+            ((30, 44), 25.0),
+            ((45, 64), 33.0),
+            ((65, 90), 21.0),
+        ])
+        .expect("valid default age distribution")
+    }
+}
+
+impl FromStr for AgeDistribution {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || {
+            Error::InvalidArgument(format!(
+                "Invalid age distribution '{}'. Use an age range (18-90) or range:weight pairs (18-29:20,30-64:60,65-90:20)",
+                s
+            ))
+        };
+        let parse_range = |range: &str| -> Result<(u32, u32)> {
+            match range.split_once('-') {
+                Some((min, max)) => Ok((
+                    min.trim().parse().map_err(|_| invalid())?,
+                    max.trim().parse().map_err(|_| invalid())?,
+                )),
+                None => {
+                    let age = range.trim().parse().map_err(|_| invalid())?;
+                    Ok((age, age))
+                }
+            }
+        };
+
+        let s = s.trim();
+        if !s.contains([':', ',']) {
+            return Self::new(&[(parse_range(s)?, 1.0)]);
+        }
+
+        let weighted = s
+            .split(',')
+            .map(|pair| {
+                let (range, weight) = pair.split_once(':').ok_or_else(invalid)?;
+                let weight = weight.trim().parse().map_err(|_| invalid())?;
+                Ok((parse_range(range)?, weight))
+            })
+            .collect::<Result<Vec<((u32, u32), f64)>>>()?;
+        Self::new(&weighted)
+    }
+}
+
+/// Birth dates for people whose ages follow an [`AgeDistribution`] on a
+/// reference date.
+#[derive(Debug, Clone)]
+pub struct BirthDates {
+    ages: AgeDistribution,
+    as_of: NaiveDate,
+}
+
+impl BirthDates {
+    /// Measures `ages` on `as_of`, which must leave room for a birth date at
+    /// the oldest age and no later than year 9999, so every date still
+    /// writes as `MM/DD/YYYY`.
+    pub fn new(ages: AgeDistribution, as_of: NaiveDate) -> Result<Self> {
+        let max_age = ages.max_age();
+        let room = years_before(as_of, max_age + 1).is_ok_and(|earliest| earliest.year() >= 1);
+        if !room || as_of.year() > 9999 {
+            return Err(Error::InvalidArgument(format!(
+                "Reference date {} must fall between year {} and 9999 to leave room for ages up to {}",
+                as_of,
+                max_age + 2,
+                max_age
+            )));
+        }
+        Ok(Self { ages, as_of })
+    }
+
+    /// The age distribution birth dates follow
+    pub fn ages(&self) -> &AgeDistribution {
+        &self.ages
+    }
+
+    /// The date ages are measured on
+    pub fn as_of(&self) -> NaiveDate {
+        self.as_of
+    }
+
+    /// Draws a birth date: an age range by weight, then a day uniformly
+    /// from every birthday that gives an age in that range on `as_of`.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use rust_faker::generators::create_rng;
+    /// use rust_faker::generators::dates::{AgeDistribution, BirthDates};
+    ///
+    /// let as_of = NaiveDate::from_ymd_opt(2024, 6, 15).unwrap();
+    /// let birth_dates = BirthDates::new(AgeDistribution::uniform(30, 30).unwrap(), as_of).unwrap();
+    /// let dob = birth_dates.generate(&mut create_rng(Some(42)));
+    /// assert!(dob > NaiveDate::from_ymd_opt(1993, 6, 15).unwrap());
+    /// assert!(dob <= NaiveDate::from_ymd_opt(1994, 6, 15).unwrap());
+    /// ```
+    pub fn generate<R: Rng + ?Sized>(&self, rng: &mut R) -> NaiveDate {
+        let (min, max) = self.ages.sample_range(rng);
+        let latest = years_before(self.as_of, min).expect("checked by BirthDates::new");
+        let earliest = years_before(self.as_of, max + 1)
+            .ok()
+            .and_then(|date| date.succ_opt())
+            .expect("checked by BirthDates::new");
+        let days = (latest - earliest).num_days();
+        earliest + chrono::Duration::days(rng.gen_range(0..=days))
+    }
+}
+
+impl Default for BirthDates {
+    /// The default age distribution, as of today.
+    fn default() -> Self {
+        Self::new(AgeDistribution::default(), Local::now().date_naive())
+            .expect("today leaves room for the default ages")
+    }
+}
+
+/// `date` moved back `years` years; February 29 becomes February 28.
+fn years_before(date: NaiveDate, years: u32) -> Result<NaiveDate> {
+    date.checked_sub_months(Months::new(12 * years))
+        .ok_or_else(|| {
+            Error::InvalidArgument(format!("{} is too early to go back {} years", date, years))
+        })
+}
+
+/// Generates a clean date of birth formatted as `MM/DD/YYYY`.
+pub fn generate_clean_date<R: Rng + ?Sized>(birth_dates: &BirthDates, rng: &mut R) -> String {
+    birth_dates.generate(rng).format(DATE_FORMAT).to_string()
+}
+
+/// Applies profile variance to a single date: record-level variance with
+/// probability `error_rate`, then any per-field error rates.
+///
+/// A placeholder overwrites the whole value, so it is only drawn before any
+/// other pattern has applied; otherwise the earlier labels would describe
+/// changes that are no longer in the record.
+pub fn vary_date<R: Rng + ?Sized>(
+    date: Date,
+    error_rate: f64,
+    profile: &VarianceProfile<VariancePattern>,
+    rng: &mut R,
+) -> Traced<Date> {
+    let mut varied = false;
+    profile.vary(date, error_rate, rng, |date, pattern, rng| {
+        if pattern == VariancePattern::PlaceholderDate && varied {
+            return None;
+        }
+        let label = apply_pattern(date, pattern, rng);
+        varied |= label.is_some();
+        label
+    })
+}
+
+/// A single, individually selectable date variance pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VariancePattern {
+    /// Day and month swapped, as in European data: "07/03/1985"
+    DayMonthSwap,
+    /// "03/07/85"
+    TwoDigitYear,
+    /// "1985-03-07"
+    Iso8601,
+    /// Days since 1899-12-30, as Excel stores dates: "31113"
+    ExcelSerial,
+    /// A day that does not exist in its month: "02/30/1985", "04/31/1985"
+    ImpossibleDate,
+    /// A placeholder entered when the real date was unknown: "01/01/1900"
+    PlaceholderDate,
+}
+
+impl NamedPattern for VariancePattern {
+    const ALL: &'static [Self] = &[
+        VariancePattern::DayMonthSwap,
+        VariancePattern::TwoDigitYear,
+        VariancePattern::Iso8601,
+        VariancePattern::ExcelSerial,
+        VariancePattern::ImpossibleDate,
+        VariancePattern::PlaceholderDate,
+    ];
+
+    fn name(&self) -> &'static str {
+        match self {
+            VariancePattern::DayMonthSwap => "day_month_swap",
+            VariancePattern::TwoDigitYear => "two_digit_year",
+            VariancePattern::Iso8601 => "iso_8601",
+            VariancePattern::ExcelSerial => "excel_serial",
+            VariancePattern::ImpossibleDate => "impossible_date",
+            VariancePattern::PlaceholderDate => "placeholder_date",
+        }
+    }
+
+    fn fields(&self) -> &'static [&'static str] {
+        &["date"]
+    }
+}
+
+impl fmt::Display for VariancePattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Values systems store when a date of birth is unknown.
+const PLACEHOLDER_DATES: &[&str] = &[
+    "01/01/1900",
+    "01/01/1901",
+    "01/01/1800",
+    "12/31/9999",
+    "00/00/0000",
+    "11/11/1111",
+];
+
+/// Day zero of Excel's 1900 date system. Serials computed from it are
+/// correct from 1900-03-01, after Excel's phantom 1900-02-29.
+fn excel_epoch() -> NaiveDate {
+    NaiveDate::from_ymd_opt(1899, 12, 30).expect("valid date")
+}
+
+/// The three slash-separated parts of a date that still has a four-digit
+/// year, whatever order the day and month are in.
+fn slash_parts(date: &str) -> Option<(&str, &str, &str)> {
+    let mut parts = date.split('/');
+    let (first, second, year) = (parts.next()?, parts.next()?, parts.next()?);
+    let digits =
+        |part: &str, len: usize| part.len() == len && part.bytes().all(|b| b.is_ascii_digit());
+    (parts.next().is_none() && digits(first, 2) && digits(second, 2) && digits(year, 4))
+        .then_some((first, second, year))
+}
+
+/// The calendar date of a value still in the clean `MM/DD/YYYY` format.
+fn parse_clean(date: &str) -> Option<NaiveDate> {
+    slash_parts(date)?;
+    NaiveDate::parse_from_str(date, DATE_FORMAT).ok()
+}
+
+/// Applies a single variance pattern to a date in place.
+///
+/// Returns the ground-truth label of the change, or `None` if the pattern
+/// had nothing to act on (e.g. converting a date that has already been
+/// reformatted to an Excel serial).
+pub fn apply_pattern<R: Rng + ?Sized>(
+    date: &mut Date,
+    pattern: VariancePattern,
+    rng: &mut R,
+) -> Option<String> {
+    let before = date.clone();
+    let value = &mut date.date;
+
+    match pattern {
+        VariancePattern::DayMonthSwap => {
+            if let Some((month, day, year)) = slash_parts(value) {
+                *value = format!("{}/{}/{}", day, month, year);
+            }
+        }
+        VariancePattern::TwoDigitYear => {
+            if let Some((first, second, year)) = slash_parts(value) {
+                *value = format!("{}/{}/{}", first, second, &year[2..]);
+            }
+        }
+        VariancePattern::Iso8601 => {
+            if let Some(parsed) = parse_clean(value) {
+                *value = parsed.format("%Y-%m-%d").to_string();
+            }
+        }
+        VariancePattern::ExcelSerial => {
+            if let Some(parsed) = parse_clean(value) {
+                let first_serial = NaiveDate::from_ymd_opt(1900, 3, 1).expect("valid date");
+                if parsed >= first_serial {
+                    *value = (parsed - excel_epoch()).num_days().to_string();
+                }
+            }
+        }
+        VariancePattern::ImpossibleDate => {
+            if let Some(parsed) = parse_clean(value) {
+                let (year, month) = (parsed.year(), parsed.month());
+                let mut days: Vec<u32> = (29..=31)
+                    .filter(|&day| NaiveDate::from_ymd_opt(year, month, day).is_none())
+                    .collect();
+                if days.is_empty() {
+                    days.push(0);
+                }
+                let day = days[rng.gen_range(0..days.len())];
+                *value = format!("{:02}/{:02}/{:04}", month, day, year);
+            }
+        }
+        VariancePattern::PlaceholderDate => {
+            if parse_clean(value).is_some() {
+                *value = PLACEHOLDER_DATES[rng.gen_range(0..PLACEHOLDER_DATES.len())].to_string();
+            }
+        }
+    }
+
+    if *date == before {
+        None
+    } else {
+        Some(pattern.name().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn apply(value: &str, pattern: VariancePattern) -> (String, Option<String>) {
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut date = Date::new(value.to_string());
+        let label = apply_pattern(&mut date, pattern, &mut rng);
+        (date.date, label)
+    }
+
+    fn as_of() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 6, 15).unwrap()
+    }
+
+    #[test]
+    fn test_birth_dates_follow_ages() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let birth_dates = BirthDates::new("25-34".parse().unwrap(), as_of()).unwrap();
+        for _ in 0..500 {
+            let dob = birth_dates.generate(&mut rng);
+            let age = as_of().years_since(dob).unwrap();
+            assert!((25..=34).contains(&age), "{} is {}", dob, age);
+        }
+    }
+
+    #[test]
+    fn test_default_ages_are_adults() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let birth_dates = BirthDates::new(AgeDistribution::default(), as_of()).unwrap();
+        for _ in 0..500 {
+            let age = as_of().years_since(birth_dates.generate(&mut rng)).unwrap();
+            assert!((18..=90).contains(&age), "{}", age);
+        }
+    }
+
+    #[test]
+    fn test_leap_day_reference_date() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let leap_day = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        let birth_dates =
+            BirthDates::new(AgeDistribution::uniform(0, 5).unwrap(), leap_day).unwrap();
+        for _ in 0..200 {
+            let dob = birth_dates.generate(&mut rng);
+            assert!(dob <= leap_day && dob.year() >= 2018, "{}", dob);
+        }
+    }
+
+    #[test]
+    fn test_reference_date_must_leave_room_for_ages() {
+        let ages = || AgeDistribution::uniform(18, 90).unwrap();
+        let date = |y| NaiveDate::from_ymd_opt(y, 1, 1).unwrap();
+        assert!(BirthDates::new(ages(), date(92)).is_ok());
+        assert!(BirthDates::new(ages(), date(9999)).is_ok());
+        for too_early in [date(91), date(1), NaiveDate::MIN] {
+            let err = BirthDates::new(ages(), too_early).unwrap_err();
+            assert!(matches!(err, Error::InvalidArgument(_)), "{}", err);
+        }
+        assert!(BirthDates::new(ages(), date(10_000)).is_err());
+    }
+
+    #[test]
+    fn test_parse_age_distribution() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let ages: AgeDistribution = "40".parse().unwrap();
+        assert_eq!(ages.sample_range(&mut rng), (40, 40));
+
+        let ages: AgeDistribution = "18-29:0, 65-90:1".parse().unwrap();
+        assert!((0..20).all(|_| ages.sample_range(&mut rng) == (65, 90)));
+        assert_eq!(ages.max_age(), 90);
+    }
+
+    #[test]
+    fn test_parse_invalid_age_distribution() {
+        for bad in [
+            "",
+            "x",
+            "18-",
+            "90-18",
+            "18-200",
+            "18-30:x",
+            "18-30,40-50",
+            "18-30:0",
+        ] {
+            assert!(
+                bad.parse::<AgeDistribution>().is_err(),
+                "{:?} should fail",
+                bad
+            );
+        }
+    }
+
+    #[test]
+    fn test_clean_date_format() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let birth_dates = BirthDates::new(AgeDistribution::default(), as_of()).unwrap();
+        for _ in 0..100 {
+            let date = generate_clean_date(&birth_dates, &mut rng);
+            assert!(parse_clean(&date).is_some(), "{}", date);
+        }
+    }
+
+    #[test]
+    fn test_format_patterns() {
+        assert_eq!(
+            apply("03/07/1985", VariancePattern::DayMonthSwap),
+            ("07/03/1985".to_string(), Some("day_month_swap".to_string()))
+        );
+        assert_eq!(
+            apply("03/07/1985", VariancePattern::TwoDigitYear).0,
+            "03/07/85"
+        );
+        assert_eq!(
+            apply("03/07/1985", VariancePattern::Iso8601).0,
+            "1985-03-07"
+        );
+        assert_eq!(apply("03/07/1985", VariancePattern::ExcelSerial).0, "31113");
+        assert_eq!(apply("01/01/2000", VariancePattern::ExcelSerial).0, "36526");
+    }
+
+    #[test]
+    fn test_day_month_swap_needs_different_parts() {
+        assert_eq!(apply("04/04/1985", VariancePattern::DayMonthSwap).1, None);
+    }
+
+    #[test]
+    fn test_two_digit_year_keeps_swapped_order() {
+        assert_eq!(
+            apply("25/03/1985", VariancePattern::TwoDigitYear).0,
+            "25/03/85"
+        );
+    }
+
+    #[test]
+    fn test_reformatted_dates_are_left_alone() {
+        for pattern in [
+            VariancePattern::DayMonthSwap,
+            VariancePattern::TwoDigitYear,
+            VariancePattern::Iso8601,
+            VariancePattern::ExcelSerial,
+            VariancePattern::ImpossibleDate,
+        ] {
+            assert_eq!(apply("31113", pattern).1, None, "{}", pattern);
+            assert_eq!(apply("03/07/85", pattern).1, None, "{}", pattern);
+        }
+    }
+
+    #[test]
+    fn test_excel_serial_skips_phantom_leap_day() {
+        assert_eq!(apply("02/28/1900", VariancePattern::ExcelSerial).1, None);
+        assert_eq!(apply("03/01/1900", VariancePattern::ExcelSerial).0, "61");
+    }
+
+    #[test]
+    fn test_impossible_dates() {
+        let (value, label) = apply("02/14/1985", VariancePattern::ImpossibleDate);
+        assert!(["02/29/1985", "02/30/1985", "02/31/1985"].contains(&value.as_str()));
+        assert_eq!(label.as_deref(), Some("impossible_date"));
+
+        let (value, _) = apply("02/14/1984", VariancePattern::ImpossibleDate);
+        assert!(["02/30/1984", "02/31/1984"].contains(&value.as_str()));
+        assert_eq!(
+            apply("04/10/1985", VariancePattern::ImpossibleDate).0,
+            "04/31/1985"
+        );
+        assert_eq!(
+            apply("03/10/1985", VariancePattern::ImpossibleDate).0,
+            "03/00/1985"
+        );
+    }
+
+    #[test]
+    fn test_placeholder_dates() {
+        let (value, label) = apply("03/07/1985", VariancePattern::PlaceholderDate);
+        assert!(PLACEHOLDER_DATES.contains(&value.as_str()));
+        assert_eq!(label.as_deref(), Some("placeholder_date"));
+        assert_eq!(apply("85-03-07", VariancePattern::PlaceholderDate).1, None);
+    }
+
+    #[test]
+    fn test_placeholder_dates_are_never_drawn_after_other_patterns() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let profile = VarianceProfile::uniform(VariancePattern::ALL);
+        let birth_dates = BirthDates::new(AgeDistribution::default(), as_of()).unwrap();
+        let mut placeholders = 0;
+        for _ in 0..2000 {
+            let date = Date::new(generate_clean_date(&birth_dates, &mut rng));
+            let traced = vary_date(date, 1.0, &profile, &mut rng);
+            if let Some(index) = traced
+                .variances
+                .iter()
+                .position(|label| label == "placeholder_date")
+            {
+                assert_eq!(index, 0, "{:?}", traced.variances);
+                placeholders += 1;
+            }
+        }
+        assert!(placeholders > 0);
+    }
+
+    #[test]
+    fn test_zero_error_rate_keeps_dates_clean() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let profile = VarianceProfile::uniform(VariancePattern::ALL);
+        let birth_dates = BirthDates::new(AgeDistribution::default(), as_of()).unwrap();
+        for _ in 0..50 {
+            let date = Date::new(generate_clean_date(&birth_dates, &mut rng));
+            let traced = vary_date(date, 0.0, &profile, &mut rng);
+            assert_eq!(traced.record, traced.clean);
+            assert!(traced.variances.is_empty());
+        }
+    }
+}
@@ -0,0 +1,406 @@
+//! Email address generation and variance.
+//!
+//! Addresses are derived from a [`Name`], the way people actually pick
+//! them: `jsmith@`, `john.smith@`, `smithj22@` and so on. The variance
+//! patterns are the errors a contact-validation service has to catch.
+
+use rand::Rng;
+use serde::Serialize;
+use std::fmt;
+
+use crate::generators::names::Name;
+use crate::generators::{match_case, NamedPattern, Traced};
+use crate::profile::VarianceProfile;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Email {
+    pub email: String,
+}
+
+impl Email {
+    pub fn new(email: String) -> Self {
+        Self { email }
+    }
+
+    pub fn to_record(&self) -> Vec<String> {
+        vec![self.email.clone()]
+    }
+}
+
+/// Free-mail and ISP domains, roughly by US popularity.
+const DOMAINS: &[&str] = &[
+    "gmail.com",
+    "yahoo.com",
+    "hotmail.com",
+    "outlook.com",
+    "aol.com",
+    "icloud.com",
+    "comcast.net",
+    "msn.com",
+    "live.com",
+    "att.net",
+];
+
+/// Lowercases a name part and drops anything that can't appear in a
+/// mailbox (apostrophes, spaces, hyphens).
+fn mailbox_part(part: &str) -> String {
+    part.chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Generates a clean email address for `name`.
+///
+/// # Examples
+/// ```
+/// use rust_faker::generators::create_rng;
+/// use rust_faker::generators::emails::generate_clean_email;
+/// use rust_faker::generators::names::Name;
+///
+/// let name = Name::new("John".to_string(), "".to_string(), "O'Brien".to_string());
+/// let email = generate_clean_email(&name, &mut create_rng(Some(1)));
+/// assert!(email.contains('@'));
+/// assert!(!email.contains('\''));
+/// ```
+pub fn generate_clean_email<R: Rng + ?Sized>(name: &Name, rng: &mut R) -> String {
+    let first = mailbox_part(&name.first_name);
+    let last = mailbox_part(&name.last_name);
+    let first_initial = first.get(..1).unwrap_or("x");
+    let last_initial = last.get(..1).unwrap_or("x");
+
+    let local = match rng.gen_range(0..6) {
+        0 => format!("{}.{}", first, last),
+        1 => format!("{}{}", first_initial, last),
+        2 => format!("{}{}{}", last, first_initial, rng.gen_range(1..100)),
+        3 => format!("{}_{}", first, last),
+        4 => format!("{}{}", first, last_initial),
+        _ => format!("{}{}{}", first, last, rng.gen_range(1..1000)),
+    };
+    let domain = DOMAINS[rng.gen_range(0..DOMAINS.len())];
+
+    format!("{}@{}", local, domain)
+}
+
+/// Applies profile variance to a single email: record-level variance with
+/// probability `error_rate`, then any per-field error rates.
+pub fn vary_email<R: Rng + ?Sized>(
+    email: Email,
+    error_rate: f64,
+    profile: &VarianceProfile<VariancePattern>,
+    rng: &mut R,
+) -> Traced<Email> {
+    profile.vary(email, error_rate, rng, apply_pattern)
+}
+
+/// A single, individually selectable email variance pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VariancePattern {
+    /// "jsmithgmail.com"
+    MissingAt,
+    /// "john..smith@gmail.com"
+    DoubledDot,
+    /// "jsmith@gmail.com " (space, tab or several spaces)
+    TrailingWhitespace,
+    /// "jsmith@gmial.com"
+    DomainTypo,
+    /// "JSMITH@GMAIL.COM"
+    Uppercase,
+    /// "mailto:jsmith@gmail.com"
+    MailtoPrefix,
+}
+
+impl NamedPattern for VariancePattern {
+    const ALL: &'static [Self] = &[
+        VariancePattern::MissingAt,
+        VariancePattern::DoubledDot,
+        VariancePattern::TrailingWhitespace,
+        VariancePattern::DomainTypo,
+        VariancePattern::Uppercase,
+        VariancePattern::MailtoPrefix,
+    ];
+
+    fn name(&self) -> &'static str {
+        match self {
+            VariancePattern::MissingAt => "missing_at",
+            VariancePattern::DoubledDot => "doubled_dot",
+            VariancePattern::TrailingWhitespace => "trailing_whitespace",
+            VariancePattern::DomainTypo => "domain_typo",
+            VariancePattern::Uppercase => "uppercase",
+            VariancePattern::MailtoPrefix => "mailto_prefix",
+        }
+    }
+
+    fn fields(&self) -> &'static [&'static str] {
+        &["email"]
+    }
+}
+
+impl fmt::Display for VariancePattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Misspellings seen in real sign-up data for the most common domains.
+const DOMAIN_TYPOS: &[(&str, &[&str])] = &[
+    (
+        "gmail.com",
+        &["gmial.com", "gmai.com", "gamil.com", "gmail.co", "gmail.cm"],
+    ),
+    (
+        "yahoo.com",
+        &["yaho.com", "yahooo.com", "yahoo.co", "yhoo.com"],
+    ),
+    (
+        "hotmail.com",
+        &["hotmial.com", "hotmal.com", "hotmail.co", "hotamil.com"],
+    ),
+    ("outlook.com", &["outlok.com", "outloo.com", "outlook.co"]),
+    ("aol.com", &["aol.co", "aoll.com"]),
+    ("icloud.com", &["iclod.com", "icloud.co"]),
+    ("comcast.net", &["comcast.com", "comcat.net"]),
+];
+
+/// Returns a plausible misspelling of `domain` in its own case: a known
+/// typo when there is one, otherwise two adjacent letters of its first
+/// label transposed. A mixed-case domain is always transposed, since a
+/// known typo could not keep its case.
+fn misspell_domain<R: Rng + ?Sized>(domain: &str, rng: &mut R) -> Option<String> {
+    if let Some((_, typos)) = DOMAIN_TYPOS
+        .iter()
+        .find(|(known, _)| match_case(domain, known) == domain)
+    {
+        return Some(match_case(domain, typos[rng.gen_range(0..typos.len())]));
+    }
+
+    let mut chars: Vec<char> = domain.chars().collect();
+    let label_len = chars.iter().position(|&c| c == '.').unwrap_or(chars.len());
+    if label_len < 2 {
+        return None;
+    }
+    let i = rng.gen_range(0..label_len - 1);
+    if chars[i] == chars[i + 1] {
+        return None;
+    }
+    chars.swap(i, i + 1);
+    Some(chars.into_iter().collect())
+}
+
+/// Applies a single variance pattern to an email in place.
+///
+/// Returns the ground-truth label of the change, or `None` if the pattern
+/// had nothing to act on (e.g. `missing_at` on an address without `@`).
+pub fn apply_pattern<R: Rng + ?Sized>(
+    email: &mut Email,
+    pattern: VariancePattern,
+    rng: &mut R,
+) -> Option<String> {
+    let before = email.clone();
+    let address = &mut email.email;
+
+    match pattern {
+        VariancePattern::MissingAt => {
+            *address = address.replacen('@', "", 1);
+        }
+        VariancePattern::DoubledDot => {
+            let dots: Vec<usize> = address.match_indices('.').map(|(i, _)| i).collect();
+            if !dots.is_empty() {
+                address.insert(dots[rng.gen_range(0..dots.len())], '.');
+            }
+        }
+        VariancePattern::TrailingWhitespace => {
+            let whitespace = [" ", "  ", "\t"];
+            address.push_str(whitespace[rng.gen_range(0..whitespace.len())]);
+        }
+        VariancePattern::DomainTypo => {
+            if let Some(at) = address.rfind('@') {
+                if let Some(typo) = misspell_domain(&address[at + 1..], rng) {
+                    address.replace_range(at + 1.., &typo);
+                }
+            }
+        }
+        VariancePattern::Uppercase => {
+            *address = address.to_uppercase();
+        }
+        VariancePattern::MailtoPrefix => {
+            if !address.to_lowercase().starts_with("mailto:") {
+                address.insert_str(0, "mailto:");
+            }
+        }
+    }
+
+    if *email == before {
+        None
+    } else {
+        Some(pattern.name().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::names::generate_clean_name;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn email(address: &str) -> Email {
+        Email::new(address.to_string())
+    }
+
+    fn apply(address: &str, pattern: VariancePattern) -> (String, Option<String>) {
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut email = email(address);
+        let label = apply_pattern(&mut email, pattern, &mut rng);
+        (email.email, label)
+    }
+
+    #[test]
+    fn test_clean_email_uses_name() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let name = Name::new("Mary".to_string(), "".to_string(), "Smith".to_string());
+        for _ in 0..50 {
+            let email = generate_clean_email(&name, &mut rng);
+            let (local, domain) = email.split_once('@').unwrap();
+            assert!(
+                local.contains("smith") || local.contains("mary"),
+                "{}",
+                email
+            );
+            assert!(DOMAINS.contains(&domain));
+        }
+    }
+
+    #[test]
+    fn test_clean_email_strips_punctuation() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let name = Name::new(
+            "Mary Ann".to_string(),
+            "".to_string(),
+            "O'Neil-Smith".to_string(),
+        );
+        for _ in 0..50 {
+            let email = generate_clean_email(&name, &mut rng);
+            let local = email.split('@').next().unwrap();
+            assert!(
+                local
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '.' || c == '_'),
+                "{}",
+                email
+            );
+        }
+    }
+
+    #[test]
+    fn test_missing_at() {
+        assert_eq!(
+            apply("jsmith@gmail.com", VariancePattern::MissingAt),
+            (
+                "jsmithgmail.com".to_string(),
+                Some("missing_at".to_string())
+            )
+        );
+        assert_eq!(apply("jsmith", VariancePattern::MissingAt).1, None);
+    }
+
+    #[test]
+    fn test_doubled_dot() {
+        let (varied, label) = apply("john.smith@gmail.com", VariancePattern::DoubledDot);
+        assert!(varied.contains(".."), "{}", varied);
+        assert_eq!(varied.len(), "john.smith@gmail.com".len() + 1);
+        assert_eq!(label.as_deref(), Some("doubled_dot"));
+    }
+
+    #[test]
+    fn test_trailing_whitespace() {
+        let (varied, _) = apply("jsmith@gmail.com", VariancePattern::TrailingWhitespace);
+        assert!(varied.starts_with("jsmith@gmail.com"));
+        assert_ne!(varied.trim_end(), varied);
+        assert_eq!(varied.trim_end(), "jsmith@gmail.com");
+    }
+
+    #[test]
+    fn test_domain_typo_known_domain() {
+        let (varied, label) = apply("jsmith@gmail.com", VariancePattern::DomainTypo);
+        let (local, domain) = varied.split_once('@').unwrap();
+        assert_eq!(local, "jsmith");
+        assert!(DOMAIN_TYPOS[0].1.contains(&domain), "{}", domain);
+        assert_eq!(label.as_deref(), Some("domain_typo"));
+    }
+
+    #[test]
+    fn test_domain_typo_keeps_case() {
+        let mut rng = StdRng::seed_from_u64(1);
+        for _ in 0..20 {
+            let mut upper = email("JOHN.SMITH@GMAIL.COM");
+            apply_pattern(&mut upper, VariancePattern::DomainTypo, &mut rng);
+            let domain = upper.email.split_once('@').unwrap().1;
+            assert_eq!(domain, domain.to_uppercase());
+            assert!(
+                DOMAIN_TYPOS[0].1.contains(&domain.to_lowercase().as_str()),
+                "{}",
+                domain
+            );
+
+            let mut mixed = email("jsmith@Gmail.com");
+            if apply_pattern(&mut mixed, VariancePattern::DomainTypo, &mut rng).is_some() {
+                let domain = mixed.email.split_once('@').unwrap().1;
+                assert!(domain.ends_with(".com"), "{}", domain);
+                assert_eq!(domain.chars().filter(|c| c.is_uppercase()).count(), 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_domain_typo_unknown_domain_transposes() {
+        let mut rng = StdRng::seed_from_u64(1);
+        for _ in 0..20 {
+            let mut email = email("jsmith@acme.org");
+            if apply_pattern(&mut email, VariancePattern::DomainTypo, &mut rng).is_some() {
+                let domain = email.email.split_once('@').unwrap().1;
+                assert!(domain.ends_with(".org"));
+                let mut sorted: Vec<char> = domain[..4].chars().collect();
+                sorted.sort();
+                assert_eq!(sorted, ['a', 'c', 'e', 'm']);
+                assert_ne!(&domain[..4], "acme");
+            }
+        }
+    }
+
+    #[test]
+    fn test_uppercase_and_mailto() {
+        assert_eq!(
+            apply("jsmith@gmail.com", VariancePattern::Uppercase).0,
+            "JSMITH@GMAIL.COM"
+        );
+        assert_eq!(
+            apply("jsmith@gmail.com", VariancePattern::MailtoPrefix).0,
+            "mailto:jsmith@gmail.com"
+        );
+        assert_eq!(
+            apply("mailto:jsmith@gmail.com", VariancePattern::MailtoPrefix).1,
+            None
+        );
+    }
+
+    #[test]
+    fn test_zero_error_rate_keeps_emails_clean() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let profile = VarianceProfile::uniform(VariancePattern::ALL);
+        for _ in 0..50 {
+            let name = generate_clean_name(&mut rng);
+            let email = Email::new(generate_clean_email(&name, &mut rng));
+            let traced = vary_email(email, 0.0, &profile, &mut rng);
+            assert_eq!(traced.record, traced.clean);
+            assert!(traced.variances.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_variance_pattern_names_round_trip() {
+        for pattern in VariancePattern::ALL {
+            assert_eq!(VariancePattern::from_name(pattern.name()), Some(*pattern));
+        }
+    }
+}
@@ -0,0 +1,577 @@
+//! Country-aware address generation and variance.
+//!
+//! Addresses follow each country's conventions: house numbers after the
+//! street in Germany and Mexico, colonias in Mexican addresses, `A1A 1A1`
+//! postal codes in Canada and `SW1A 1AA` codes in the UK. The variance
+//! patterns target the fields US-only data never exercises: the country,
+//! the region and the postal code format.
+
+use fake::faker::address::en::{SecondaryAddress, StreetName, StreetSuffix};
+use fake::faker::name::en::LastName;
+use fake::Fake;
+use rand::Rng;
+use serde::Serialize;
+use std::fmt;
+
+use crate::generators::{NamedPattern, Traced};
+use crate::geography;
+use crate::profile::VarianceProfile;
+use crate::regions::Country;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct InternationalAddress {
+    pub address1: String,
+    pub address2: String,
+    pub city: String,
+    /// State, province or county
+    pub region: String,
+    pub postal_code: String,
+    pub country: String,
+}
+
+impl InternationalAddress {
+    pub fn to_record(&self) -> Vec<String> {
+        vec![
+            self.address1.clone(),
+            self.address2.clone(),
+            self.city.clone(),
+            self.region.clone(),
+            self.postal_code.clone(),
+            self.country.clone(),
+        ]
+    }
+}
+
+/// Letters Canada Post uses in postal codes.
+const CANADIAN_POSTAL_LETTERS: &[u8] = b"ABCEGHJKLMNPRSTVWXYZ";
+
+/// Letters Royal Mail uses in the inward half of a postcode.
+const UK_INWARD_LETTERS: &[u8] = b"ABDEFGHJLNPQRSTUWXYZ";
+
+const UK_STREET_SUFFIXES: &[&str] = &[
+    "Road", "Street", "Lane", "Avenue", "Close", "Drive", "Way", "Gardens", "Crescent", "Grove",
+    "Terrace", "Place",
+];
+
+const GERMAN_STREET_STEMS: &[&str] = &[
+    "Haupt", "Bahnhof", "Garten", "Kirch", "Schiller", "Goethe", "Linden", "Wald", "Schul",
+    "Mühlen", "Berg", "Markt", "Ring", "Rosen",
+];
+
+const GERMAN_STREET_SUFFIXES: &[&str] = &["straße", "weg", "gasse", "allee", "platz"];
+
+const MEXICAN_STREET_TYPES: &[&str] = &["Calle", "Avenida", "Boulevard", "Calzada", "Privada"];
+
+const MEXICAN_STREET_NAMES: &[&str] = &[
+    "Hidalgo",
+    "Juárez",
+    "Morelos",
+    "Reforma",
+    "Insurgentes",
+    "Independencia",
+    "Allende",
+    "Madero",
+    "Zaragoza",
+    "5 de Mayo",
+    "Guerrero",
+    "Revolución",
+];
+
+const MEXICAN_COLONIAS: &[&str] = &[
+    "Centro",
+    "Del Valle",
+    "Jardines",
+    "San José",
+    "La Paz",
+    "Las Flores",
+    "Industrial",
+    "Moderna",
+    "Reforma",
+    "Santa María",
+    "Lomas",
+    "Obrera",
+];
+
+fn pick<R: Rng + ?Sized>(items: &[&'static str], rng: &mut R) -> &'static str {
+    items[rng.gen_range(0..items.len())]
+}
+
+fn pick_letter<R: Rng + ?Sized>(letters: &[u8], rng: &mut R) -> char {
+    char::from(letters[rng.gen_range(0..letters.len())])
+}
+
+/// Completes a bundled postal code: the inward half for Canada (`3L9`) and
+/// the UK (`1AA`); German and Mexican codes are already complete.
+fn complete_postal_code<R: Rng + ?Sized>(country: Country, prefix: &str, rng: &mut R) -> String {
+    match country {
+        Country::Canada => format!(
+            "{} {}{}{}",
+            prefix,
+            rng.gen_range(0..10),
+            pick_letter(CANADIAN_POSTAL_LETTERS, rng),
+            rng.gen_range(0..10)
+        ),
+        Country::UnitedKingdom => format!(
+            "{} {}{}{}",
+            prefix,
+            rng.gen_range(0..10),
+            pick_letter(UK_INWARD_LETTERS, rng),
+            pick_letter(UK_INWARD_LETTERS, rng)
+        ),
+        _ => prefix.to_string(),
+    }
+}
+
+/// The first address line in the country's usual order.
+fn street_line<R: Rng + ?Sized>(country: Country, rng: &mut R) -> String {
+    match country {
+        Country::UnitedStates | Country::Canada => {
+            let number: u32 = (1..9999).fake_with_rng(rng);
+            let name: String = StreetName().fake_with_rng(rng);
+            let suffix: String = StreetSuffix().fake_with_rng(rng);
+            format!("{} {} {}", number, name, suffix)
+        }
+        Country::UnitedKingdom => {
+            let number: u32 = rng.gen_range(1..300);
+            let name: String = LastName().fake_with_rng(rng);
+            format!("{} {} {}", number, name, pick(UK_STREET_SUFFIXES, rng))
+        }
+        Country::Germany => {
+            let number: u32 = rng.gen_range(1..300);
+            format!(
+                "{}{} {}",
+                pick(GERMAN_STREET_STEMS, rng),
+                pick(GERMAN_STREET_SUFFIXES, rng),
+                number
+            )
+        }
+        Country::Mexico => {
+            let number: u32 = rng.gen_range(1..300);
+            format!(
+                "{} {} {}",
+                pick(MEXICAN_STREET_TYPES, rng),
+                pick(MEXICAN_STREET_NAMES, rng),
+                number
+            )
+        }
+    }
+}
+
+/// The second address line: a unit, flat or floor, or the colonia that
+/// every Mexican address names.
+fn secondary_line<R: Rng + ?Sized>(country: Country, rng: &mut R) -> String {
+    match country {
+        Country::UnitedStates if rng.gen_bool(0.5) => SecondaryAddress().fake_with_rng(rng),
+        Country::Canada if rng.gen_bool(0.3) => format!("Unit {}", rng.gen_range(1..500)),
+        Country::UnitedKingdom if rng.gen_bool(0.3) => format!("Flat {}", rng.gen_range(1..40)),
+        Country::Germany if rng.gen_bool(0.2) => format!("{}. OG", rng.gen_range(1..6)),
+        Country::Mexico => format!("Col. {}", pick(MEXICAN_COLONIAS, rng)),
+        _ => String::new(),
+    }
+}
+
+/// Generates a clean address in a country drawn uniformly from
+/// `countries`.
+///
+/// The city, region and postal code come from one bundled location, so
+/// they agree. The region is written the way the country's mail is
+/// addressed: a code in the US and Canada (`ON`), a name elsewhere
+/// (`Bayern`). The country is its ISO-2 code.
+///
+/// # Panics
+/// Panics if `countries` is empty.
+pub fn generate_clean_international_address<R: Rng + ?Sized>(
+    countries: &[Country],
+    rng: &mut R,
+) -> InternationalAddress {
+    let country = countries[rng.gen_range(0..countries.len())];
+    let address1 = street_line(country, rng);
+    let address2 = secondary_line(country, rng);
+
+    let (city, region, postal_code) = match geography::random_world_location(country, rng) {
+        Some(location) => {
+            let prefix = location.postal_codes[rng.gen_range(0..location.postal_codes.len())];
+            let region = if country.abbreviates_region() {
+                location.region
+            } else {
+                country
+                    .subdivisions()
+                    .into_iter()
+                    .find(|(code, _)| *code == location.region)
+                    .map_or(location.region, |(_, name)| name)
+            };
+            (
+                location.city,
+                region,
+                complete_postal_code(country, prefix, rng),
+            )
+        }
+        None => {
+            let location = geography::random_location(rng);
+            (location.city, location.state, location.zip.to_string())
+        }
+    };
+
+    InternationalAddress {
+        address1,
+        address2,
+        city: city.to_string(),
+        region: region.to_string(),
+        postal_code,
+        country: country.code().to_string(),
+    }
+}
+
+/// Applies profile variance to a single address: record-level variance
+/// with probability `error_rate`, then any per-field error rates.
+pub fn vary_international_address<R: Rng + ?Sized>(
+    address: InternationalAddress,
+    error_rate: f64,
+    profile: &VarianceProfile<VariancePattern>,
+    rng: &mut R,
+) -> Traced<InternationalAddress> {
+    profile.vary(address, error_rate, rng, apply_pattern)
+}
+
+/// A single, individually selectable international address variance
+/// pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VariancePattern {
+    /// Country left empty
+    MissingCountry,
+    /// ISO-2 code and English name exchanged: "DE" <-> "Germany"
+    CountryName,
+    /// Another name for the country: "UK", "Deutschland", "MEX"
+    CountryAlias,
+    /// Postal code written the wrong way: "M5V3L9", "sw1a 1aa", "1067"
+    /// for "01067", "D-10115"
+    MalformedPostalCode,
+    /// Postal code left empty
+    MissingPostalCode,
+    /// Region code and name exchanged: "ON" <-> "Ontario"
+    RegionName,
+    /// Region left empty
+    MissingRegion,
+}
+
+impl NamedPattern for VariancePattern {
+    const ALL: &'static [Self] = &[
+        VariancePattern::MissingCountry,
+        VariancePattern::CountryName,
+        VariancePattern::CountryAlias,
+        VariancePattern::MalformedPostalCode,
+        VariancePattern::MissingPostalCode,
+        VariancePattern::RegionName,
+        VariancePattern::MissingRegion,
+    ];
+
+    fn name(&self) -> &'static str {
+        match self {
+            VariancePattern::MissingCountry => "missing_country",
+            VariancePattern::CountryName => "country_name",
+            VariancePattern::CountryAlias => "country_alias",
+            VariancePattern::MalformedPostalCode => "malformed_postal_code",
+            VariancePattern::MissingPostalCode => "missing_postal_code",
+            VariancePattern::RegionName => "region_name",
+            VariancePattern::MissingRegion => "missing_region",
+        }
+    }
+
+    fn fields(&self) -> &'static [&'static str] {
+        match self {
+            VariancePattern::MissingCountry
+            | VariancePattern::CountryName
+            | VariancePattern::CountryAlias => &["country"],
+            VariancePattern::MalformedPostalCode | VariancePattern::MissingPostalCode => {
+                &["postal_code"]
+            }
+            VariancePattern::RegionName | VariancePattern::MissingRegion => &["region"],
+        }
+    }
+}
+
+impl fmt::Display for VariancePattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// The other form of a region, code for name or name for code, looked up
+/// in `country` when the address still names it and in every country
+/// otherwise.
+fn other_region_form(country: Option<Country>, region: &str) -> Option<&'static str> {
+    let countries = match country {
+        Some(country) => vec![country],
+        None => Country::ALL.to_vec(),
+    };
+    countries
+        .iter()
+        .flat_map(|country| country.subdivisions())
+        .find_map(|(code, name)| {
+            if code.is_empty() {
+                None
+            } else if code.eq_ignore_ascii_case(region) {
+                Some(name)
+            } else if name.to_lowercase() == region.to_lowercase() {
+                Some(code)
+            } else {
+                None
+            }
+        })
+}
+
+/// The ways a postal code is commonly mangled, given its current form and
+/// the country it belongs to (if known).
+fn malformed_postal_codes(postal_code: &str, country: Option<Country>) -> Vec<String> {
+    let mut forms = Vec::new();
+    if postal_code.is_empty() {
+        return forms;
+    }
+
+    if postal_code.contains(' ') {
+        forms.push(postal_code.replace(' ', ""));
+    }
+    if postal_code.chars().any(|c| c.is_ascii_uppercase()) {
+        forms.push(postal_code.to_lowercase());
+    }
+    if postal_code.bytes().all(|b| b.is_ascii_digit()) {
+        // Stored as a number somewhere along the way
+        let trimmed = postal_code.trim_start_matches('0');
+        if trimmed.len() < postal_code.len() && !trimmed.is_empty() {
+            forms.push(trimmed.to_string());
+        }
+        match country {
+            Some(Country::Germany) => forms.push(format!("D-{}", postal_code)),
+            Some(Country::Mexico) => forms.push(format!("C.P. {}", postal_code)),
+            Some(Country::UnitedStates) => forms.push(format!("{}-0000", postal_code)),
+            _ => {}
+        }
+        if postal_code.len() > 4 {
+            forms.push(postal_code[..postal_code.len() - 1].to_string());
+        }
+    }
+    forms
+}
+
+/// Applies a single variance pattern to an address in place.
+///
+/// Returns the ground-truth label of the change, or `None` if the pattern
+/// had nothing to act on (e.g. naming a UK county, which has no code).
+pub fn apply_pattern<R: Rng + ?Sized>(
+    address: &mut InternationalAddress,
+    pattern: VariancePattern,
+    rng: &mut R,
+) -> Option<String> {
+    let before = address.clone();
+    let country: Option<Country> = address.country.parse().ok();
+
+    match pattern {
+        VariancePattern::MissingCountry => {
+            address.country.clear();
+        }
+        VariancePattern::CountryName => {
+            if let Some(country) = country {
+                address.country = if address.country.eq_ignore_ascii_case(country.code()) {
+                    country.name().to_string()
+                } else {
+                    country.code().to_string()
+                };
+            }
+        }
+        VariancePattern::CountryAlias => {
+            if let Some(country) = country {
+                let aliases: Vec<&str> = country
+                    .aliases()
+                    .iter()
+                    .copied()
+                    .filter(|alias| *alias != address.country)
+                    .collect();
+                if !aliases.is_empty() {
+                    address.country = pick(&aliases, rng).to_string();
+                }
+            }
+        }
+        VariancePattern::MalformedPostalCode => {
+            let forms = malformed_postal_codes(&address.postal_code, country);
+            if !forms.is_empty() {
+                address.postal_code = forms[rng.gen_range(0..forms.len())].clone();
+            }
+        }
+        VariancePattern::MissingPostalCode => {
+            address.postal_code.clear();
+        }
+        VariancePattern::RegionName => {
+            if let Some(other) = other_region_form(country, &address.region) {
+                address.region = other.to_string();
+            }
+        }
+        VariancePattern::MissingRegion => {
+            address.region.clear();
+        }
+    }
+
+    if *address == before {
+        None
+    } else {
+        Some(pattern.name().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn address(country: Country) -> InternationalAddress {
+        let mut rng = StdRng::seed_from_u64(42);
+        generate_clean_international_address(&[country], &mut rng)
+    }
+
+    fn apply(
+        mut address: InternationalAddress,
+        pattern: VariancePattern,
+    ) -> (InternationalAddress, Option<String>) {
+        let mut rng = StdRng::seed_from_u64(3);
+        let label = apply_pattern(&mut address, pattern, &mut rng);
+        (address, label)
+    }
+
+    fn is_canadian_postal_code(code: &str) -> bool {
+        let b = code.as_bytes();
+        b.len() == 7
+            && b[0].is_ascii_uppercase()
+            && b[1].is_ascii_digit()
+            && b[2].is_ascii_uppercase()
+            && b[3] == b' '
+            && b[4].is_ascii_digit()
+            && CANADIAN_POSTAL_LETTERS.contains(&b[5])
+            && b[6].is_ascii_digit()
+    }
+
+    #[test]
+    fn test_postal_code_formats() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..100 {
+            let ca = generate_clean_international_address(&[Country::Canada], &mut rng);
+            assert!(
+                is_canadian_postal_code(&ca.postal_code),
+                "{}",
+                ca.postal_code
+            );
+
+            let gb = generate_clean_international_address(&[Country::UnitedKingdom], &mut rng);
+            let (outward, inward) = gb.postal_code.split_once(' ').unwrap();
+            assert!((2..=4).contains(&outward.len()), "{}", gb.postal_code);
+            assert_eq!(inward.len(), 3, "{}", gb.postal_code);
+
+            for country in [Country::Germany, Country::Mexico, Country::UnitedStates] {
+                let address = generate_clean_international_address(&[country], &mut rng);
+                assert_eq!(address.postal_code.len(), 5, "{:?}", address);
+                assert!(address.postal_code.bytes().all(|b| b.is_ascii_digit()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_region_conventions() {
+        assert_eq!(address(Country::Canada).region.len(), 2);
+        assert_eq!(address(Country::UnitedStates).region.len(), 2);
+        for country in [Country::Germany, Country::Mexico, Country::UnitedKingdom] {
+            let address = address(country);
+            assert!(
+                country
+                    .subdivisions()
+                    .iter()
+                    .any(|(_, name)| *name == address.region),
+                "{:?}",
+                address
+            );
+        }
+    }
+
+    #[test]
+    fn test_street_order_follows_country() {
+        let german = address(Country::Germany);
+        assert!(german
+            .address1
+            .rsplit(' ')
+            .next()
+            .unwrap()
+            .parse::<u32>()
+            .is_ok());
+        let mexican = address(Country::Mexico);
+        assert!(mexican.address2.starts_with("Col. "), "{:?}", mexican);
+        let british = address(Country::UnitedKingdom);
+        assert!(british
+            .address1
+            .split(' ')
+            .next()
+            .unwrap()
+            .parse::<u32>()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_country_patterns() {
+        let clean = address(Country::Germany);
+        assert_eq!(clean.country, "DE");
+        let (named, label) = apply(clean.clone(), VariancePattern::CountryName);
+        assert_eq!(named.country, "Germany");
+        assert_eq!(label.as_deref(), Some("country_name"));
+        assert_eq!(apply(named, VariancePattern::CountryName).0.country, "DE");
+
+        let (aliased, _) = apply(clean.clone(), VariancePattern::CountryAlias);
+        assert!(["DEU", "Deutschland"].contains(&aliased.country.as_str()));
+
+        let (missing, _) = apply(clean, VariancePattern::MissingCountry);
+        assert!(missing.country.is_empty());
+        assert_eq!(apply(missing, VariancePattern::CountryName).1, None);
+    }
+
+    #[test]
+    fn test_region_name_swaps_both_ways() {
+        let mut address = address(Country::Canada);
+        address.region = "ON".to_string();
+        let (named, _) = apply(address, VariancePattern::RegionName);
+        assert_eq!(named.region, "Ontario");
+        assert_eq!(apply(named, VariancePattern::RegionName).0.region, "ON");
+
+        let mut german = self::address(Country::Germany);
+        german.region = "Bayern".to_string();
+        german.country.clear();
+        assert_eq!(apply(german, VariancePattern::RegionName).0.region, "BY");
+
+        let british = self::address(Country::UnitedKingdom);
+        assert_eq!(apply(british, VariancePattern::RegionName).1, None);
+    }
+
+    #[test]
+    fn test_malformed_postal_codes() {
+        assert_eq!(
+            malformed_postal_codes("M5V 3L9", Some(Country::Canada)),
+            ["M5V3L9", "m5v 3l9"]
+        );
+        assert_eq!(
+            malformed_postal_codes("01067", Some(Country::Germany)),
+            ["1067", "D-01067", "0106"]
+        );
+        assert_eq!(
+            malformed_postal_codes("44100", Some(Country::Mexico)),
+            ["C.P. 44100", "4410"]
+        );
+        assert_eq!(malformed_postal_codes("", None), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_zero_error_rate_keeps_addresses_clean() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let profile = VarianceProfile::uniform(VariancePattern::ALL);
+        for _ in 0..50 {
+            let address = generate_clean_international_address(&Country::ALL, &mut rng);
+            let traced = vary_international_address(address, 0.0, &profile, &mut rng);
+            assert_eq!(traced.record, traced.clean);
+            assert!(traced.variances.is_empty());
+        }
+    }
+}
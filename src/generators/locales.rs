@@ -0,0 +1,776 @@
+//! Name locales: culturally specific name generation and the spelling
+//! tables the locale-aware name variance patterns draw on.
+//!
+//! Every locale fills the same [`Name`] fields the way a well-kept record
+//! would: the given name in `first_name` and the family name in
+//! `last_name`, whatever order the culture writes them in. The
+//! interesting structure — two Hispanic surnames, a Dutch `van der`, an
+//! Arabic `bin` and `al-` — is what the variance patterns then break.
+
+use rand::Rng;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::Error;
+use crate::generators::names::{generate_clean_name, Name};
+
+/// A naming convention to generate names in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum NameLocale {
+    /// Anglo names from `fake`
+    #[default]
+    English,
+    /// Given name(s) and two surnames: "José Luis García Márquez"
+    Hispanic,
+    /// Pinyin, family name written first: "Zhang Wei"
+    Chinese,
+    /// Hepburn with long-vowel marks, family name written first: "Satō Haruto"
+    Japanese,
+    /// Revised Romanization with hyphenated given names: "Kim Min-jun"
+    Korean,
+    /// Family, middle and given name with full diacritics: "Nguyễn Văn Hùng"
+    Vietnamese,
+    /// Patronymic `bin`/`bint` and `al-` family names: "Khalid bin Saeed al-Rashid"
+    Arabic,
+    /// Lowercase surname particles: "Pieter van der Berg"
+    Dutch,
+}
+
+impl NameLocale {
+    pub const ALL: [NameLocale; 8] = [
+        NameLocale::English,
+        NameLocale::Hispanic,
+        NameLocale::Chinese,
+        NameLocale::Japanese,
+        NameLocale::Korean,
+        NameLocale::Vietnamese,
+        NameLocale::Arabic,
+        NameLocale::Dutch,
+    ];
+
+    /// The code used on the command line (`en`, `es`, `zh`, ...)
+    pub fn code(&self) -> &'static str {
+        match self {
+            NameLocale::English => "en",
+            NameLocale::Hispanic => "es",
+            NameLocale::Chinese => "zh",
+            NameLocale::Japanese => "ja",
+            NameLocale::Korean => "ko",
+            NameLocale::Vietnamese => "vi",
+            NameLocale::Arabic => "ar",
+            NameLocale::Dutch => "nl",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            NameLocale::English => "english",
+            NameLocale::Hispanic => "hispanic",
+            NameLocale::Chinese => "chinese",
+            NameLocale::Japanese => "japanese",
+            NameLocale::Korean => "korean",
+            NameLocale::Vietnamese => "vietnamese",
+            NameLocale::Arabic => "arabic",
+            NameLocale::Dutch => "dutch",
+        }
+    }
+
+    /// Whether the culture writes the family name before the given name
+    pub fn family_name_first(&self) -> bool {
+        matches!(
+            self,
+            NameLocale::Chinese
+                | NameLocale::Japanese
+                | NameLocale::Korean
+                | NameLocale::Vietnamese
+        )
+    }
+
+    /// The bundled family names of a family-name-first locale; empty for
+    /// the others
+    fn family_first_surnames(&self) -> &'static [&'static str] {
+        match self {
+            NameLocale::Chinese => CHINESE_SURNAMES,
+            NameLocale::Japanese => JAPANESE_SURNAMES,
+            NameLocale::Korean => KOREAN_SURNAMES,
+            NameLocale::Vietnamese => VIETNAMESE_SURNAMES,
+            _ => &[],
+        }
+    }
+}
+
+impl fmt::Display for NameLocale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl FromStr for NameLocale {
+    type Err = Error;
+
+    /// Parses a locale code (`es`) or name (`hispanic`), case-insensitively.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().to_lowercase();
+        NameLocale::ALL
+            .iter()
+            .copied()
+            .find(|locale| {
+                locale.code() == s
+                    || locale.name() == s
+                    || (s == "spanish" && *locale == NameLocale::Hispanic)
+            })
+            .ok_or_else(|| {
+                let valid: Vec<&str> = NameLocale::ALL.iter().map(|l| l.code()).collect();
+                Error::InvalidArgument(format!(
+                    "Unknown locale '{}'. Valid locales: {}",
+                    s,
+                    valid.join(", ")
+                ))
+            })
+    }
+}
+
+const HISPANIC_GIVEN_NAMES: &[&str] = &[
+    "José",
+    "Juan",
+    "Luis",
+    "Carlos",
+    "Jesús",
+    "Andrés",
+    "Miguel",
+    "Alejandro",
+    "Sergio",
+    "Raúl",
+    "Óscar",
+    "Ramón",
+    "María",
+    "Ana",
+    "Carmen",
+    "Sofía",
+    "Lucía",
+    "Verónica",
+    "Inés",
+    "Rocío",
+    "Guadalupe",
+    "Mónica",
+    "Elena",
+    "Beatriz",
+];
+
+const HISPANIC_COMPOUND_GIVEN_NAMES: &[&str] = &[
+    "José Luis",
+    "Juan Carlos",
+    "Miguel Ángel",
+    "Juan Pablo",
+    "María José",
+    "María Fernanda",
+    "Ana María",
+    "María del Carmen",
+];
+
+const HISPANIC_SURNAMES: &[&str] = &[
+    "García",
+    "Rodríguez",
+    "Martínez",
+    "Hernández",
+    "López",
+    "González",
+    "Pérez",
+    "Sánchez",
+    "Ramírez",
+    "Torres",
+    "Flores",
+    "Gómez",
+    "Díaz",
+    "Muñoz",
+    "Núñez",
+    "Peña",
+    "Ibáñez",
+    "Márquez",
+    "Vargas",
+    "Castillo",
+    "Ortiz",
+    "Jiménez",
+    "Ruiz",
+    "Morales",
+];
+
+const CHINESE_GIVEN_NAMES: &[&str] = &[
+    "Wei", "Fang", "Jing", "Lei", "Min", "Jun", "Yan", "Xiaoming", "Haoran", "Yuxuan", "Zihan",
+    "Xinyi", "Jiahui", "Mingyu", "Tingting", "Zhiwei",
+];
+
+const CHINESE_SURNAMES: &[&str] = &[
+    "Wang", "Li", "Zhang", "Liu", "Chen", "Yang", "Huang", "Zhao", "Wu", "Zhou", "Xu", "Lin",
+    "Guo", "He", "Ma", "Sun",
+];
+
+const JAPANESE_GIVEN_NAMES: &[&str] = &[
+    "Haruto", "Yūto", "Sōta", "Ren", "Shōta", "Ryō", "Kenji", "Takeshi", "Yūki", "Yui", "Aoi",
+    "Sakura", "Yōko", "Hina", "Keiko", "Mei",
+];
+
+const JAPANESE_SURNAMES: &[&str] = &[
+    "Satō",
+    "Suzuki",
+    "Takahashi",
+    "Tanaka",
+    "Itō",
+    "Watanabe",
+    "Yamamoto",
+    "Nakamura",
+    "Kobayashi",
+    "Katō",
+    "Yoshida",
+    "Yamada",
+    "Ōno",
+    "Kōno",
+    "Saitō",
+    "Inoue",
+];
+
+const KOREAN_GIVEN_NAMES: &[&str] = &[
+    "Min-jun", "Seo-yeon", "Ji-ho", "Ha-eun", "Do-yun", "Ji-woo", "Seo-jun", "Su-bin", "Hyun-woo",
+    "Ye-jin", "Jae-won", "Eun-ji", "Sung-min", "Hye-jin",
+];
+
+const KOREAN_SURNAMES: &[&str] = &[
+    "Kim", "Lee", "Park", "Choi", "Jung", "Kang", "Cho", "Yoon", "Jang", "Lim", "Han", "Shin",
+];
+
+const VIETNAMESE_MALE_NAMES: &[&str] = &[
+    "Anh", "Hùng", "Tuấn", "Dũng", "Quang", "Hải", "Khánh", "Việt",
+];
+
+const VIETNAMESE_FEMALE_NAMES: &[&str] = &[
+    "Linh", "Thảo", "Hương", "Lan", "Phương", "Trang", "Nhung", "Hằng",
+];
+
+/// "Văn" plus middle names usual for men
+const VIETNAMESE_MALE_MIDDLE_NAMES: &[&str] = &["Văn", "Đức", "Minh", "Thanh", "Quốc", "Hữu"];
+
+/// "Thị" plus middle names usual for women
+const VIETNAMESE_FEMALE_MIDDLE_NAMES: &[&str] = &["Thị", "Ngọc", "Minh", "Thanh", "Xuân", "Thu"];
+
+const VIETNAMESE_SURNAMES: &[&str] = &[
+    "Nguyễn", "Trần", "Lê", "Phạm", "Hoàng", "Huỳnh", "Phan", "Vũ", "Võ", "Đặng", "Bùi", "Đỗ",
+    "Hồ", "Ngô", "Dương",
+];
+
+const ARABIC_MALE_NAMES: &[&str] = &[
+    "Mohammed",
+    "Ahmed",
+    "Omar",
+    "Khalid",
+    "Youssef",
+    "Hassan",
+    "Hussein",
+    "Abdullah",
+    "Ibrahim",
+    "Tariq",
+    "Karim",
+    "Saeed",
+    "Abdul Rahman",
+];
+
+const ARABIC_FEMALE_NAMES: &[&str] = &[
+    "Fatima", "Aisha", "Layla", "Mariam", "Noor", "Huda", "Salma", "Yasmin", "Rania", "Zainab",
+];
+
+/// Family names written with the `al-` article
+const ARABIC_ARTICLE_SURNAMES: &[&str] = &[
+    "Rashid", "Hashimi", "Masri", "Qasimi", "Farsi", "Amin", "Zahrani", "Tamimi", "Sayed",
+];
+
+const ARABIC_SURNAMES: &[&str] = &[
+    "Haddad", "Mansour", "Khoury", "Nasser", "Saleh", "Aziz", "Hamdan", "Shaheen",
+];
+
+const DUTCH_MALE_NAMES: &[&str] = &["Jan", "Pieter", "Daan", "Bram", "Sven", "Joost", "Maarten"];
+
+const DUTCH_FEMALE_NAMES: &[&str] = &[
+    "Emma", "Sanne", "Anouk", "Lotte", "Femke", "Ingrid", "Marieke",
+];
+
+const DUTCH_PARTICLE_SURNAMES: &[&str] = &[
+    "van der Berg",
+    "van den Bosch",
+    "de Vries",
+    "van Dijk",
+    "de Jong",
+    "van Leeuwen",
+    "van der Meer",
+    "de Boer",
+    "van Beek",
+    "ter Horst",
+    "van 't Hof",
+];
+
+const DUTCH_SURNAMES: &[&str] = &[
+    "Bakker", "Visser", "Jansen", "Smit", "Mulder", "Meijer", "Bos", "Vos", "Peters", "Hendriks",
+];
+
+fn pick<R: Rng + ?Sized>(items: &[&str], rng: &mut R) -> String {
+    items[rng.gen_range(0..items.len())].to_string()
+}
+
+/// Generates a clean name in `locale`.
+pub fn generate_locale_name<R: Rng + ?Sized>(locale: NameLocale, rng: &mut R) -> Name {
+    match locale {
+        NameLocale::English => generate_clean_name(rng),
+        NameLocale::Hispanic => {
+            let first = if rng.gen_bool(0.25) {
+                pick(HISPANIC_COMPOUND_GIVEN_NAMES, rng)
+            } else {
+                pick(HISPANIC_GIVEN_NAMES, rng)
+            };
+            let paternal = pick(HISPANIC_SURNAMES, rng);
+            // Most records carry both the paternal and the maternal surname
+            let last = if rng.gen_bool(0.85) {
+                format!("{} {}", paternal, pick(HISPANIC_SURNAMES, rng))
+            } else {
+                paternal
+            };
+            Name::new(first, String::new(), last)
+        }
+        NameLocale::Chinese => Name::new(
+            pick(CHINESE_GIVEN_NAMES, rng),
+            String::new(),
+            pick(CHINESE_SURNAMES, rng),
+        ),
+        NameLocale::Japanese => Name::new(
+            pick(JAPANESE_GIVEN_NAMES, rng),
+            String::new(),
+            pick(JAPANESE_SURNAMES, rng),
+        ),
+        NameLocale::Korean => Name::new(
+            pick(KOREAN_GIVEN_NAMES, rng),
+            String::new(),
+            pick(KOREAN_SURNAMES, rng),
+        ),
+        NameLocale::Vietnamese => {
+            let (given, middle) = if rng.gen_bool(0.5) {
+                (VIETNAMESE_FEMALE_NAMES, VIETNAMESE_FEMALE_MIDDLE_NAMES)
+            } else {
+                (VIETNAMESE_MALE_NAMES, VIETNAMESE_MALE_MIDDLE_NAMES)
+            };
+            Name::new(
+                pick(given, rng),
+                pick(middle, rng),
+                pick(VIETNAMESE_SURNAMES, rng),
+            )
+        }
+        NameLocale::Arabic => {
+            let female = rng.gen_bool(0.5);
+            let first = if female {
+                pick(ARABIC_FEMALE_NAMES, rng)
+            } else {
+                pick(ARABIC_MALE_NAMES, rng)
+            };
+            // Patronymic: "son of" / "daughter of" the father's given name
+            let middle = if rng.gen_bool(0.5) {
+                let particle = if female { "bint" } else { "bin" };
+                format!("{} {}", particle, pick(ARABIC_MALE_NAMES, rng))
+            } else {
+                String::new()
+            };
+            let last = if rng.gen_bool(0.6) {
+                format!("al-{}", pick(ARABIC_ARTICLE_SURNAMES, rng))
+            } else {
+                pick(ARABIC_SURNAMES, rng)
+            };
+            Name::new(first, middle, last)
+        }
+        NameLocale::Dutch => {
+            let given = if rng.gen_bool(0.5) {
+                DUTCH_FEMALE_NAMES
+            } else {
+                DUTCH_MALE_NAMES
+            };
+            let first = pick(given, rng);
+            let middle = if rng.gen_bool(0.3) {
+                let others: Vec<&str> = given.iter().copied().filter(|n| *n != first).collect();
+                pick(&others, rng)
+            } else {
+                String::new()
+            };
+            let last = if rng.gen_bool(0.5) {
+                pick(DUTCH_PARTICLE_SURNAMES, rng)
+            } else {
+                pick(DUTCH_SURNAMES, rng)
+            };
+            Name::new(first, middle, last)
+        }
+    }
+}
+
+/// Generates a clean name in a locale drawn uniformly from `locales`.
+///
+/// # Panics
+/// Panics if `locales` is empty.
+pub fn generate_clean_locale_name<R: Rng + ?Sized>(locales: &[NameLocale], rng: &mut R) -> Name {
+    // A single locale draws nothing, so `en` alone reproduces
+    // `generate_clean_name` exactly
+    let locale = match locales {
+        [locale] => *locale,
+        _ => locales[rng.gen_range(0..locales.len())],
+    };
+    generate_locale_name(locale, rng)
+}
+
+/// Groups of romanizations of the same name. A spelling may sit in more
+/// than one group (Korean and Chinese "Lee").
+const TRANSLITERATIONS: &[&[&str]] = &[
+    // Arabic
+    &["Mohammed", "Muhammad", "Mohamed", "Mohammad", "Mohamad"],
+    &["Ahmed", "Ahmad"],
+    &["Omar", "Umar"],
+    &["Khalid", "Khaled"],
+    &["Youssef", "Yusuf", "Yousef", "Youssouf"],
+    &["Hassan", "Hasan"],
+    &["Hussein", "Husayn", "Hussain", "Hossein"],
+    &["Abdullah", "Abdallah"],
+    &["Ibrahim", "Ebrahim"],
+    &["Saeed", "Said", "Sayeed"],
+    &["Fatima", "Fatimah", "Fatma"],
+    &["Aisha", "Aishah", "Ayesha"],
+    &["Layla", "Leila", "Laila"],
+    &["Mariam", "Maryam", "Meriem"],
+    &["Zainab", "Zaynab", "Zeinab"],
+    // Chinese: Pinyin, Wade-Giles and Cantonese
+    &["Zhang", "Chang", "Cheung"],
+    &["Wang", "Wong"],
+    &["Li", "Lee"],
+    &["Chen", "Chan", "Chin"],
+    &["Zhou", "Chou", "Chow"],
+    &["Huang", "Hwang", "Wong"],
+    &["Liu", "Lau"],
+    &["Zhao", "Chao", "Chiu"],
+    &["Wu", "Ng", "Woo"],
+    &["Xu", "Hsu", "Tsui"],
+    &["Yang", "Yeung"],
+    &["Lin", "Lam"],
+    &["Guo", "Kuo", "Kwok"],
+    &["He", "Ho"],
+    // Korean: Revised Romanization and McCune-Reischauer
+    &["Lee", "Yi", "Rhee"],
+    &["Park", "Pak", "Bak"],
+    &["Choi", "Choe", "Choy"],
+    &["Jung", "Jeong", "Chung"],
+    &["Kang", "Gang"],
+    &["Cho", "Jo"],
+    &["Yoon", "Yun"],
+    &["Lim", "Im", "Rim"],
+    &["Kim", "Gim"],
+    &["Shin", "Sin"],
+    // Japanese: long vowels marked, doubled, dropped or written with h
+    &["Satō", "Sato", "Satou", "Satoh"],
+    &["Itō", "Ito", "Itou", "Itoh"],
+    &["Katō", "Kato", "Katou", "Katoh"],
+    &["Saitō", "Saito", "Saitou", "Saitoh"],
+    &["Ōno", "Ono", "Oono", "Ohno"],
+    &["Kōno", "Kono", "Kouno", "Kohno"],
+    &["Yūki", "Yuki", "Yuuki"],
+    &["Yūto", "Yuto", "Yuuto"],
+    &["Sōta", "Sota", "Souta"],
+    &["Shōta", "Shota", "Shouta"],
+    &["Ryō", "Ryo", "Ryou"],
+    &["Yōko", "Yoko", "Youko"],
+];
+
+/// Other romanizations of `word`, from every group that lists it.
+pub fn transliterations(word: &str) -> Vec<&'static str> {
+    let mut spellings: Vec<&'static str> = Vec::new();
+    for group in TRANSLITERATIONS {
+        if group.contains(&word) {
+            for &spelling in group.iter() {
+                if spelling != word && !spellings.contains(&spelling) {
+                    spellings.push(spelling);
+                }
+            }
+        }
+    }
+    spellings
+}
+
+/// The unaccented form of a lowercase Latin letter, if it has one.
+fn fold_lowercase(c: char) -> Option<char> {
+    let folded = match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ạ' | 'ả' | 'ấ' | 'ầ' | 'ẩ' | 'ẫ' | 'ậ'
+        | 'ắ' | 'ằ' | 'ẳ' | 'ẵ' | 'ặ' => 'a',
+        'ç' => 'c',
+        'đ' => 'd',
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ẹ' | 'ẻ' | 'ẽ' | 'ế' | 'ề' | 'ể' | 'ễ' | 'ệ' => {
+            'e'
+        }
+        'ì' | 'í' | 'î' | 'ï' | 'ī' | 'ĩ' | 'ỉ' | 'ị' => 'i',
+        'ñ' => 'n',
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ō' | 'ơ' | 'ọ' | 'ỏ' | 'ố' | 'ồ' | 'ổ' | 'ỗ' | 'ộ' | 'ớ'
+        | 'ờ' | 'ở' | 'ỡ' | 'ợ' => 'o',
+        'ù' | 'ú' | 'û' | 'ü' | 'ū' | 'ũ' | 'ư' | 'ụ' | 'ủ' | 'ứ' | 'ừ' | 'ử' | 'ữ' | 'ự' => {
+            'u'
+        }
+        'ý' | 'ÿ' | 'ỳ' | 'ỵ' | 'ỷ' | 'ỹ' => 'y',
+        _ => return None,
+    };
+    Some(folded)
+}
+
+/// Strips diacritics from Latin letters: "Nguyễn" → "Nguyen",
+/// "Đặng" → "Dang", "Satō" → "Sato". Other characters pass through.
+pub fn strip_accents(s: &str) -> String {
+    s.chars()
+        .map(|c| {
+            if c.is_ascii() {
+                return c;
+            }
+            let mut lower = c.to_lowercase();
+            match (lower.next(), lower.next()) {
+                (Some(l), None) => match fold_lowercase(l) {
+                    Some(folded) if c.is_uppercase() => folded.to_ascii_uppercase(),
+                    Some(folded) => folded,
+                    None => c,
+                },
+                _ => c,
+            }
+        })
+        .collect()
+}
+
+/// Dutch surname particles, longest first so "van der" wins over "van"
+const DUTCH_PARTICLES: &[&str] = &[
+    "van der", "van den", "van de", "van 't", "van", "de", "ter", "ten",
+];
+
+/// Ways a surname's particle is commonly mangled: "van der Berg" →
+/// "Van der Berg", "Van Der Berg", "Vanderberg", "Berg"; "al-Rashid" →
+/// "Al-Rashid", "Al Rashid", "Alrashid", "el-Rashid", "Rashid".
+pub fn particle_variants(surname: &str) -> Vec<String> {
+    let mut variants = Vec::new();
+
+    if let Some(rest) = surname
+        .strip_prefix("al-")
+        .or_else(|| surname.strip_prefix("Al-"))
+    {
+        variants.push(format!("Al-{}", rest));
+        variants.push(format!("Al {}", rest));
+        variants.push(format!("Al{}", rest.to_lowercase()));
+        variants.push(format!("el-{}", rest));
+        variants.push(rest.to_string());
+    } else if let Some(particle) = DUTCH_PARTICLES.iter().find(|p| {
+        surname
+            .get(..p.len())
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case(p))
+            && surname.as_bytes().get(p.len()) == Some(&b' ')
+    }) {
+        let rest = &surname[particle.len() + 1..];
+        let words: Vec<&str> = surname[..particle.len()].split(' ').collect();
+        let capitalized: Vec<String> = words.iter().map(|w| capitalize(w)).collect();
+        let mut first_capital = vec![capitalize(words[0])];
+        first_capital.extend(words[1..].iter().map(|w| w.to_string()));
+
+        variants.push(format!("{} {}", first_capital.join(" "), rest));
+        variants.push(format!("{} {}", capitalized.join(" "), rest));
+        variants.push(format!(
+            "{}{}",
+            capitalize(&words.concat().replace('\'', "")),
+            rest.to_lowercase()
+        ));
+        variants.push(rest.to_string());
+    }
+
+    variants.retain(|v| v != surname);
+    variants.dedup();
+    variants
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Other ways to write a multi-part given name: "Min-jun" → "Minjun",
+/// "Min Jun", "Min-Jun"; "Juan Carlos" → "JuanCarlos", "Juan-Carlos".
+pub fn given_name_spellings(given: &str) -> Vec<String> {
+    let Some(separator) = given.chars().find(|&c| c == '-' || c == ' ') else {
+        return Vec::new();
+    };
+    let parts: Vec<&str> = given.split(separator).collect();
+    let capitalized: Vec<String> = parts.iter().map(|p| capitalize(p)).collect();
+
+    let mut spellings = if separator == '-' {
+        // Run together, a romanized given name keeps only its first capital
+        vec![
+            capitalize(&parts.concat().to_lowercase()),
+            capitalized.join(" "),
+            capitalized.join("-"),
+        ]
+    } else {
+        vec![capitalized.concat(), capitalized.join("-")]
+    };
+    spellings.retain(|s| s != given);
+    spellings.dedup();
+    spellings
+}
+
+/// Whether `last_name` is a family name from a culture that writes it
+/// before the given name ("Zhang", "Satō", "Nguyễn"), with or without its
+/// diacritics.
+pub fn is_family_name_first(last_name: &str) -> bool {
+    let stripped = strip_accents(last_name);
+    NameLocale::ALL
+        .iter()
+        .filter(|locale| locale.family_name_first())
+        .flat_map(|locale| locale.family_first_surnames())
+        .any(|surname| strip_accents(surname) == stripped)
+}
+
+/// Splits a two-surname Hispanic last name ("García Márquez") into its
+/// paternal and maternal surnames. Particle surnames ("van Dijk") and
+/// single surnames are not split.
+pub fn surname_pair(last_name: &str) -> Option<(&str, &str)> {
+    let (paternal, maternal) = last_name.split_once(' ')?;
+    let capitalized = |s: &str| s.chars().next().is_some_and(|c| c.is_uppercase());
+    if capitalized(paternal) && capitalized(maternal) && !maternal.contains(' ') {
+        Some((paternal, maternal))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_locale_from_str() {
+        assert_eq!("es".parse::<NameLocale>().unwrap(), NameLocale::Hispanic);
+        assert_eq!(
+            "Spanish".parse::<NameLocale>().unwrap(),
+            NameLocale::Hispanic
+        );
+        assert_eq!("VI".parse::<NameLocale>().unwrap(), NameLocale::Vietnamese);
+        assert_eq!("dutch".parse::<NameLocale>().unwrap(), NameLocale::Dutch);
+        let err = "fr".parse::<NameLocale>().unwrap_err().to_string();
+        assert!(err.contains("Valid locales: en, es, zh"), "{}", err);
+    }
+
+    #[test]
+    fn test_locale_names_have_expected_shape() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..100 {
+            let vietnamese = generate_locale_name(NameLocale::Vietnamese, &mut rng);
+            let female = VIETNAMESE_FEMALE_NAMES.contains(&vietnamese.first_name.as_str());
+            let middles = if female {
+                VIETNAMESE_FEMALE_MIDDLE_NAMES
+            } else {
+                VIETNAMESE_MALE_MIDDLE_NAMES
+            };
+            assert!(
+                middles.contains(&vietnamese.middle_name.as_str()),
+                "{:?}",
+                vietnamese
+            );
+
+            let dutch = generate_locale_name(NameLocale::Dutch, &mut rng);
+            if !dutch.middle_name.is_empty() {
+                assert_ne!(dutch.middle_name, dutch.first_name);
+                let given = if DUTCH_FEMALE_NAMES.contains(&dutch.first_name.as_str()) {
+                    DUTCH_FEMALE_NAMES
+                } else {
+                    DUTCH_MALE_NAMES
+                };
+                assert!(given.contains(&dutch.middle_name.as_str()), "{:?}", dutch);
+            }
+
+            let korean = generate_locale_name(NameLocale::Korean, &mut rng);
+            assert!(korean.first_name.contains('-'), "{:?}", korean);
+
+            let arabic = generate_locale_name(NameLocale::Arabic, &mut rng);
+            assert!(
+                arabic.middle_name.is_empty()
+                    || arabic.middle_name.starts_with("bin ")
+                    || arabic.middle_name.starts_with("bint "),
+                "{:?}",
+                arabic
+            );
+        }
+    }
+
+    #[test]
+    fn test_hispanic_names_mostly_have_two_surnames() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let pairs = (0..200)
+            .map(|_| generate_locale_name(NameLocale::Hispanic, &mut rng))
+            .filter(|name| surname_pair(&name.last_name).is_some())
+            .count();
+        assert!(pairs > 140, "only {} of 200 had two surnames", pairs);
+    }
+
+    #[test]
+    fn test_transliterations() {
+        assert_eq!(transliterations("Wang"), ["Wong"]);
+        assert_eq!(transliterations("Lee"), ["Li", "Yi", "Rhee"]);
+        assert!(transliterations("Satō").contains(&"Satoh"));
+        assert!(transliterations("Smith").is_empty());
+    }
+
+    #[test]
+    fn test_strip_accents() {
+        assert_eq!(strip_accents("Nguyễn"), "Nguyen");
+        assert_eq!(strip_accents("Đặng Thị Thảo"), "Dang Thi Thao");
+        assert_eq!(strip_accents("José Núñez"), "Jose Nunez");
+        assert_eq!(strip_accents("Ōno"), "Ono");
+        assert_eq!(strip_accents("Smith"), "Smith");
+    }
+
+    #[test]
+    fn test_particle_variants() {
+        assert_eq!(
+            particle_variants("van der Berg"),
+            ["Van der Berg", "Van Der Berg", "Vanderberg", "Berg"]
+        );
+        assert_eq!(
+            particle_variants("al-Rashid"),
+            ["Al-Rashid", "Al Rashid", "Alrashid", "el-Rashid", "Rashid"]
+        );
+        assert!(particle_variants("van 't Hof").contains(&"Vanthof".to_string()));
+        assert!(particle_variants("Bakker").is_empty());
+        assert!(particle_variants("Vanterpool").is_empty());
+    }
+
+    #[test]
+    fn test_given_name_spellings() {
+        assert_eq!(
+            given_name_spellings("Min-jun"),
+            ["Minjun", "Min Jun", "Min-Jun"]
+        );
+        assert_eq!(
+            given_name_spellings("Juan Carlos"),
+            ["JuanCarlos", "Juan-Carlos"]
+        );
+        assert!(given_name_spellings("Wei").is_empty());
+    }
+
+    #[test]
+    fn test_is_family_name_first() {
+        assert!(is_family_name_first("Zhang"));
+        assert!(is_family_name_first("Satō"));
+        assert!(is_family_name_first("Sato"));
+        assert!(is_family_name_first("Nguyen"));
+        assert!(!is_family_name_first("García"));
+        assert!(!is_family_name_first("Smith"));
+    }
+
+    #[test]
+    fn test_surname_pair() {
+        assert_eq!(surname_pair("García Márquez"), Some(("García", "Márquez")));
+        assert_eq!(surname_pair("van Dijk"), None);
+        assert_eq!(surname_pair("Smith"), None);
+    }
+}
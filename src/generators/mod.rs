@@ -1,2 +1,303 @@
 pub mod addresses;
+pub mod clusters;
+pub mod companies;
+pub mod dates;
+pub mod emails;
+pub mod international;
+pub mod locales;
 pub mod names;
+pub mod nicknames;
+pub mod parallel;
+pub mod people;
+pub mod phones;
+pub mod streets;
+pub mod tins;
+pub mod typos;
+pub mod unicode;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::error::{Error, Result};
+
+/// Creates the random number generator used for a run.
+///
+/// The same seed always yields the same sequence, so identical arguments
+/// and seed reproduce byte-identical output. Without a seed the generator
+/// is seeded from OS entropy.
+pub fn create_rng(seed: Option<u64>) -> StdRng {
+    match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    }
+}
+
+/// A generated record paired with the clean record it was derived from and
+/// the ordered list of variance operations applied to it (e.g.
+/// `abbreviate_suffix`, `remove_zip`, `typo:transpose`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Traced<T> {
+    pub record: T,
+    pub clean: T,
+    pub variances: Vec<String>,
+}
+
+impl<T: Clone> Traced<T> {
+    /// Wraps a record that had no variance applied.
+    pub fn unchanged(record: T) -> Self {
+        Self {
+            clean: record.clone(),
+            record,
+            variances: Vec::new(),
+        }
+    }
+}
+
+/// Lazily generates an endless stream of traced records, each drawn by
+/// `clean` and then varied by `vary` (e.g.
+/// [`vary_address`](addresses::vary_address)).
+///
+/// Nothing is buffered, so `.take(n)` the stream into a
+/// [`RecordSink`](crate::writer::RecordSink) to produce datasets of any
+/// size in constant memory.
+pub fn traced<'a, T, R, C, V>(
+    rng: &'a mut R,
+    mut clean: C,
+    mut vary: V,
+) -> impl Iterator<Item = Traced<T>> + 'a
+where
+    R: Rng + ?Sized,
+    C: FnMut(&mut R) -> T + 'a,
+    V: FnMut(T, &mut R) -> Traced<T> + 'a,
+{
+    std::iter::repeat_with(move || {
+        let record = clean(rng);
+        vary(record, rng)
+    })
+}
+
+/// Labels the variances of one component of a composite record, e.g.
+/// `remove_zip` on the address becomes `address.remove_zip`.
+pub(crate) fn prefixed(
+    component: &'static str,
+    variances: Vec<String>,
+) -> impl Iterator<Item = String> {
+    variances
+        .into_iter()
+        .map(move |variance| format!("{}.{}", component, variance))
+}
+
+/// Writes `word` in the case of `like`: all caps, all lowercase, or as
+/// given when `like` is mixed case ("BILL" -> "WILLIAM", "main st" ->
+/// "street").
+pub fn match_case(like: &str, word: &str) -> String {
+    let letters = || like.chars().filter(|c| c.is_alphabetic());
+    if letters().all(char::is_uppercase) && like.chars().count() > 1 {
+        word.to_uppercase()
+    } else if letters().all(char::is_lowercase) {
+        word.to_lowercase()
+    } else {
+        word.to_string()
+    }
+}
+
+/// A named variance pattern that can be individually enabled or disabled.
+///
+/// Implemented by the per-generator `VariancePattern` enums so the CLI can
+/// select patterns by name (`--variance po_box --exclude-variance remove_state`).
+pub trait NamedPattern: Copy + PartialEq + 'static {
+    /// Every pattern, in declaration order
+    const ALL: &'static [Self];
+
+    /// The snake_case name used on the command line and in ground truth
+    fn name(&self) -> &'static str;
+
+    /// The record fields this pattern can change, used to target per-field
+    /// error rates from a variance profile
+    fn fields(&self) -> &'static [&'static str];
+
+    /// Looks up a pattern by its name (case-insensitive).
+    fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim().to_lowercase();
+        Self::ALL.iter().copied().find(|p| p.name() == name)
+    }
+}
+
+/// Resolves `--variance` / `--exclude-variance` lists into the enabled patterns.
+///
+/// An empty `include` list means every pattern. Patterns in `exclude` are
+/// then removed. Unknown names and an empty final selection are errors.
+pub fn select_patterns<P: NamedPattern>(include: &[String], exclude: &[String]) -> Result<Vec<P>> {
+    let parse = |names: &[String]| -> Result<Vec<P>> {
+        names
+            .iter()
+            .map(|name| {
+                P::from_name(name).ok_or_else(|| {
+                    let valid: Vec<&str> = P::ALL.iter().map(|p| p.name()).collect();
+                    Error::InvalidArgument(format!(
+                        "Unknown variance pattern '{}'. Valid patterns: {}",
+                        name,
+                        valid.join(", ")
+                    ))
+                })
+            })
+            .collect()
+    };
+
+    let included = if include.is_empty() {
+        P::ALL.to_vec()
+    } else {
+        parse(include)?
+    };
+    let excluded = parse(exclude)?;
+
+    let mut selected: Vec<P> = Vec::new();
+    for pattern in included {
+        if !excluded.contains(&pattern) && !selected.contains(&pattern) {
+            selected.push(pattern);
+        }
+    }
+
+    if selected.is_empty() {
+        return Err(Error::InvalidArgument(
+            "No variance patterns left enabled".to_string(),
+        ));
+    }
+
+    Ok(selected)
+}
+
+/// Validates that an error rate lies within [0.0, 1.0].
+pub fn validate_error_rate(error_rate: f64) -> Result<()> {
+    if !(0.0..=1.0).contains(&error_rate) {
+        return Err(Error::InvalidArgument(
+            "Error rate must be between 0.0 and 1.0".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Validates that a record count is non-zero.
+pub fn validate_count(count: usize) -> Result<()> {
+    if count == 0 {
+        return Err(Error::InvalidArgument(
+            "Count must be greater than 0".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_zero_count() {
+        assert!(validate_count(0).is_err());
+    }
+
+    #[test]
+    fn test_validate_error_rate_below_zero() {
+        assert!(validate_error_rate(-0.1).is_err());
+    }
+
+    #[test]
+    fn test_validate_error_rate_above_one() {
+        assert!(validate_error_rate(1.5).is_err());
+    }
+
+    #[test]
+    fn test_create_rng_same_seed_same_sequence() {
+        use rand::Rng;
+
+        let a: u64 = create_rng(Some(42)).gen();
+        let b: u64 = create_rng(Some(42)).gen();
+        assert_eq!(a, b);
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum TestPattern {
+        One,
+        Two,
+        Three,
+    }
+
+    impl NamedPattern for TestPattern {
+        const ALL: &'static [Self] = &[TestPattern::One, TestPattern::Two, TestPattern::Three];
+
+        fn name(&self) -> &'static str {
+            match self {
+                TestPattern::One => "one",
+                TestPattern::Two => "two",
+                TestPattern::Three => "three",
+            }
+        }
+
+        fn fields(&self) -> &'static [&'static str] {
+            &["value"]
+        }
+    }
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_select_patterns_defaults_to_all() {
+        let selected: Vec<TestPattern> = select_patterns(&[], &[]).unwrap();
+        assert_eq!(selected, TestPattern::ALL);
+    }
+
+    #[test]
+    fn test_select_patterns_include_and_exclude() {
+        let selected: Vec<TestPattern> =
+            select_patterns(&names(&["one", "THREE", "two"]), &names(&["two"])).unwrap();
+        assert_eq!(selected, vec![TestPattern::One, TestPattern::Three]);
+    }
+
+    #[test]
+    fn test_select_patterns_unknown_name() {
+        let result: Result<Vec<TestPattern>> = select_patterns(&names(&["four"]), &[]);
+        let message = result.unwrap_err().to_string();
+        assert!(message.contains("Unknown variance pattern 'four'"));
+        assert!(message.contains("one, two, three"));
+    }
+
+    #[test]
+    fn test_select_patterns_all_excluded() {
+        let result: Result<Vec<TestPattern>> =
+            select_patterns(&[], &names(&["one", "two", "three"]));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_traced_is_lazy_and_unbounded() {
+        let mut rng = create_rng(Some(2));
+        let mut drawn = 0;
+        let records: Vec<_> = traced(
+            &mut rng,
+            |rng| {
+                drawn += 1;
+                rng.gen::<u8>()
+            },
+            |record, _| Traced::unchanged(record),
+        )
+        .take(7)
+        .collect();
+        assert_eq!(records.len(), 7);
+        assert_eq!(drawn, 7);
+    }
+
+    #[test]
+    fn test_traced_unchanged() {
+        let traced = Traced::unchanged("record".to_string());
+        assert_eq!(traced.record, traced.clean);
+        assert!(traced.variances.is_empty());
+    }
+
+    #[test]
+    fn test_validate_valid_inputs() {
+        assert!(validate_count(100).is_ok());
+        assert!(validate_error_rate(0.5).is_ok());
+    }
+}
@@ -1,8 +1,19 @@
 use fake::faker::name::en::*;
 use fake::Fake;
 use rand::Rng;
+use serde::Serialize;
+use std::fmt;
 
-#[derive(Debug, Clone, PartialEq)]
+use crate::generators::locales::{
+    given_name_spellings, is_family_name_first, particle_variants, strip_accents, surname_pair,
+    transliterations,
+};
+use crate::generators::nicknames::{nicknames, substitutions};
+use crate::generators::typos::{self, TypoModel};
+use crate::generators::{match_case, traced, unicode, NamedPattern, Traced};
+use crate::profile::VarianceProfile;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct Name {
     pub first_name: String,
     pub middle_name: String,
@@ -27,15 +38,13 @@ impl Name {
     }
 }
 
-pub fn generate_clean_name() -> Name {
-    let mut rng = rand::thread_rng();
-
-    let first_name: String = FirstName().fake();
-    let last_name: String = LastName().fake();
+pub fn generate_clean_name<R: Rng + ?Sized>(rng: &mut R) -> Name {
+    let first_name: String = FirstName().fake_with_rng(rng);
+    let last_name: String = LastName().fake_with_rng(rng);
 
     // 50% chance of having a middle name
     let middle_name = if rng.gen_bool(0.5) {
-        FirstName().fake()
+        FirstName().fake_with_rng(rng)
     } else {
         String::new()
     };
@@ -52,94 +61,72 @@ pub fn generate_clean_name() -> Name {
 /// # Arguments
 /// * `count` - Number of names to generate
 /// * `error_rate` - Probability (0.0 to 1.0) of applying variance to each name
+/// * `patterns` - Variance patterns that may be applied
+/// * `rng` - Random number generator; seed it to reproduce a dataset
 ///
 /// # Panics
 /// Panics if `error_rate` is outside the range [0.0, 1.0]
 ///
 /// # Examples
 /// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rust_faker::generators::names::{generate_names, VariancePattern};
+/// use rust_faker::generators::NamedPattern;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+///
 /// // Generate 10 clean names
-/// let clean_names = generate_names(10, 0.0);
+/// let clean_names = generate_names(10, 0.0, VariancePattern::ALL, &mut rng);
 ///
-/// // Generate 10 names with 30% variance
-/// let varied_names = generate_names(10, 0.3);
+/// // Generate 10 names with 30% variance, only typos
+/// let typo_names = generate_names(10, 0.3, &[VariancePattern::Typo], &mut rng);
 /// ```
-pub fn generate_names(count: usize, error_rate: f64) -> Vec<Name> {
-    let mut rng = rand::thread_rng();
-    let mut names = Vec::with_capacity(count);
-
-    for _ in 0..count {
-        let clean_name = generate_clean_name();
-
-        // Apply variance based on error rate
-        let name = if rng.gen_bool(error_rate) {
-            apply_name_variance(clean_name)
-        } else {
-            clean_name
-        };
-
-        names.push(name);
-    }
+pub fn generate_names<R: Rng + ?Sized>(
+    count: usize,
+    error_rate: f64,
+    patterns: &[VariancePattern],
+    rng: &mut R,
+) -> Vec<Name> {
+    let profile = VarianceProfile::uniform(patterns);
+    traced(rng, generate_clean_name, |name, rng| {
+        vary_name(name, error_rate, &profile, rng)
+    })
+    .take(count)
+    .map(|traced| traced.record)
+    .collect()
+}
 
-    names
+/// Applies profile variance to a single name: record-level variance with
+/// probability `error_rate`, then any per-field error rates.
+pub fn vary_name<R: Rng + ?Sized>(
+    name: Name,
+    error_rate: f64,
+    profile: &VarianceProfile<VariancePattern>,
+    rng: &mut R,
+) -> Traced<Name> {
+    profile.vary(name, error_rate, rng, apply_pattern)
 }
 
-fn get_random_prefix() -> String {
-    let mut rng = rand::thread_rng();
+fn get_random_prefix<R: Rng + ?Sized>(rng: &mut R) -> String {
     let prefixes = ["Dr.", "Mr.", "Mrs.", "Ms.", "Prof.", "Rev."];
     prefixes[rng.gen_range(0..prefixes.len())].to_string()
 }
 
-fn get_random_suffix() -> String {
-    let mut rng = rand::thread_rng();
+fn get_random_suffix<R: Rng + ?Sized>(rng: &mut R) -> String {
     let suffixes = ["Jr.", "Sr.", "II", "III", "IV", "MD", "PhD", "Esq."];
     suffixes[rng.gen_range(0..suffixes.len())].to_string()
 }
 
-/// Adds a realistic typo to a name string.
-///
-/// Randomly applies one of three typo types:
-/// - Double a letter (e.g., "John" -> "Johhn")
-/// - Transpose two adjacent letters (e.g., "John" -> "Jhon")
-/// - Remove a letter (e.g., "John" -> "Jon")
-///
-/// Returns the original string unchanged if it's empty or has less than 2 characters.
-fn add_typo(name: &str) -> String {
-    if name.is_empty() {
-        return name.to_string();
-    }
-
-    let mut rng = rand::thread_rng();
-    let mut chars: Vec<char> = name.chars().collect();
-
-    if chars.len() < 2 {
-        return name.to_string();
+/// A random nickname for the formal first name `first_name`, if the
+/// nickname dictionary has one.
+fn pick_nickname<R: Rng + ?Sized>(first_name: &str, rng: &mut R) -> Option<String> {
+    let nicknames = nicknames(first_name);
+    if nicknames.is_empty() {
+        return None;
     }
-
-    let typo_type = rng.gen_range(0..3);
-    match typo_type {
-        0 => {
-            // Double a letter
-            let pos = rng.gen_range(0..chars.len());
-            chars.insert(pos, chars[pos]);
-        }
-        1 => {
-            // Transpose two letters
-            if chars.len() >= 2 {
-                let pos = rng.gen_range(0..chars.len() - 1);
-                chars.swap(pos, pos + 1);
-            }
-        }
-        _ => {
-            // Remove a letter (but keep at least one)
-            if chars.len() > 1 {
-                let pos = rng.gen_range(0..chars.len());
-                chars.remove(pos);
-            }
-        }
-    }
-
-    chars.into_iter().collect()
+    let nickname = nicknames[rng.gen_range(0..nicknames.len())];
+    Some(match_case(first_name, nickname))
 }
 
 /// Converts a string to alternating case (e.g., "Joshua" -> "JoShUa").
@@ -159,124 +146,442 @@ fn to_mixed_case(name: &str) -> String {
         .collect()
 }
 
-/// Applies 1-3 random variance patterns to a Name.
-///
-/// Randomly selects and applies between 1 and 3 variance types from 15 possible patterns:
-/// - Field swapping and combining (0-3)
-/// - Hyphenation patterns (4-6)
-/// - Prefixes and suffixes (7-8)
-/// - Nickname formats (9-10)
-/// - Case variations (11-13)
-/// - Typos (14)
-///
-/// Variance patterns can be applied multiple times, potentially creating cumulative effects.
-fn apply_name_variance(mut name: Name) -> Name {
-    let mut rng = rand::thread_rng();
+/// Applies a typo from `model` to the first or last name, relabeling the
+/// change with the kind of typo applied.
+fn misspell<R: Rng + ?Sized>(name: &mut Name, model: TypoModel, label: &mut String, rng: &mut R) {
+    let fields = [&mut name.first_name, &mut name.last_name];
+    if let Some(typo_label) = typos::misspell(fields, model, rng) {
+        *label = typo_label.to_string();
+    }
+}
 
-    // Apply 1-3 random variance patterns
-    let num_variances = rng.gen_range(1..=3);
+/// Replaces one word of the name that has other romanizations with one of
+/// them, e.g. the "Saeed" in "bin Saeed". Leaves the name unchanged if no
+/// word has any.
+fn transliterate<R: Rng + ?Sized>(name: &mut Name, rng: &mut R) {
+    let mut candidates: Vec<(usize, usize)> = Vec::new();
+    let fields = [&name.first_name, &name.middle_name, &name.last_name];
+    for (field, value) in fields.iter().enumerate() {
+        for (index, word) in value.split(' ').enumerate() {
+            if !transliterations(word).is_empty() {
+                candidates.push((field, index));
+            }
+        }
+    }
+    if candidates.is_empty() {
+        return;
+    }
 
-    for _ in 0..num_variances {
-        let variance_type = rng.gen_range(0..15);
+    let (field, index) = candidates[rng.gen_range(0..candidates.len())];
+    let value = match field {
+        0 => &mut name.first_name,
+        1 => &mut name.middle_name,
+        _ => &mut name.last_name,
+    };
+    let mut words: Vec<&str> = value.split(' ').collect();
+    let spellings = transliterations(words[index]);
+    words[index] = spellings[rng.gen_range(0..spellings.len())];
+    *value = words.join(" ");
+}
 
-        match variance_type {
-            0 => {
-                // Swap first and last names
-                std::mem::swap(&mut name.first_name, &mut name.last_name);
-            }
-            1 => {
-                // First and last combined in first name
-                name.first_name = format!("{} {}", name.first_name, name.last_name);
-                name.last_name = String::new();
-            }
-            2 => {
-                // "LastName, FirstName" format in first name
-                name.first_name = format!("{}, {}", name.last_name, name.first_name);
-                name.last_name = String::new();
-            }
-            3 => {
-                // Full name in one field
-                name.first_name = format!(
-                    "{} {} {}",
-                    name.first_name, name.middle_name, name.last_name
-                );
-                name.middle_name = String::new();
-                name.last_name = String::new();
-            }
-            4 => {
-                // Hyphenated last name
-                let extra_last: String = LastName().fake();
-                name.last_name = format!("{}-{}", name.last_name, extra_last);
-            }
-            5 => {
-                // Hyphenated first name
-                let extra_first: String = FirstName().fake();
-                name.first_name = format!("{}-{}", name.first_name, extra_first);
-            }
-            6 => {
-                // Multiple last names
-                let extra_last: String = LastName().fake();
-                name.last_name = format!("{} {}", name.last_name, extra_last);
+/// A single, individually selectable name variance pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VariancePattern {
+    /// Swap first and last names
+    SwapFirstLast,
+    /// "John Doe" in the first name field, last name blank
+    CombineFirstLast,
+    /// "Doe, John" in the first name field, last name blank
+    LastCommaFirst,
+    /// First, middle and last name all in the first name field
+    FullNameInFirst,
+    /// "Smith-Johnson"
+    HyphenateLast,
+    /// "Mary-Jane"
+    HyphenateFirst,
+    /// "Garcia Lopez"
+    MultipleLast,
+    /// "Dr. John"
+    AddPrefix,
+    /// "Doe Jr."
+    AddSuffix,
+    /// A quoted nickname in place of the first name: "\"Bob\"" for "Robert"
+    QuotedNickname,
+    /// A nickname after the first name: "Robert (Bob)", "Zelda (Zel)"
+    ParenthesizedNickname,
+    /// The first (or, failing that, middle) name swapped for a nickname or
+    /// back for the formal name: "William" -> "Bill", "Peggy" -> "Margaret"
+    Nickname,
+    /// ALL CAPS
+    Uppercase,
+    /// all lowercase
+    Lowercase,
+    /// MiXeD CaSe
+    MixedCase,
+    /// A doubled, transposed or missing letter in the first or last name
+    Typo,
+    /// A neighbouring QWERTY key in the first or last name: "Jihn"
+    KeyboardTypo,
+    /// A sound-alike spelling of the first or last name: "Steven" for
+    /// "Stephen", "Fillips" for "Phillips"
+    PhoneticTypo,
+    /// An OCR misreading in the first or last name: "Bums" for "Burns"
+    OcrTypo,
+    /// A speech-to-text mishearing of the first or last name: "Leigh" for
+    /// "Lee", "Mc Donald" for "McDonald"
+    VoiceTypo,
+    /// Diacritics stripped: "Nguyễn Văn Hùng" -> "Nguyen Van Hung"
+    DropAccents,
+    /// Another romanization of a name: "Mohammed" -> "Muhammad",
+    /// "Zhang" -> "Cheung", "Satō" -> "Satoh"
+    Transliteration,
+    /// Hispanic surnames reversed: "García Márquez" -> "Márquez García"
+    SurnameOrder,
+    /// Only the paternal surname kept: "García Márquez" -> "García"
+    DropSecondSurname,
+    /// The first part of a multi-part surname read as a middle name:
+    /// "José | García | Márquez", "Jan | van der | Berg"
+    SplitSurname,
+    /// A surname particle recapitalized, joined or dropped: "Van Der Berg",
+    /// "Vanderberg", "Al Rashid", "Rashid"
+    SurnameParticle,
+    /// A multi-part given name rejoined: "Min-jun" -> "Minjun", "Min Jun"
+    GivenNameSpacing,
+    /// An East Asian name kept in its native family-name-first order:
+    /// "Zhang Wei" in the first name field, or first and last swapped
+    FamilyNameFirst,
+    /// One name stressed with Unicode look-alikes: a decomposed accent,
+    /// zero-width or no-break space, smart quote, homoglyph, ...
+    Unicode,
+}
+
+impl NamedPattern for VariancePattern {
+    const ALL: &'static [Self] = &[
+        VariancePattern::SwapFirstLast,
+        VariancePattern::CombineFirstLast,
+        VariancePattern::LastCommaFirst,
+        VariancePattern::FullNameInFirst,
+        VariancePattern::HyphenateLast,
+        VariancePattern::HyphenateFirst,
+        VariancePattern::MultipleLast,
+        VariancePattern::AddPrefix,
+        VariancePattern::AddSuffix,
+        VariancePattern::QuotedNickname,
+        VariancePattern::ParenthesizedNickname,
+        VariancePattern::Nickname,
+        VariancePattern::Uppercase,
+        VariancePattern::Lowercase,
+        VariancePattern::MixedCase,
+        VariancePattern::Typo,
+        VariancePattern::KeyboardTypo,
+        VariancePattern::PhoneticTypo,
+        VariancePattern::OcrTypo,
+        VariancePattern::VoiceTypo,
+        VariancePattern::DropAccents,
+        VariancePattern::Transliteration,
+        VariancePattern::SurnameOrder,
+        VariancePattern::DropSecondSurname,
+        VariancePattern::SplitSurname,
+        VariancePattern::SurnameParticle,
+        VariancePattern::GivenNameSpacing,
+        VariancePattern::FamilyNameFirst,
+        VariancePattern::Unicode,
+    ];
+
+    fn name(&self) -> &'static str {
+        match self {
+            VariancePattern::SwapFirstLast => "swap_first_last",
+            VariancePattern::CombineFirstLast => "combine_first_last",
+            VariancePattern::LastCommaFirst => "last_comma_first",
+            VariancePattern::FullNameInFirst => "full_name_in_first",
+            VariancePattern::HyphenateLast => "hyphenate_last",
+            VariancePattern::HyphenateFirst => "hyphenate_first",
+            VariancePattern::MultipleLast => "multiple_last",
+            VariancePattern::AddPrefix => "add_prefix",
+            VariancePattern::AddSuffix => "add_suffix",
+            VariancePattern::QuotedNickname => "quoted_nickname",
+            VariancePattern::ParenthesizedNickname => "parenthesized_nickname",
+            VariancePattern::Nickname => "nickname",
+            VariancePattern::Uppercase => "uppercase",
+            VariancePattern::Lowercase => "lowercase",
+            VariancePattern::MixedCase => "mixed_case",
+            VariancePattern::Typo => "typo",
+            VariancePattern::KeyboardTypo => "keyboard_typo",
+            VariancePattern::PhoneticTypo => "phonetic_typo",
+            VariancePattern::OcrTypo => "ocr_typo",
+            VariancePattern::VoiceTypo => "voice_typo",
+            VariancePattern::DropAccents => "drop_accents",
+            VariancePattern::Transliteration => "transliteration",
+            VariancePattern::SurnameOrder => "surname_order",
+            VariancePattern::DropSecondSurname => "drop_second_surname",
+            VariancePattern::SplitSurname => "split_surname",
+            VariancePattern::SurnameParticle => "surname_particle",
+            VariancePattern::GivenNameSpacing => "given_name_spacing",
+            VariancePattern::FamilyNameFirst => "family_name_first",
+            VariancePattern::Unicode => "unicode",
+        }
+    }
+
+    fn fields(&self) -> &'static [&'static str] {
+        match self {
+            VariancePattern::SwapFirstLast
+            | VariancePattern::CombineFirstLast
+            | VariancePattern::LastCommaFirst
+            | VariancePattern::Typo
+            | VariancePattern::KeyboardTypo
+            | VariancePattern::PhoneticTypo
+            | VariancePattern::OcrTypo
+            | VariancePattern::VoiceTypo => &["first_name", "last_name"],
+            VariancePattern::FullNameInFirst
+            | VariancePattern::Uppercase
+            | VariancePattern::Lowercase
+            | VariancePattern::MixedCase
+            | VariancePattern::DropAccents
+            | VariancePattern::Transliteration
+            | VariancePattern::FamilyNameFirst
+            | VariancePattern::Unicode => &["first_name", "middle_name", "last_name"],
+            VariancePattern::HyphenateLast
+            | VariancePattern::MultipleLast
+            | VariancePattern::AddSuffix
+            | VariancePattern::SurnameOrder
+            | VariancePattern::DropSecondSurname
+            | VariancePattern::SurnameParticle => &["last_name"],
+            VariancePattern::SplitSurname => &["middle_name", "last_name"],
+            VariancePattern::Nickname => &["first_name", "middle_name"],
+            VariancePattern::HyphenateFirst
+            | VariancePattern::AddPrefix
+            | VariancePattern::QuotedNickname
+            | VariancePattern::ParenthesizedNickname
+            | VariancePattern::GivenNameSpacing => &["first_name"],
+        }
+    }
+}
+
+impl fmt::Display for VariancePattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Applies a single variance pattern to a name in place.
+///
+/// Returns the ground-truth label of the change, or `None` if the pattern
+/// had nothing to act on. Most patterns are labeled with their name. Typos
+/// are labeled `typo:<kind>` and Unicode stress `unicode:<kind>`. A nickname
+/// swap is labeled `nickname:<field>:<from>-><to>`. A dictionary nickname
+/// written in quotes is labeled `quoted_nickname:first_name:<from>-><to>`,
+/// and one written in parentheses
+/// `parenthesized_nickname:first_name:<from>-><to>`.
+pub fn apply_pattern<R: Rng + ?Sized>(
+    name: &mut Name,
+    pattern: VariancePattern,
+    rng: &mut R,
+) -> Option<String> {
+    let before = name.clone();
+    let mut label = pattern.name().to_string();
+
+    match pattern {
+        VariancePattern::SwapFirstLast => {
+            std::mem::swap(&mut name.first_name, &mut name.last_name);
+        }
+        VariancePattern::CombineFirstLast => {
+            name.first_name = format!("{} {}", name.first_name, name.last_name);
+            name.last_name = String::new();
+        }
+        VariancePattern::LastCommaFirst => {
+            name.first_name = format!("{}, {}", name.last_name, name.first_name);
+            name.last_name = String::new();
+        }
+        VariancePattern::FullNameInFirst => {
+            name.first_name = format!(
+                "{} {} {}",
+                name.first_name, name.middle_name, name.last_name
+            );
+            name.middle_name = String::new();
+            name.last_name = String::new();
+        }
+        VariancePattern::HyphenateLast => {
+            let extra_last: String = LastName().fake_with_rng(rng);
+            name.last_name = format!("{}-{}", name.last_name, extra_last);
+        }
+        VariancePattern::HyphenateFirst => {
+            let extra_first: String = FirstName().fake_with_rng(rng);
+            name.first_name = format!("{}-{}", name.first_name, extra_first);
+        }
+        VariancePattern::MultipleLast => {
+            let extra_last: String = LastName().fake_with_rng(rng);
+            name.last_name = format!("{} {}", name.last_name, extra_last);
+        }
+        VariancePattern::AddPrefix => {
+            name.first_name = format!("{} {}", get_random_prefix(rng), name.first_name);
+        }
+        VariancePattern::AddSuffix => {
+            if !name.last_name.is_empty() {
+                name.last_name = format!("{} {}", name.last_name, get_random_suffix(rng));
             }
-            7 => {
-                // Add prefix to first name
-                name.first_name = format!("{} {}", get_random_prefix(), name.first_name);
+        }
+        VariancePattern::QuotedNickname => {
+            let nickname = match pick_nickname(&name.first_name, rng) {
+                Some(nickname) => {
+                    label = format!("{}:first_name:{}->{}", label, name.first_name, nickname);
+                    nickname
+                }
+                None => name.first_name.clone(),
+            };
+            name.first_name = format!("\"{}\"", nickname);
+        }
+        VariancePattern::ParenthesizedNickname => {
+            if !name.first_name.is_empty() {
+                let short = match pick_nickname(&name.first_name, rng) {
+                    Some(nickname) => {
+                        label = format!("{}:first_name:{}->{}", label, name.first_name, nickname);
+                        nickname
+                    }
+                    None => name.first_name.chars().take(3).collect(),
+                };
+                name.first_name = format!("{} ({})", name.first_name, short);
             }
-            8 => {
-                // Add suffix to last name
-                if !name.last_name.is_empty() {
-                    name.last_name = format!("{} {}", name.last_name, get_random_suffix());
+        }
+        VariancePattern::Nickname => {
+            let givens = [
+                ("first_name", &mut name.first_name),
+                ("middle_name", &mut name.middle_name),
+            ];
+            for (field, given) in givens {
+                let swaps = substitutions(given);
+                if !swaps.is_empty() {
+                    let swap = swaps[rng.gen_range(0..swaps.len())].clone();
+                    label = format!("nickname:{}:{}->{}", field, given, swap);
+                    *given = swap;
+                    break;
                 }
             }
-            9 => {
-                // Nickname in quotes
-                name.first_name = format!("\"{}\"", name.first_name);
+        }
+        VariancePattern::Uppercase => {
+            name.first_name = name.first_name.to_uppercase();
+            name.middle_name = name.middle_name.to_uppercase();
+            name.last_name = name.last_name.to_uppercase();
+        }
+        VariancePattern::Lowercase => {
+            name.first_name = name.first_name.to_lowercase();
+            name.middle_name = name.middle_name.to_lowercase();
+            name.last_name = name.last_name.to_lowercase();
+        }
+        VariancePattern::MixedCase => {
+            name.first_name = to_mixed_case(&name.first_name);
+            name.middle_name = to_mixed_case(&name.middle_name);
+            name.last_name = to_mixed_case(&name.last_name);
+        }
+        VariancePattern::Typo => misspell(name, TypoModel::Basic, &mut label, rng),
+        VariancePattern::KeyboardTypo => misspell(name, TypoModel::Keyboard, &mut label, rng),
+        VariancePattern::PhoneticTypo => misspell(name, TypoModel::Phonetic, &mut label, rng),
+        VariancePattern::OcrTypo => misspell(name, TypoModel::Ocr, &mut label, rng),
+        VariancePattern::VoiceTypo => misspell(name, TypoModel::Voice, &mut label, rng),
+        VariancePattern::DropAccents => {
+            name.first_name = strip_accents(&name.first_name);
+            name.middle_name = strip_accents(&name.middle_name);
+            name.last_name = strip_accents(&name.last_name);
+        }
+        VariancePattern::Transliteration => {
+            transliterate(name, rng);
+        }
+        VariancePattern::SurnameOrder => {
+            if let Some((paternal, maternal)) = surname_pair(&name.last_name) {
+                name.last_name = format!("{} {}", maternal, paternal);
             }
-            10 => {
-                // Nickname in parentheses
-                if !name.first_name.is_empty() {
-                    name.first_name = format!(
-                        "{} ({})",
-                        name.first_name,
-                        &name.first_name[..3.min(name.first_name.len())]
-                    );
-                }
+        }
+        VariancePattern::DropSecondSurname => {
+            if let Some((paternal, _)) = surname_pair(&name.last_name) {
+                name.last_name = paternal.to_string();
             }
-            11 => {
-                // All caps
-                name.first_name = name.first_name.to_uppercase();
-                name.middle_name = name.middle_name.to_uppercase();
-                name.last_name = name.last_name.to_uppercase();
+        }
+        VariancePattern::SplitSurname => {
+            if let Some((head, tail)) = name.last_name.rsplit_once(' ') {
+                let (head, tail) = (head.to_string(), tail.to_string());
+                name.middle_name = if name.middle_name.is_empty() {
+                    head
+                } else {
+                    format!("{} {}", name.middle_name, head)
+                };
+                name.last_name = tail;
             }
-            12 => {
-                // All lowercase
-                name.first_name = name.first_name.to_lowercase();
-                name.middle_name = name.middle_name.to_lowercase();
-                name.last_name = name.last_name.to_lowercase();
+        }
+        VariancePattern::SurnameParticle => {
+            let variants = particle_variants(&name.last_name);
+            if !variants.is_empty() {
+                name.last_name = variants[rng.gen_range(0..variants.len())].clone();
             }
-            13 => {
-                // Mixed case
-                name.first_name = to_mixed_case(&name.first_name);
-                name.middle_name = to_mixed_case(&name.middle_name);
-                name.last_name = to_mixed_case(&name.last_name);
+        }
+        VariancePattern::GivenNameSpacing => {
+            let spellings = given_name_spellings(&name.first_name);
+            if !spellings.is_empty() {
+                name.first_name = spellings[rng.gen_range(0..spellings.len())].clone();
             }
-            _ => {
-                // Add typo
+        }
+        VariancePattern::FamilyNameFirst => {
+            if is_family_name_first(&name.last_name) {
                 if rng.gen_bool(0.5) {
-                    name.first_name = add_typo(&name.first_name);
+                    let parts = [&name.last_name, &name.middle_name, &name.first_name];
+                    let words: Vec<&str> = parts
+                        .iter()
+                        .filter(|part| !part.is_empty())
+                        .map(|part| part.as_str())
+                        .collect();
+                    name.first_name = words.join(" ");
+                    name.middle_name = String::new();
+                    name.last_name = String::new();
                 } else {
-                    name.last_name = add_typo(&name.last_name);
+                    std::mem::swap(&mut name.first_name, &mut name.last_name);
                 }
             }
         }
+        VariancePattern::Unicode => {
+            let fields = &mut [
+                &mut name.first_name,
+                &mut name.middle_name,
+                &mut name.last_name,
+            ];
+            if let Some(unicode_label) = unicode::stress(fields, rng) {
+                label = unicode_label.to_string();
+            }
+        }
     }
 
-    name
+    if *name == before {
+        None
+    } else {
+        Some(label)
+    }
+}
+
+/// Applies 1-3 random variance patterns, drawn from `patterns`, to a Name.
+///
+/// Patterns are chosen uniformly and may repeat, potentially creating
+/// cumulative effects. See [`VariancePattern`] for the available patterns.
+pub fn apply_name_variance<R: Rng + ?Sized>(
+    name: Name,
+    patterns: &[VariancePattern],
+    rng: &mut R,
+) -> Name {
+    trace_name_variance(name, patterns, rng).record
+}
+
+/// Applies variance like [`apply_name_variance`], recording the clean name
+/// and the ordered list of variance operations that changed it.
+pub fn trace_name_variance<R: Rng + ?Sized>(
+    name: Name,
+    patterns: &[VariancePattern],
+    rng: &mut R,
+) -> Traced<Name> {
+    VarianceProfile::uniform(patterns).trace(name, rng, apply_pattern)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::generators::locales::{generate_clean_locale_name, NameLocale};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
 
     #[test]
     fn test_name_creation() {
@@ -311,7 +616,7 @@ mod tests {
 
     #[test]
     fn test_generate_clean_name() {
-        let name = generate_clean_name();
+        let name = generate_clean_name(&mut rand::thread_rng());
         assert!(!name.first_name.is_empty());
         assert!(!name.last_name.is_empty());
         // middle_name can be empty (50% chance)
@@ -319,34 +624,27 @@ mod tests {
 
     #[test]
     fn test_generate_clean_name_randomness() {
-        let name1 = generate_clean_name();
-        let name2 = generate_clean_name();
+        let mut rng = rand::thread_rng();
+        let name1 = generate_clean_name(&mut rng);
+        let name2 = generate_clean_name(&mut rng);
         // Very unlikely to generate identical names
         assert_ne!(name1, name2);
     }
 
     #[test]
     fn test_get_random_prefix() {
-        let prefix = get_random_prefix();
-        let valid_prefixes = vec!["Dr.", "Mr.", "Mrs.", "Ms.", "Prof.", "Rev."];
+        let prefix = get_random_prefix(&mut rand::thread_rng());
+        let valid_prefixes = ["Dr.", "Mr.", "Mrs.", "Ms.", "Prof.", "Rev."];
         assert!(valid_prefixes.contains(&prefix.as_str()));
     }
 
     #[test]
     fn test_get_random_suffix() {
-        let suffix = get_random_suffix();
-        let valid_suffixes = vec!["Jr.", "Sr.", "II", "III", "IV", "MD", "PhD", "Esq."];
+        let suffix = get_random_suffix(&mut rand::thread_rng());
+        let valid_suffixes = ["Jr.", "Sr.", "II", "III", "IV", "MD", "PhD", "Esq."];
         assert!(valid_suffixes.contains(&suffix.as_str()));
     }
 
-    #[test]
-    fn test_add_typo() {
-        let result = add_typo("Joshua");
-        assert!(!result.is_empty());
-        // Verify length changed by at most 1 character
-        assert!((result.len() as i32 - "Joshua".len() as i32).abs() <= 1);
-    }
-
     #[test]
     fn test_to_mixed_case() {
         let result = to_mixed_case("Joshua");
@@ -369,7 +667,7 @@ mod tests {
         );
 
         // Apply variance and verify it doesn't panic
-        let varied = apply_name_variance(clean);
+        let varied = apply_name_variance(clean, VariancePattern::ALL, &mut rand::thread_rng());
         // At least one field should have content
         assert!(
             !varied.first_name.is_empty()
@@ -378,15 +676,83 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_trace_name_variance_records_clean_and_variances() {
+        let clean = Name::new(
+            "Joshua".to_string(),
+            "Allen".to_string(),
+            "Caudill".to_string(),
+        );
+
+        let traced = trace_name_variance(
+            clean.clone(),
+            VariancePattern::ALL,
+            &mut StdRng::seed_from_u64(11),
+        );
+        assert_eq!(traced.clean, clean);
+        assert!(traced.variances.len() <= 3);
+        if traced.variances.is_empty() {
+            assert_eq!(traced.record, clean);
+        }
+    }
+
+    #[test]
+    fn test_vary_name_zero_error_rate_has_no_variances() {
+        let profile = VarianceProfile::uniform(VariancePattern::ALL);
+        let mut rng = StdRng::seed_from_u64(3);
+        for _ in 0..10 {
+            let t = vary_name(generate_clean_name(&mut rng), 0.0, &profile, &mut rng);
+            assert_eq!(t.record, t.clean);
+            assert!(t.variances.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_variance_pattern_names_round_trip() {
+        for pattern in VariancePattern::ALL {
+            assert_eq!(VariancePattern::from_name(pattern.name()), Some(*pattern));
+        }
+    }
+
+    #[test]
+    fn test_apply_pattern_typo_label() {
+        let mut name = Name::new(
+            "Joshua".to_string(),
+            "Allen".to_string(),
+            "Caudill".to_string(),
+        );
+        let label = apply_pattern(&mut name, VariancePattern::Typo, &mut rand::thread_rng());
+        assert!(label.unwrap().starts_with("typo:"));
+    }
+
+    #[test]
+    fn test_trace_name_variance_only_uses_selected_patterns() {
+        let clean = Name::new(
+            "Joshua".to_string(),
+            "Allen".to_string(),
+            "Caudill".to_string(),
+        );
+        let mut rng = StdRng::seed_from_u64(4);
+
+        for _ in 0..20 {
+            let traced =
+                trace_name_variance(clean.clone(), &[VariancePattern::Uppercase], &mut rng);
+            assert_eq!(traced.record.first_name, "JOSHUA");
+            assert_eq!(traced.record.last_name, "CAUDILL");
+            // Repeating the pattern changes nothing further, so it is recorded once
+            assert_eq!(traced.variances, vec!["uppercase"]);
+        }
+    }
+
     #[test]
     fn test_generate_names_count() {
-        let names = generate_names(10, 0.0);
+        let names = generate_names(10, 0.0, VariancePattern::ALL, &mut rand::thread_rng());
         assert_eq!(names.len(), 10);
     }
 
     #[test]
     fn test_generate_names_zero_error_rate() {
-        let names = generate_names(5, 0.0);
+        let names = generate_names(5, 0.0, VariancePattern::ALL, &mut rand::thread_rng());
         // All should be clean
         for name in names {
             assert!(!name.first_name.is_empty());
@@ -396,8 +762,294 @@ mod tests {
 
     #[test]
     fn test_generate_names_full_error_rate() {
-        let names = generate_names(5, 1.0);
+        let names = generate_names(5, 1.0, VariancePattern::ALL, &mut rand::thread_rng());
         // All should have variance applied
         assert_eq!(names.len(), 5);
     }
+
+    #[test]
+    fn test_generate_names_same_seed_is_deterministic() {
+        let first = generate_names(50, 0.5, VariancePattern::ALL, &mut StdRng::seed_from_u64(7));
+        let second = generate_names(50, 0.5, VariancePattern::ALL, &mut StdRng::seed_from_u64(7));
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_generate_names_different_seeds_differ() {
+        let first = generate_names(50, 0.5, VariancePattern::ALL, &mut StdRng::seed_from_u64(7));
+        let second = generate_names(50, 0.5, VariancePattern::ALL, &mut StdRng::seed_from_u64(8));
+        assert_ne!(first, second);
+    }
+
+    fn name(first: &str, middle: &str, last: &str) -> Name {
+        Name::new(first.to_string(), middle.to_string(), last.to_string())
+    }
+
+    fn apply(mut name: Name, pattern: VariancePattern) -> (Name, Option<String>) {
+        let label = apply_pattern(&mut name, pattern, &mut StdRng::seed_from_u64(1));
+        (name, label)
+    }
+
+    #[test]
+    fn test_parenthesized_nickname_counts_characters() {
+        let (name, _) = apply(
+            name("Thảo", "Thị", "Nguyễn"),
+            VariancePattern::ParenthesizedNickname,
+        );
+        assert_eq!(name.first_name, "Thảo (Thả)");
+    }
+
+    #[test]
+    fn test_drop_accents() {
+        let (name, label) = apply(name("Hùng", "Văn", "Đặng"), VariancePattern::DropAccents);
+        assert_eq!(name, self::name("Hung", "Van", "Dang"));
+        assert_eq!(label.as_deref(), Some("drop_accents"));
+        assert_eq!(
+            apply(
+                self::name("John", "", "Smith"),
+                VariancePattern::DropAccents
+            )
+            .1,
+            None
+        );
+    }
+
+    #[test]
+    fn test_transliteration() {
+        let (name, _) = apply(
+            name("Khalid", "bin Saeed", "al-Rashid"),
+            VariancePattern::Transliteration,
+        );
+        assert!(
+            name.first_name == "Khaled"
+                || ["bin Said", "bin Sayeed"].contains(&name.middle_name.as_str()),
+            "{:?}",
+            name
+        );
+        assert_eq!(name.last_name, "al-Rashid");
+        assert_eq!(
+            apply(
+                self::name("John", "", "Smith"),
+                VariancePattern::Transliteration
+            )
+            .1,
+            None
+        );
+    }
+
+    #[test]
+    fn test_hispanic_surname_patterns() {
+        let clean = name("José Luis", "", "García Márquez");
+        assert_eq!(
+            apply(clean.clone(), VariancePattern::SurnameOrder)
+                .0
+                .last_name,
+            "Márquez García"
+        );
+        assert_eq!(
+            apply(clean.clone(), VariancePattern::DropSecondSurname)
+                .0
+                .last_name,
+            "García"
+        );
+        let (split, _) = apply(clean.clone(), VariancePattern::SplitSurname);
+        assert_eq!(split, name("José Luis", "García", "Márquez"));
+
+        let dutch = name("Jan", "", "van der Berg");
+        assert_eq!(apply(dutch.clone(), VariancePattern::SurnameOrder).1, None);
+        assert_eq!(
+            apply(dutch, VariancePattern::SplitSurname).0,
+            name("Jan", "van der", "Berg")
+        );
+    }
+
+    #[test]
+    fn test_surname_particle_and_given_name_spacing() {
+        let (name, label) = apply(
+            name("Min-jun", "", "van Dijk"),
+            VariancePattern::SurnameParticle,
+        );
+        assert!(["Van Dijk", "Vandijk", "Dijk"].contains(&name.last_name.as_str()));
+        assert_eq!(label.as_deref(), Some("surname_particle"));
+
+        let (name, _) = apply(name, VariancePattern::GivenNameSpacing);
+        assert!(["Minjun", "Min Jun", "Min-Jun"].contains(&name.first_name.as_str()));
+    }
+
+    #[test]
+    fn test_family_name_first() {
+        let mut forms = Vec::new();
+        for seed in 0..20 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let mut vietnamese = name("Hùng", "Văn", "Nguyễn");
+            let label = apply_pattern(&mut vietnamese, VariancePattern::FamilyNameFirst, &mut rng);
+            assert_eq!(label.as_deref(), Some("family_name_first"));
+            assert!(
+                vietnamese == name("Nguyễn Văn Hùng", "", "")
+                    || vietnamese == name("Nguyễn", "Văn", "Hùng"),
+                "{:?}",
+                vietnamese
+            );
+            if !forms.contains(&vietnamese) {
+                forms.push(vietnamese);
+            }
+        }
+        assert_eq!(forms.len(), 2);
+
+        let (chinese, _) = apply(name("Wei", "", "Zhang"), VariancePattern::FamilyNameFirst);
+        assert!([name("Zhang Wei", "", ""), name("Zhang", "", "Wei")].contains(&chinese));
+        assert_eq!(
+            apply(name("John", "", "Smith"), VariancePattern::FamilyNameFirst).1,
+            None
+        );
+    }
+
+    #[test]
+    fn test_locale_names_zero_error_rate_are_clean() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let profile = VarianceProfile::uniform(VariancePattern::ALL);
+        for _ in 0..100 {
+            let clean = generate_clean_locale_name(&NameLocale::ALL, &mut rng);
+            let traced = vary_name(clean, 0.0, &profile, &mut rng);
+            assert_eq!(traced.record, traced.clean);
+            assert!(!traced.record.first_name.is_empty());
+            assert!(!traced.record.last_name.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_single_english_locale_matches_generate_clean_name() {
+        let profile = VarianceProfile::uniform(VariancePattern::ALL);
+        let mut rng = StdRng::seed_from_u64(9);
+        let locale: Vec<_> = traced(
+            &mut rng,
+            |rng| generate_clean_locale_name(&[NameLocale::English], rng),
+            |name, rng| vary_name(name, 0.5, &profile, rng),
+        )
+        .take(20)
+        .collect();
+        let mut rng = StdRng::seed_from_u64(9);
+        let english: Vec<_> = traced(&mut rng, generate_clean_name, |name, rng| {
+            vary_name(name, 0.5, &profile, rng)
+        })
+        .take(20)
+        .collect();
+        assert_eq!(locale, english);
+    }
+
+    #[test]
+    fn test_unicode_label_names_kind() {
+        let (name, label) = apply(name("Joshua", "", "O'Brien"), VariancePattern::Unicode);
+        assert!(label.unwrap().starts_with("unicode:"));
+        assert_ne!(name, self::name("Joshua", "", "O'Brien"));
+    }
+
+    #[test]
+    fn test_every_pattern_survives_multibyte_names() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let stressed = [
+            name("Thảo", "Thị", "Nguyễn"),
+            name("Jose\u{301}", "", "N\u{303}u\u{301}n\u{303}ez"),
+            name("Ｊｏ", "\u{200B}", "Ха"),
+            name("é", "", "ß"),
+        ];
+        for clean in &stressed {
+            for _ in 0..20 {
+                trace_name_variance(clean.clone(), VariancePattern::ALL, &mut rng);
+            }
+        }
+    }
+
+    #[test]
+    fn test_typo_models_label_their_kind() {
+        for (pattern, expected) in [
+            (VariancePattern::KeyboardTypo, "typo:keyboard"),
+            (VariancePattern::PhoneticTypo, "typo:phonetic"),
+            (VariancePattern::OcrTypo, "typo:ocr"),
+        ] {
+            let (varied, label) = apply(name("Catherine", "", "Burns"), pattern);
+            assert_eq!(label.as_deref(), Some(expected));
+            assert_ne!(varied, name("Catherine", "", "Burns"));
+        }
+        let (varied, label) = apply(name("Ronald", "", "McDonald"), VariancePattern::VoiceTypo);
+        assert_eq!(label.as_deref(), Some("typo:voice"));
+        assert_eq!(varied.first_name, "Ronald");
+        assert_eq!(varied.last_name, "Mc Donald");
+    }
+
+    #[test]
+    fn test_typo_model_without_a_match_leaves_name_unchanged() {
+        let (varied, label) = apply(name("Bob", "", "Ng"), VariancePattern::VoiceTypo);
+        assert_eq!(label, None);
+        assert_eq!(varied, name("Bob", "", "Ng"));
+    }
+
+    #[test]
+    fn test_nickname_swaps_both_ways_and_records_substitution() {
+        let (varied, label) = apply(name("William", "", "Smith"), VariancePattern::Nickname);
+        assert!(["Bill", "Will", "Liam", "Billy", "Willie"].contains(&varied.first_name.as_str()));
+        assert_eq!(
+            label.unwrap(),
+            format!("nickname:first_name:William->{}", varied.first_name)
+        );
+
+        let (varied, label) = apply(name("PEGGY", "", "SMITH"), VariancePattern::Nickname);
+        assert_eq!(varied.first_name, "MARGARET");
+        assert_eq!(
+            label.as_deref(),
+            Some("nickname:first_name:PEGGY->MARGARET")
+        );
+
+        let (varied, label) = apply(name("Zelda", "Peggy", "Smith"), VariancePattern::Nickname);
+        assert_eq!(varied.middle_name, "Margaret");
+        assert_eq!(
+            label.as_deref(),
+            Some("nickname:middle_name:Peggy->Margaret")
+        );
+
+        let (varied, label) = apply(name("Zelda", "", "Smith"), VariancePattern::Nickname);
+        assert_eq!(label, None);
+        assert_eq!(varied, name("Zelda", "", "Smith"));
+    }
+
+    #[test]
+    fn test_nickname_patterns_use_dictionary_nicknames() {
+        let (varied, label) = apply(name("Robert", "", "Smith"), VariancePattern::QuotedNickname);
+        let nickname = varied.first_name.trim_matches('"');
+        assert!(nicknames("Robert").contains(&nickname));
+        assert_eq!(varied.first_name, format!("\"{}\"", nickname));
+        assert_eq!(
+            label,
+            Some(format!("quoted_nickname:first_name:Robert->{}", nickname))
+        );
+
+        let (varied, label) = apply(
+            name("Margaret", "", "Smith"),
+            VariancePattern::ParenthesizedNickname,
+        );
+        let nickname = varied
+            .first_name
+            .strip_prefix("Margaret (")
+            .and_then(|rest| rest.strip_suffix(')'))
+            .unwrap();
+        assert!(nicknames("Margaret").contains(&nickname));
+        assert_eq!(
+            label,
+            Some(format!(
+                "parenthesized_nickname:first_name:Margaret->{}",
+                nickname
+            ))
+        );
+
+        // Without a known nickname the label is left bare
+        let (varied, label) = apply(name("Zelda", "", "Smith"), VariancePattern::QuotedNickname);
+        assert_eq!(varied.first_name, "\"Zelda\"");
+        assert_eq!(label.as_deref(), Some("quoted_nickname"));
+        let (varied, label) = apply(
+            name("Zelda", "", "Smith"),
+            VariancePattern::ParenthesizedNickname,
+        );
+        assert_eq!(varied.first_name, "Zelda (Zel)");
+        assert_eq!(label.as_deref(), Some("parenthesized_nickname"));
+    }
 }
@@ -0,0 +1,196 @@
+//! A bundled nickname dictionary: formal given names and the nicknames
+//! people actually go by, for the `nickname` name variance.
+//!
+//! The table runs formal -> nicknames, and [`substitutions`] reads it in
+//! both directions, so "William" can become "Bill" and "Bill" can become
+//! "William" (or any other name "Bill" is short for).
+
+use crate::generators::match_case;
+
+/// Formal given names and their common nicknames, most common first.
+const NICKNAMES: &[(&str, &[&str])] = &[
+    ("Abigail", &["Abby", "Gail"]),
+    ("Albert", &["Al", "Bert"]),
+    ("Alexander", &["Alex", "Xander", "Sandy"]),
+    ("Alexandra", &["Alex", "Sandra", "Lexi"]),
+    ("Alfred", &["Al", "Alf", "Fred"]),
+    ("Alice", &["Allie", "Ally"]),
+    ("Allison", &["Allie", "Ally"]),
+    ("Amanda", &["Mandy", "Amy"]),
+    ("Andrew", &["Andy", "Drew"]),
+    ("Angela", &["Angie"]),
+    ("Anthony", &["Tony"]),
+    ("Arthur", &["Art", "Artie"]),
+    ("Barbara", &["Barb", "Barbie"]),
+    ("Benjamin", &["Ben", "Benny"]),
+    ("Bernard", &["Bernie"]),
+    ("Beverly", &["Bev"]),
+    ("Bradley", &["Brad"]),
+    ("Calvin", &["Cal"]),
+    ("Cameron", &["Cam"]),
+    ("Carol", &["Carrie"]),
+    ("Caroline", &["Carrie", "Carol"]),
+    ("Catherine", &["Cathy", "Kate", "Cat"]),
+    ("Charles", &["Charlie", "Chuck", "Chas"]),
+    ("Charlotte", &["Charlie", "Lottie"]),
+    ("Christina", &["Chris", "Tina", "Christy"]),
+    ("Christine", &["Chris", "Christy"]),
+    ("Christopher", &["Chris", "Kit", "Topher"]),
+    ("Clifford", &["Cliff"]),
+    ("Cynthia", &["Cindy"]),
+    ("Daniel", &["Dan", "Danny"]),
+    ("David", &["Dave", "Davey"]),
+    ("Deborah", &["Debbie", "Deb"]),
+    ("Dennis", &["Denny"]),
+    ("Donald", &["Don", "Donnie"]),
+    ("Dorothy", &["Dot", "Dottie", "Dolly"]),
+    ("Douglas", &["Doug"]),
+    ("Edward", &["Ed", "Eddie", "Ted", "Ned"]),
+    ("Eleanor", &["Ellie", "Nell", "Nora"]),
+    ("Elizabeth", &["Liz", "Beth", "Betty", "Eliza", "Lizzie"]),
+    ("Emily", &["Em", "Emmy"]),
+    ("Eugene", &["Gene"]),
+    ("Frances", &["Fran", "Frannie"]),
+    ("Francis", &["Frank"]),
+    ("Franklin", &["Frank"]),
+    ("Frederick", &["Fred", "Freddie"]),
+    ("Gabriel", &["Gabe"]),
+    ("Gabrielle", &["Gabby", "Elle"]),
+    ("Gerald", &["Gerry", "Jerry"]),
+    ("Gregory", &["Greg"]),
+    ("Harold", &["Hal", "Harry"]),
+    ("Henry", &["Hank", "Harry"]),
+    ("Herbert", &["Herb", "Bert"]),
+    ("Isabella", &["Bella", "Izzy"]),
+    ("Jacob", &["Jake"]),
+    ("James", &["Jim", "Jimmy", "Jamie"]),
+    ("Janet", &["Jan"]),
+    ("Jeffrey", &["Jeff"]),
+    ("Jennifer", &["Jen", "Jenny"]),
+    ("Jessica", &["Jess", "Jessie"]),
+    ("John", &["Jack", "Johnny"]),
+    ("Jonathan", &["Jon", "Johnny"]),
+    ("Joseph", &["Joe", "Joey"]),
+    ("Joshua", &["Josh"]),
+    ("Judith", &["Judy"]),
+    ("Katherine", &["Kathy", "Kate", "Katie", "Kat"]),
+    ("Kathleen", &["Kathy", "Kate"]),
+    ("Kenneth", &["Ken", "Kenny"]),
+    ("Kimberly", &["Kim"]),
+    ("Lawrence", &["Larry"]),
+    ("Leonard", &["Leo", "Len", "Lenny"]),
+    ("Louis", &["Lou"]),
+    ("Margaret", &["Peggy", "Maggie", "Meg", "Marge"]),
+    ("Martha", &["Marty", "Patsy"]),
+    ("Martin", &["Marty"]),
+    ("Matthew", &["Matt"]),
+    ("Michael", &["Mike", "Mikey", "Mick"]),
+    ("Michelle", &["Shelly"]),
+    ("Mitchell", &["Mitch"]),
+    ("Nancy", &["Nan"]),
+    ("Natalie", &["Nat"]),
+    ("Nathan", &["Nate"]),
+    ("Nathaniel", &["Nate", "Nat"]),
+    ("Nicholas", &["Nick", "Nicky"]),
+    ("Nicole", &["Nikki"]),
+    ("Pamela", &["Pam"]),
+    ("Patricia", &["Pat", "Patty", "Trish"]),
+    ("Patrick", &["Pat", "Paddy"]),
+    ("Peter", &["Pete"]),
+    ("Philip", &["Phil"]),
+    ("Phillip", &["Phil"]),
+    ("Rebecca", &["Becky", "Becca"]),
+    ("Richard", &["Rick", "Dick", "Rich", "Richie"]),
+    ("Robert", &["Bob", "Rob", "Bobby", "Robbie", "Bert"]),
+    ("Ronald", &["Ron", "Ronnie"]),
+    ("Rosemary", &["Rose", "Rosie"]),
+    ("Russell", &["Russ"]),
+    ("Samantha", &["Sam", "Sammie"]),
+    ("Samuel", &["Sam", "Sammy"]),
+    ("Sandra", &["Sandy"]),
+    ("Stephanie", &["Steph"]),
+    ("Stephen", &["Steve"]),
+    ("Steven", &["Steve"]),
+    ("Stuart", &["Stu"]),
+    ("Susan", &["Sue", "Susie"]),
+    ("Suzanne", &["Sue", "Suzy"]),
+    ("Teresa", &["Terry", "Tess"]),
+    ("Theodore", &["Ted", "Teddy", "Theo"]),
+    ("Thomas", &["Tom", "Tommy"]),
+    ("Timothy", &["Tim", "Timmy"]),
+    ("Valerie", &["Val"]),
+    ("Victoria", &["Vicky", "Tori"]),
+    ("Vincent", &["Vince", "Vinny"]),
+    ("Virginia", &["Ginny", "Ginger"]),
+    ("Walter", &["Walt", "Wally"]),
+    ("William", &["Bill", "Will", "Liam", "Billy", "Willie"]),
+    ("Zachary", &["Zach", "Zack"]),
+];
+
+/// Common nicknames for the formal name `formal` (matched
+/// case-insensitively), most common first.
+pub fn nicknames(formal: &str) -> &'static [&'static str] {
+    NICKNAMES
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(formal))
+        .map_or(&[], |(_, nicknames)| nicknames)
+}
+
+/// Formal names `nickname` (matched case-insensitively) is short for:
+/// "Bert" for "Albert", "Herbert" and "Robert".
+pub fn formal_names(nickname: &str) -> Vec<&'static str> {
+    NICKNAMES
+        .iter()
+        .filter(|(_, nicknames)| nicknames.iter().any(|n| n.eq_ignore_ascii_case(nickname)))
+        .map(|(name, _)| *name)
+        .collect()
+}
+
+/// Every name `name` can be swapped for, in either direction, written in
+/// the same case as `name` ("BILL" -> "WILLIAM").
+pub fn substitutions(name: &str) -> Vec<String> {
+    let mut names: Vec<&str> = nicknames(name).to_vec();
+    for formal in formal_names(name) {
+        if !names.contains(&formal) {
+            names.push(formal);
+        }
+    }
+    names.into_iter().map(|n| match_case(name, n)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nicknames_of_formal_name() {
+        assert_eq!(nicknames("Margaret"), ["Peggy", "Maggie", "Meg", "Marge"]);
+        assert!(nicknames("william").contains(&"Liam"));
+        assert!(nicknames("Bill").is_empty());
+    }
+
+    #[test]
+    fn test_formal_names_of_nickname() {
+        assert_eq!(formal_names("Bob"), ["Robert"]);
+        assert_eq!(formal_names("Bert"), ["Albert", "Herbert", "Robert"]);
+        assert!(formal_names("William").is_empty());
+    }
+
+    #[test]
+    fn test_substitutions_run_both_ways_and_keep_case() {
+        assert!(substitutions("William").contains(&"Bill".to_string()));
+        assert_eq!(substitutions("Bill"), ["William"]);
+        assert_eq!(substitutions("BOB"), ["ROBERT"]);
+        assert_eq!(substitutions("peggy"), ["margaret"]);
+        assert!(substitutions("Zelda").is_empty());
+    }
+
+    #[test]
+    fn test_table_is_sorted_without_self_references() {
+        assert!(NICKNAMES.windows(2).all(|w| w[0].0 < w[1].0));
+        for (formal, nicknames) in NICKNAMES {
+            assert!(!nicknames.is_empty());
+            assert!(!nicknames.contains(formal), "{}", formal);
+        }
+    }
+}
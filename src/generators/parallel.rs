@@ -0,0 +1,171 @@
+//! Deterministic multi-threaded record generation.
+//!
+//! Work is split into fixed-size chunks, each with its own RNG derived from
+//! a base seed and the chunk index. Chunks are generated concurrently but
+//! delivered in order, so the output for a given seed is identical no
+//! matter how many threads run.
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::thread;
+
+use crate::error::{Error, Result};
+
+/// Number of records generated from each chunk RNG.
+pub const CHUNK_SIZE: usize = 10_000;
+
+/// Derives the RNG for chunk `chunk` of a run seeded with `base_seed`.
+pub fn chunk_rng(base_seed: u64, chunk: u64) -> StdRng {
+    // Spread chunk indices so neighbouring seeds don't share chunk streams
+    StdRng::seed_from_u64(base_seed ^ chunk.wrapping_mul(0x9E37_79B9_7F4A_7C15))
+}
+
+/// Returns the number of threads to use when none is requested.
+pub fn default_threads() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+/// Validates that a thread count is non-zero.
+pub fn validate_threads(threads: usize) -> Result<()> {
+    if threads == 0 {
+        return Err(Error::InvalidArgument(
+            "Threads must be greater than 0".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Generates `count` records on up to `threads` threads and passes them to
+/// `consume` one chunk at a time, in order.
+///
+/// `generate` is called with a chunk's RNG and the number of records the
+/// chunk must hold. At most `threads` chunks are held in memory at once.
+///
+/// # Examples
+/// ```
+/// use rand::Rng;
+/// use rust_faker::generators::parallel::generate_parallel;
+///
+/// let mut single = Vec::new();
+/// generate_parallel(25_000, 1, 7, |rng, n| (0..n).map(|_| rng.gen::<u32>()).collect(), |chunk| {
+///     single.extend(chunk);
+///     Ok::<(), ()>(())
+/// })
+/// .unwrap();
+///
+/// let mut multi = Vec::new();
+/// generate_parallel(25_000, 4, 7, |rng, n| (0..n).map(|_| rng.gen::<u32>()).collect(), |chunk| {
+///     multi.extend(chunk);
+///     Ok::<(), ()>(())
+/// })
+/// .unwrap();
+///
+/// assert_eq!(single, multi);
+/// ```
+pub fn generate_parallel<T, G, C, E>(
+    count: usize,
+    threads: usize,
+    base_seed: u64,
+    generate: G,
+    mut consume: C,
+) -> std::result::Result<(), E>
+where
+    T: Send,
+    G: Fn(&mut StdRng, usize) -> Vec<T> + Sync,
+    C: FnMut(Vec<T>) -> std::result::Result<(), E>,
+{
+    let chunks = count.div_ceil(CHUNK_SIZE);
+    let chunk_len = |chunk: usize| CHUNK_SIZE.min(count - chunk * CHUNK_SIZE);
+    let run_chunk =
+        |chunk: usize| generate(&mut chunk_rng(base_seed, chunk as u64), chunk_len(chunk));
+
+    if threads <= 1 {
+        for chunk in 0..chunks {
+            consume(run_chunk(chunk))?;
+        }
+        return Ok(());
+    }
+
+    for batch_start in (0..chunks).step_by(threads) {
+        let batch_end = (batch_start + threads).min(chunks);
+        let batch: Vec<Vec<T>> = thread::scope(|scope| {
+            let handles: Vec<_> = (batch_start..batch_end)
+                .map(|chunk| scope.spawn(move || run_chunk(chunk)))
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().expect("generator thread panicked"))
+                .collect()
+        });
+
+        for records in batch {
+            consume(records)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    fn collect(count: usize, threads: usize, seed: u64) -> Vec<u64> {
+        let mut out = Vec::new();
+        generate_parallel(
+            count,
+            threads,
+            seed,
+            |rng, n| (0..n).map(|_| rng.gen::<u64>()).collect(),
+            |chunk| {
+                out.extend(chunk);
+                Ok::<(), ()>(())
+            },
+        )
+        .unwrap();
+        out
+    }
+
+    #[test]
+    fn test_output_independent_of_thread_count() {
+        let count = CHUNK_SIZE * 3 + 17;
+        let single = collect(count, 1, 9);
+        assert_eq!(single.len(), count);
+        assert_eq!(single, collect(count, 2, 9));
+        assert_eq!(single, collect(count, 8, 9));
+    }
+
+    #[test]
+    fn test_different_seeds_differ() {
+        assert_ne!(collect(100, 1, 1), collect(100, 1, 2));
+    }
+
+    #[test]
+    fn test_zero_count_generates_nothing() {
+        assert!(collect(0, 4, 1).is_empty());
+    }
+
+    #[test]
+    fn test_consume_error_stops_generation() {
+        let mut calls = 0;
+        let result = generate_parallel(
+            CHUNK_SIZE * 5,
+            2,
+            1,
+            |_, n| vec![0u8; n],
+            |_| {
+                calls += 1;
+                Err("full")
+            },
+        );
+        assert_eq!(result, Err("full"));
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn test_validate_threads() {
+        assert!(validate_threads(0).is_err());
+        assert!(validate_threads(4).is_ok());
+    }
+}
@@ -0,0 +1,325 @@
+//! Person records combining a name, an address and optional contact data.
+//!
+//! Each component is varied independently with its own profile and error
+//! rate, so one row can carry a clean name next to a damaged address, the
+//! way customer master data does.
+
+use rand::Rng;
+use serde::Serialize;
+
+use crate::generators::addresses::{self, generate_clean_address, Address};
+use crate::generators::dates::{self, generate_clean_date, BirthDates, Date};
+use crate::generators::emails::{self, generate_clean_email, Email};
+use crate::generators::names::{self, generate_clean_name, Name};
+use crate::generators::phones::{self, generate_clean_phone, Phone};
+use crate::generators::{prefixed, NamedPattern, Traced};
+use crate::profile::VarianceProfile;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Person {
+    #[serde(flatten)]
+    pub name: Name,
+    #[serde(flatten)]
+    pub address: Address,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    pub date_of_birth: Option<String>,
+}
+
+impl Person {
+    pub fn to_record(&self) -> Vec<String> {
+        let mut record = self.name.to_record();
+        record.extend(self.address.to_record());
+        for field in [&self.email, &self.phone, &self.date_of_birth] {
+            record.push(field.clone().unwrap_or_default());
+        }
+        record
+    }
+}
+
+/// Which optional contact fields to generate. Disabled fields are left
+/// empty (`null` in JSON) so the column layout never changes.
+#[derive(Debug, Clone, Default)]
+pub struct ContactFields {
+    pub email: bool,
+    pub phone: bool,
+    /// Where dates of birth are drawn from, if they are generated
+    pub date_of_birth: Option<BirthDates>,
+}
+
+/// Per-component variance for person records.
+pub struct PersonVariance {
+    pub names: VarianceProfile<names::VariancePattern>,
+    pub name_error_rate: f64,
+    pub addresses: VarianceProfile<addresses::VariancePattern>,
+    pub address_error_rate: f64,
+    pub emails: VarianceProfile<emails::VariancePattern>,
+    pub email_error_rate: f64,
+    pub phones: VarianceProfile<phones::VariancePattern>,
+    pub phone_error_rate: f64,
+    pub dates: VarianceProfile<dates::VariancePattern>,
+    pub date_error_rate: f64,
+}
+
+impl PersonVariance {
+    /// Every pattern, weighted equally, at one error rate.
+    pub fn uniform(error_rate: f64) -> Self {
+        Self {
+            names: VarianceProfile::uniform(names::VariancePattern::ALL),
+            name_error_rate: error_rate,
+            addresses: VarianceProfile::uniform(addresses::VariancePattern::ALL),
+            address_error_rate: error_rate,
+            emails: VarianceProfile::uniform(emails::VariancePattern::ALL),
+            email_error_rate: error_rate,
+            phones: VarianceProfile::uniform(phones::VariancePattern::ALL),
+            phone_error_rate: error_rate,
+            dates: VarianceProfile::uniform(dates::VariancePattern::ALL),
+            date_error_rate: error_rate,
+        }
+    }
+}
+
+pub fn generate_clean_person<R: Rng + ?Sized>(fields: &ContactFields, rng: &mut R) -> Person {
+    let name = generate_clean_name(rng);
+    let address = generate_clean_address(rng);
+    let email = fields.email.then(|| generate_clean_email(&name, rng));
+    let phone = fields
+        .phone
+        .then(|| generate_clean_phone(Some(&address.state), rng));
+    let date_of_birth = fields
+        .date_of_birth
+        .as_ref()
+        .map(|birth_dates| generate_clean_date(birth_dates, rng));
+
+    Person {
+        name,
+        address,
+        email,
+        phone,
+        date_of_birth,
+    }
+}
+
+/// Varies the name, address, email, phone and date of birth of a person
+/// independently.
+///
+/// Ground-truth variance names are prefixed with the component they
+/// changed, e.g. `name.uppercase`, `address.remove_zip`, `email.domain_typo`,
+/// `phone.dots` or `dob.excel_serial`.
+pub fn vary_person<R: Rng + ?Sized>(
+    person: Person,
+    variance: &PersonVariance,
+    rng: &mut R,
+) -> Traced<Person> {
+    let name = variance.names.vary(
+        person.name.clone(),
+        variance.name_error_rate,
+        rng,
+        names::apply_pattern,
+    );
+    let address = variance.addresses.vary(
+        person.address.clone(),
+        variance.address_error_rate,
+        rng,
+        addresses::apply_pattern,
+    );
+
+    let email = person.email.clone().map(|email| {
+        variance.emails.vary(
+            Email::new(email),
+            variance.email_error_rate,
+            rng,
+            emails::apply_pattern,
+        )
+    });
+
+    let phone = person.phone.clone().map(|phone| {
+        variance.phones.vary(
+            Phone::new(phone),
+            variance.phone_error_rate,
+            rng,
+            phones::apply_pattern,
+        )
+    });
+
+    let date_of_birth = person.date_of_birth.clone().map(|date| {
+        dates::vary_date(
+            Date::new(date),
+            variance.date_error_rate,
+            &variance.dates,
+            rng,
+        )
+    });
+
+    let mut variances: Vec<String> = prefixed("name", name.variances)
+        .chain(prefixed("address", address.variances))
+        .collect();
+    let email = email.map(|email| {
+        variances.extend(prefixed("email", email.variances));
+        email.record.email
+    });
+    let phone = phone.map(|phone| {
+        variances.extend(prefixed("phone", phone.variances));
+        phone.record.phone
+    });
+    let date_of_birth = date_of_birth.map(|date| {
+        variances.extend(prefixed("dob", date.variances));
+        date.record.date
+    });
+    Traced {
+        record: Person {
+            name: name.record,
+            address: address.record,
+            email,
+            phone,
+            date_of_birth,
+        },
+        clean: person,
+        variances,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_contact_fields_are_optional() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let person = generate_clean_person(&ContactFields::default(), &mut rng);
+        assert!(person.email.is_none());
+        assert!(person.phone.is_none());
+        assert!(person.date_of_birth.is_none());
+        assert_eq!(person.to_record().len(), 11);
+
+        let all = ContactFields {
+            email: true,
+            phone: true,
+            date_of_birth: Some(BirthDates::default()),
+        };
+        let person = generate_clean_person(&all, &mut rng);
+        assert!(person.email.is_some());
+        assert!(person.phone.is_some());
+        assert!(person.date_of_birth.is_some());
+    }
+
+    #[test]
+    fn test_zero_error_rate_keeps_people_clean() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let variance = PersonVariance::uniform(0.0);
+        for _ in 0..50 {
+            let clean = generate_clean_person(&ContactFields::default(), &mut rng);
+            let traced = vary_person(clean, &variance, &mut rng);
+            assert_eq!(traced.record, traced.clean);
+            assert!(traced.variances.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_variances_are_prefixed_by_component() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let variance = PersonVariance::uniform(1.0);
+        for _ in 0..50 {
+            let clean = generate_clean_person(&ContactFields::default(), &mut rng);
+            let traced = vary_person(clean, &variance, &mut rng);
+            assert!(!traced.variances.is_empty());
+            for label in &traced.variances {
+                assert!(
+                    label.starts_with("name.")
+                        || label.starts_with("address.")
+                        || label.starts_with("email.")
+                        || label.starts_with("phone.")
+                        || label.starts_with("dob."),
+                    "{}",
+                    label
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_only_varied_components_change() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let variance = PersonVariance {
+            name_error_rate: 0.0,
+            email_error_rate: 0.0,
+            ..PersonVariance::uniform(1.0)
+        };
+        let fields = ContactFields {
+            email: true,
+            ..ContactFields::default()
+        };
+        for _ in 0..50 {
+            let clean = generate_clean_person(&fields, &mut rng);
+            let traced = vary_person(clean, &variance, &mut rng);
+            assert_eq!(traced.record.name, traced.clean.name);
+            assert_eq!(traced.record.email, traced.clean.email);
+        }
+    }
+
+    #[test]
+    fn test_phone_area_code_matches_address_state() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let fields = ContactFields {
+            phone: true,
+            ..ContactFields::default()
+        };
+        for _ in 0..50 {
+            let person = generate_clean_person(&fields, &mut rng);
+            let phone = person.phone.unwrap();
+            let area_code: u16 = phone[..3].parse().unwrap();
+            let area_codes = phones::area_codes_for_state(&person.address.state).unwrap();
+            assert!(
+                area_codes.contains(&area_code),
+                "{} in {}",
+                phone,
+                person.address.state
+            );
+        }
+    }
+
+    #[test]
+    fn test_email_variance_is_recorded() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let variance = PersonVariance {
+            name_error_rate: 0.0,
+            address_error_rate: 0.0,
+            ..PersonVariance::uniform(1.0)
+        };
+        let fields = ContactFields {
+            email: true,
+            ..ContactFields::default()
+        };
+        for _ in 0..20 {
+            let clean = generate_clean_person(&fields, &mut rng);
+            let traced = vary_person(clean, &variance, &mut rng);
+            assert!(!traced.variances.is_empty());
+            assert!(traced.variances.iter().all(|v| v.starts_with("email.")));
+            assert_ne!(traced.record.email, traced.clean.email);
+        }
+    }
+
+    #[test]
+    fn test_date_of_birth_variance_is_recorded() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let variance = PersonVariance {
+            name_error_rate: 0.0,
+            address_error_rate: 0.0,
+            ..PersonVariance::uniform(1.0)
+        };
+        let fields = ContactFields {
+            date_of_birth: Some(BirthDates::default()),
+            ..ContactFields::default()
+        };
+        for _ in 0..20 {
+            let clean = generate_clean_person(&fields, &mut rng);
+            let traced = vary_person(clean, &variance, &mut rng);
+            assert!(traced.variances.iter().all(|v| v.starts_with("dob.")));
+            if traced.variances.is_empty() {
+                assert_eq!(traced.record, traced.clean);
+            }
+        }
+    }
+}
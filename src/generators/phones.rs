@@ -0,0 +1,473 @@
+//! US phone number generation and variance.
+//!
+//! Numbers follow the North American Numbering Plan, with area codes taken
+//! from the state they belong to. The variance patterns reproduce the
+//! formats and damage seen in ingested contact data.
+
+use rand::Rng;
+use serde::Serialize;
+use std::fmt;
+
+use crate::generators::{NamedPattern, Traced};
+use crate::profile::VarianceProfile;
+use crate::regions::ALL_STATES;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Phone {
+    pub phone: String,
+}
+
+impl Phone {
+    pub fn new(phone: String) -> Self {
+        Self { phone }
+    }
+
+    pub fn to_record(&self) -> Vec<String> {
+        vec![self.phone.clone()]
+    }
+}
+
+/// Area codes in service for each state in [`ALL_STATES`].
+pub const STATE_AREA_CODES: &[(&str, &[u16])] = &[
+    ("AK", &[907]),
+    ("AL", &[205, 251, 256, 334, 938]),
+    ("AR", &[479, 501, 870]),
+    ("AZ", &[480, 520, 602, 623, 928]),
+    (
+        "CA",
+        &[209, 213, 310, 415, 510, 559, 619, 626, 714, 818, 916, 949],
+    ),
+    ("CO", &[303, 719, 720, 970]),
+    ("CT", &[203, 475, 860, 959]),
+    ("DC", &[202]),
+    ("DE", &[302]),
+    (
+        "FL",
+        &[305, 321, 352, 386, 407, 561, 727, 813, 850, 904, 954],
+    ),
+    ("GA", &[229, 404, 470, 478, 678, 706, 770, 912]),
+    ("HI", &[808]),
+    ("IA", &[319, 515, 563, 641, 712]),
+    ("ID", &[208, 986]),
+    ("IL", &[217, 224, 309, 312, 618, 630, 708, 773, 815, 847]),
+    ("IN", &[219, 260, 317, 574, 765, 812]),
+    ("KS", &[316, 620, 785, 913]),
+    ("KY", &[270, 502, 606, 859]),
+    ("LA", &[225, 318, 337, 504, 985]),
+    ("MA", &[339, 413, 508, 617, 774, 781, 978]),
+    ("MD", &[240, 301, 410, 443]),
+    ("ME", &[207]),
+    (
+        "MI",
+        &[231, 248, 269, 313, 517, 586, 616, 734, 810, 906, 989],
+    ),
+    ("MN", &[218, 320, 507, 612, 651, 763, 952]),
+    ("MO", &[314, 417, 573, 636, 660, 816]),
+    ("MS", &[228, 601, 662, 769]),
+    ("MT", &[406]),
+    ("NC", &[252, 336, 704, 828, 910, 919, 980]),
+    ("ND", &[701]),
+    ("NE", &[308, 402, 531]),
+    ("NH", &[603]),
+    ("NJ", &[201, 609, 732, 856, 908, 973]),
+    ("NM", &[505, 575]),
+    ("NV", &[702, 725, 775]),
+    (
+        "NY",
+        &[
+            212, 315, 347, 516, 518, 585, 607, 631, 646, 716, 718, 845, 914, 917,
+        ],
+    ),
+    ("OH", &[216, 234, 330, 419, 440, 513, 614, 740, 937]),
+    ("OK", &[405, 539, 580, 918]),
+    ("OR", &[458, 503, 541, 971]),
+    ("PA", &[215, 267, 412, 484, 570, 610, 717, 724, 814]),
+    ("RI", &[401]),
+    ("SC", &[803, 843, 864]),
+    ("SD", &[605]),
+    ("TN", &[423, 615, 629, 731, 865, 901, 931]),
+    (
+        "TX",
+        &[
+            210, 214, 254, 281, 361, 409, 432, 512, 713, 806, 817, 830, 903, 915, 936, 940, 956,
+            972,
+        ],
+    ),
+    ("UT", &[385, 435, 801]),
+    ("VA", &[276, 434, 540, 571, 703, 757, 804]),
+    ("VT", &[802]),
+    ("WA", &[206, 253, 360, 425, 509]),
+    ("WI", &[262, 414, 608, 715, 920]),
+    ("WV", &[304, 681]),
+    ("WY", &[307]),
+];
+
+/// Returns the area codes for a state (case-insensitive), or `None` if the
+/// state is not recognized.
+///
+/// # Examples
+/// ```
+/// use rust_faker::generators::phones::area_codes_for_state;
+///
+/// assert!(area_codes_for_state("il").unwrap().contains(&312));
+/// assert_eq!(area_codes_for_state("XX"), None);
+/// ```
+pub fn area_codes_for_state(state: &str) -> Option<&'static [u16]> {
+    let state = state.to_uppercase();
+    STATE_AREA_CODES
+        .iter()
+        .find(|(code, _)| *code == state)
+        .map(|(_, area_codes)| *area_codes)
+}
+
+/// Draws a NANP exchange: `NXX` where N is 2-9 and the code is not an
+/// `N11` service code.
+fn exchange<R: Rng + ?Sized>(rng: &mut R) -> u16 {
+    loop {
+        let code = rng.gen_range(200..1000);
+        if code % 100 != 11 {
+            return code;
+        }
+    }
+}
+
+/// Generates a clean NANP phone number formatted as `312-555-0100`.
+///
+/// The area code belongs to `state` when one is given and recognized;
+/// otherwise it belongs to a random state.
+pub fn generate_clean_phone<R: Rng + ?Sized>(state: Option<&str>, rng: &mut R) -> String {
+    let area_codes = state.and_then(area_codes_for_state).unwrap_or_else(|| {
+        let state = ALL_STATES[rng.gen_range(0..ALL_STATES.len())];
+        area_codes_for_state(state).expect("every state has area codes")
+    });
+    let area_code = area_codes[rng.gen_range(0..area_codes.len())];
+    let exchange = exchange(rng);
+    let line: u16 = rng.gen_range(0..10_000);
+    format!("{:03}-{:03}-{:04}", area_code, exchange, line)
+}
+
+/// Applies profile variance to a single phone number: record-level variance
+/// with probability `error_rate`, then any per-field error rates.
+pub fn vary_phone<R: Rng + ?Sized>(
+    phone: Phone,
+    error_rate: f64,
+    profile: &VarianceProfile<VariancePattern>,
+    rng: &mut R,
+) -> Traced<Phone> {
+    profile.vary(phone, error_rate, rng, apply_pattern)
+}
+
+/// A single, individually selectable phone variance pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VariancePattern {
+    /// "(312) 555-0100"
+    Parentheses,
+    /// "312.555.0100"
+    Dots,
+    /// "+1 312 555 0100"
+    International,
+    /// "555-0100"
+    MissingAreaCode,
+    /// "312-555-0100 x204"
+    Extension,
+    /// Two adjacent digits swapped: "312-555-0010"
+    TransposedDigits,
+    /// A digit replaced by a look-alike letter: "312-555-O100"
+    LetterForDigit,
+}
+
+impl NamedPattern for VariancePattern {
+    const ALL: &'static [Self] = &[
+        VariancePattern::Parentheses,
+        VariancePattern::Dots,
+        VariancePattern::International,
+        VariancePattern::MissingAreaCode,
+        VariancePattern::Extension,
+        VariancePattern::TransposedDigits,
+        VariancePattern::LetterForDigit,
+    ];
+
+    fn name(&self) -> &'static str {
+        match self {
+            VariancePattern::Parentheses => "parentheses",
+            VariancePattern::Dots => "dots",
+            VariancePattern::International => "international",
+            VariancePattern::MissingAreaCode => "missing_area_code",
+            VariancePattern::Extension => "extension",
+            VariancePattern::TransposedDigits => "transposed_digits",
+            VariancePattern::LetterForDigit => "letter_for_digit",
+        }
+    }
+
+    fn fields(&self) -> &'static [&'static str] {
+        &["phone"]
+    }
+}
+
+impl fmt::Display for VariancePattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Splits a trailing extension (` x204`, ` ext. 204`) from a number.
+fn split_extension(phone: &str) -> (&str, &str) {
+    match phone.find(" x").or_else(|| phone.find(" ext")) {
+        Some(i) => phone.split_at(i),
+        None => (phone, ""),
+    }
+}
+
+/// The area code, exchange and line of a number, if it still has all ten
+/// digits (an optional leading country code 1 is ignored).
+fn number_parts(number: &str) -> Option<(String, String, String)> {
+    let digits: String = number.chars().filter(char::is_ascii_digit).collect();
+    let digits = match digits.len() {
+        10 => digits,
+        11 if digits.starts_with('1') => digits[1..].to_string(),
+        _ => return None,
+    };
+    if number.chars().any(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    Some((
+        digits[..3].to_string(),
+        digits[3..6].to_string(),
+        digits[6..].to_string(),
+    ))
+}
+
+/// Reformats the number part of `phone`, keeping any extension.
+fn reformat(phone: &mut String, format: impl Fn(&str, &str, &str) -> String) {
+    let (number, extension) = split_extension(phone);
+    if let Some((area_code, exchange, line)) = number_parts(number) {
+        *phone = format!("{}{}", format(&area_code, &exchange, &line), extension);
+    }
+}
+
+/// Letters commonly typed or OCR'd in place of digits.
+const LOOKALIKE_LETTERS: &[(char, char)] =
+    &[('0', 'O'), ('1', 'l'), ('2', 'Z'), ('5', 'S'), ('8', 'B')];
+
+/// Applies a single variance pattern to a phone number in place.
+///
+/// Returns the ground-truth label of the change, or `None` if the pattern
+/// had nothing to act on (e.g. reformatting a number that has already lost
+/// digits).
+pub fn apply_pattern<R: Rng + ?Sized>(
+    phone: &mut Phone,
+    pattern: VariancePattern,
+    rng: &mut R,
+) -> Option<String> {
+    let before = phone.clone();
+    let number = &mut phone.phone;
+
+    match pattern {
+        VariancePattern::Parentheses => {
+            reformat(number, |area, exchange, line| {
+                format!("({}) {}-{}", area, exchange, line)
+            });
+        }
+        VariancePattern::Dots => {
+            reformat(number, |area, exchange, line| {
+                format!("{}.{}.{}", area, exchange, line)
+            });
+        }
+        VariancePattern::International => {
+            reformat(number, |area, exchange, line| {
+                format!("+1 {} {} {}", area, exchange, line)
+            });
+        }
+        VariancePattern::MissingAreaCode => {
+            reformat(number, |_, exchange, line| format!("{}-{}", exchange, line));
+        }
+        VariancePattern::Extension => {
+            if split_extension(number).1.is_empty() {
+                let styles = [" x", " ext. ", " ext "];
+                let style = styles[rng.gen_range(0..styles.len())];
+                number.push_str(&format!("{}{}", style, rng.gen_range(1..1000)));
+            }
+        }
+        VariancePattern::TransposedDigits => {
+            let mut chars: Vec<char> = number.chars().collect();
+            let pairs: Vec<usize> = (0..chars.len().saturating_sub(1))
+                .filter(|&i| {
+                    chars[i].is_ascii_digit()
+                        && chars[i + 1].is_ascii_digit()
+                        && chars[i] != chars[i + 1]
+                })
+                .collect();
+            if !pairs.is_empty() {
+                let i = pairs[rng.gen_range(0..pairs.len())];
+                chars.swap(i, i + 1);
+                *number = chars.into_iter().collect();
+            }
+        }
+        VariancePattern::LetterForDigit => {
+            let candidates: Vec<(usize, char)> = number
+                .char_indices()
+                .filter_map(|(i, c)| {
+                    LOOKALIKE_LETTERS
+                        .iter()
+                        .find(|(digit, _)| *digit == c)
+                        .map(|(_, letter)| (i, *letter))
+                })
+                .collect();
+            if !candidates.is_empty() {
+                let (i, letter) = candidates[rng.gen_range(0..candidates.len())];
+                number.replace_range(i..i + 1, &letter.to_string());
+            }
+        }
+    }
+
+    if *phone == before {
+        None
+    } else {
+        Some(pattern.name().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn apply(number: &str, pattern: VariancePattern) -> (String, Option<String>) {
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut phone = Phone::new(number.to_string());
+        let label = apply_pattern(&mut phone, pattern, &mut rng);
+        (phone.phone, label)
+    }
+
+    #[test]
+    fn test_clean_phone_is_valid_nanp() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..200 {
+            let phone = generate_clean_phone(None, &mut rng);
+            let parts: Vec<&str> = phone.split('-').collect();
+            assert_eq!(parts.len(), 3, "{}", phone);
+            assert_eq!(parts[2].len(), 4);
+            for code in &parts[..2] {
+                assert_eq!(code.len(), 3);
+                assert!(!code.starts_with(['0', '1']), "{}", phone);
+                assert!(!code.ends_with("11"), "{}", phone);
+            }
+        }
+    }
+
+    #[test]
+    fn test_clean_phone_uses_state_area_code() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..50 {
+            let phone = generate_clean_phone(Some("il"), &mut rng);
+            let area_code: u16 = phone[..3].parse().unwrap();
+            assert!(area_codes_for_state("IL").unwrap().contains(&area_code));
+        }
+    }
+
+    #[test]
+    fn test_every_state_has_area_codes() {
+        for state in ALL_STATES {
+            let area_codes = area_codes_for_state(state).unwrap();
+            assert!(!area_codes.is_empty(), "{}", state);
+            assert!(area_codes.iter().all(|code| (200..1000).contains(code)));
+        }
+        assert_eq!(STATE_AREA_CODES.len(), ALL_STATES.len());
+    }
+
+    #[test]
+    fn test_format_patterns() {
+        assert_eq!(
+            apply("312-555-0100", VariancePattern::Parentheses).0,
+            "(312) 555-0100"
+        );
+        assert_eq!(
+            apply("312-555-0100", VariancePattern::Dots).0,
+            "312.555.0100"
+        );
+        assert_eq!(
+            apply("312-555-0100", VariancePattern::International).0,
+            "+1 312 555 0100"
+        );
+        assert_eq!(
+            apply("312-555-0100", VariancePattern::MissingAreaCode).0,
+            "555-0100"
+        );
+        assert_eq!(
+            apply("+1 312 555 0100", VariancePattern::Dots).0,
+            "312.555.0100"
+        );
+    }
+
+    #[test]
+    fn test_format_keeps_extension() {
+        assert_eq!(
+            apply("312-555-0100 x204", VariancePattern::Parentheses).0,
+            "(312) 555-0100 x204"
+        );
+    }
+
+    #[test]
+    fn test_format_needs_ten_digits() {
+        assert_eq!(apply("555-0100", VariancePattern::Dots).1, None);
+        assert_eq!(apply("312-555-O100", VariancePattern::Dots).1, None);
+    }
+
+    #[test]
+    fn test_extension() {
+        let (varied, label) = apply("312-555-0100", VariancePattern::Extension);
+        assert!(varied.starts_with("312-555-0100 "));
+        assert!(varied.chars().last().unwrap().is_ascii_digit());
+        assert_eq!(label.as_deref(), Some("extension"));
+        assert_eq!(apply(&varied, VariancePattern::Extension).1, None);
+    }
+
+    #[test]
+    fn test_transposed_digits() {
+        let (varied, label) = apply("312-555-0100", VariancePattern::TransposedDigits);
+        assert_ne!(varied, "312-555-0100");
+        let mut sorted: Vec<char> = varied.chars().collect();
+        sorted.sort();
+        let mut expected: Vec<char> = "312-555-0100".chars().collect();
+        expected.sort();
+        assert_eq!(sorted, expected);
+        assert_eq!(label.as_deref(), Some("transposed_digits"));
+    }
+
+    #[test]
+    fn test_letter_for_digit() {
+        let (varied, label) = apply("312-555-0100", VariancePattern::LetterForDigit);
+        assert_eq!(varied.len(), "312-555-0100".len());
+        assert!(
+            varied.chars().any(|c| c.is_ascii_alphabetic()),
+            "{}",
+            varied
+        );
+        assert_eq!(label.as_deref(), Some("letter_for_digit"));
+        assert_eq!(
+            apply("347-967-3469", VariancePattern::LetterForDigit).1,
+            None
+        );
+    }
+
+    #[test]
+    fn test_zero_error_rate_keeps_phones_clean() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let profile = VarianceProfile::uniform(VariancePattern::ALL);
+        for _ in 0..50 {
+            let phone = Phone::new(generate_clean_phone(Some("TX"), &mut rng));
+            let traced = vary_phone(phone, 0.0, &profile, &mut rng);
+            assert_eq!(traced.record, traced.clean);
+            let area_code: u16 = traced.record.phone[..3].parse().unwrap();
+            assert!(area_codes_for_state("TX").unwrap().contains(&area_code));
+        }
+    }
+
+    #[test]
+    fn test_variance_pattern_names_round_trip() {
+        for pattern in VariancePattern::ALL {
+            assert_eq!(VariancePattern::from_name(pattern.name()), Some(*pattern));
+        }
+    }
+}
@@ -0,0 +1,808 @@
+//! Street-line vocabulary from USPS Publication 28 — street suffixes,
+//! directionals and ordinal street names — and the transforms the
+//! address variance patterns apply to `address1` with it.
+//!
+//! A street line is read as words: an optional house number (with an
+//! optional fraction, "123 1/2"), an optional pre-directional, the street
+//! name, the suffix, an optional post-directional and anything after it
+//! (a unit moved into the line). The suffix is the last suffix word after
+//! the street name, and a directional only counts as one when a street
+//! name separates it from the suffix, so "123 West St" keeps its "West".
+//! Every transform returns `None` when the line has nothing for it to act
+//! on, and writes new words in the case of the line ("123 MAIN ST" ->
+//! "123 MAIN STREET"). Only the words a transform changes are rewritten;
+//! the whitespace between them (doubled, non-breaking) is kept as it was.
+
+use rand::Rng;
+use std::ops::Range;
+
+use crate::generators::match_case;
+
+/// A street suffix's primary name, Postal Service standard abbreviation
+/// and other commonly used abbreviations.
+type Suffix = (&'static str, &'static str, &'static [&'static str]);
+
+/// USPS Publication 28 Appendix C1 street suffixes.
+const STREET_SUFFIXES: &[Suffix] = &[
+    ("Alley", "Aly", &["Allee", "Ally"]),
+    ("Annex", "Anx", &["Anex", "Annx"]),
+    ("Arcade", "Arc", &[]),
+    ("Avenue", "Ave", &["Av", "Aven", "Avenu", "Avn", "Avnue"]),
+    ("Bayou", "Byu", &["Bayoo"]),
+    ("Beach", "Bch", &[]),
+    ("Bend", "Bnd", &[]),
+    ("Bluff", "Blf", &["Bluf"]),
+    ("Bluffs", "Blfs", &[]),
+    ("Bottom", "Btm", &["Bot", "Bottm"]),
+    ("Boulevard", "Blvd", &["Boul", "Boulv"]),
+    ("Branch", "Br", &["Brnch"]),
+    ("Bridge", "Brg", &["Brdge"]),
+    ("Brook", "Brk", &[]),
+    ("Brooks", "Brks", &[]),
+    ("Burg", "Bg", &[]),
+    ("Burgs", "Bgs", &[]),
+    ("Bypass", "Byp", &["Bypa", "Bypas", "Byps"]),
+    ("Camp", "Cp", &["Cmp"]),
+    ("Canyon", "Cyn", &["Canyn", "Cnyn"]),
+    ("Cape", "Cpe", &[]),
+    ("Causeway", "Cswy", &["Causwa"]),
+    (
+        "Center",
+        "Ctr",
+        &["Cen", "Cent", "Centr", "Centre", "Cnter", "Cntr"],
+    ),
+    ("Centers", "Ctrs", &[]),
+    ("Circle", "Cir", &["Circ", "Circl", "Crcl", "Crcle"]),
+    ("Circles", "Cirs", &[]),
+    ("Cliff", "Clf", &[]),
+    ("Cliffs", "Clfs", &[]),
+    ("Club", "Clb", &[]),
+    ("Common", "Cmn", &[]),
+    ("Commons", "Cmns", &[]),
+    ("Corner", "Cor", &[]),
+    ("Corners", "Cors", &[]),
+    ("Course", "Crse", &[]),
+    ("Court", "Ct", &[]),
+    ("Courts", "Cts", &[]),
+    ("Cove", "Cv", &[]),
+    ("Coves", "Cvs", &[]),
+    ("Creek", "Crk", &[]),
+    ("Crescent", "Cres", &["Crsent", "Crsnt"]),
+    ("Crest", "Crst", &[]),
+    ("Crossing", "Xing", &["Crssng"]),
+    ("Crossroad", "Xrd", &[]),
+    ("Crossroads", "Xrds", &[]),
+    ("Curve", "Curv", &[]),
+    ("Dale", "Dl", &[]),
+    ("Dam", "Dm", &[]),
+    ("Divide", "Dv", &["Div", "Dvd"]),
+    ("Drive", "Dr", &["Driv", "Drv"]),
+    ("Drives", "Drs", &[]),
+    ("Estate", "Est", &[]),
+    ("Estates", "Ests", &[]),
+    ("Expressway", "Expy", &["Exp", "Expr", "Express", "Expw"]),
+    ("Extension", "Ext", &["Extn", "Extnsn"]),
+    ("Extensions", "Exts", &[]),
+    ("Fall", "Fall", &[]),
+    ("Falls", "Fls", &[]),
+    ("Ferry", "Fry", &["Frry"]),
+    ("Field", "Fld", &[]),
+    ("Fields", "Flds", &[]),
+    ("Flat", "Flt", &[]),
+    ("Flats", "Flts", &[]),
+    ("Ford", "Frd", &[]),
+    ("Fords", "Frds", &[]),
+    ("Forest", "Frst", &["Forests"]),
+    ("Forge", "Frg", &["Forg"]),
+    ("Forges", "Frgs", &[]),
+    ("Fork", "Frk", &[]),
+    ("Forks", "Frks", &[]),
+    ("Fort", "Ft", &["Frt"]),
+    ("Freeway", "Fwy", &["Freewy", "Frway", "Frwy"]),
+    ("Garden", "Gdn", &["Gardn", "Grden", "Grdn"]),
+    ("Gardens", "Gdns", &["Grdns"]),
+    ("Gateway", "Gtwy", &["Gatewy", "Gatway", "Gtway"]),
+    ("Glen", "Gln", &[]),
+    ("Glens", "Glns", &[]),
+    ("Green", "Grn", &[]),
+    ("Greens", "Grns", &[]),
+    ("Grove", "Grv", &["Grov"]),
+    ("Groves", "Grvs", &[]),
+    ("Harbor", "Hbr", &["Harb", "Harbr", "Hrbor"]),
+    ("Harbors", "Hbrs", &[]),
+    ("Haven", "Hvn", &[]),
+    ("Heights", "Hts", &["Ht"]),
+    ("Highway", "Hwy", &["Highwy", "Hiway", "Hiwy", "Hway"]),
+    ("Hill", "Hl", &[]),
+    ("Hills", "Hls", &[]),
+    ("Hollow", "Holw", &["Hllw", "Hollows", "Holws"]),
+    ("Inlet", "Inlt", &[]),
+    ("Island", "Is", &["Islnd"]),
+    ("Islands", "Iss", &["Islnds"]),
+    ("Isle", "Isle", &["Isles"]),
+    ("Junction", "Jct", &["Jction", "Jctn", "Junctn", "Juncton"]),
+    ("Junctions", "Jcts", &["Jctns"]),
+    ("Key", "Ky", &[]),
+    ("Keys", "Kys", &[]),
+    ("Knoll", "Knl", &["Knol"]),
+    ("Knolls", "Knls", &[]),
+    ("Lake", "Lk", &[]),
+    ("Lakes", "Lks", &[]),
+    ("Land", "Land", &[]),
+    ("Landing", "Lndg", &["Lndng"]),
+    ("Lane", "Ln", &[]),
+    ("Light", "Lgt", &[]),
+    ("Lights", "Lgts", &[]),
+    ("Loaf", "Lf", &[]),
+    ("Lock", "Lck", &[]),
+    ("Locks", "Lcks", &[]),
+    ("Lodge", "Ldg", &["Ldge", "Lodg"]),
+    ("Loop", "Loop", &["Loops"]),
+    ("Mall", "Mall", &[]),
+    ("Manor", "Mnr", &[]),
+    ("Manors", "Mnrs", &[]),
+    ("Meadow", "Mdw", &[]),
+    ("Meadows", "Mdws", &["Medows"]),
+    ("Mews", "Mews", &[]),
+    ("Mill", "Ml", &[]),
+    ("Mills", "Mls", &[]),
+    ("Mission", "Msn", &["Missn", "Mssn"]),
+    ("Motorway", "Mtwy", &[]),
+    ("Mount", "Mt", &["Mnt"]),
+    ("Mountain", "Mtn", &["Mntain", "Mntn", "Mountin", "Mtin"]),
+    ("Mountains", "Mtns", &["Mntns"]),
+    ("Neck", "Nck", &[]),
+    ("Orchard", "Orch", &["Orchrd"]),
+    ("Oval", "Oval", &["Ovl"]),
+    ("Overpass", "Opas", &[]),
+    ("Park", "Park", &["Prk"]),
+    ("Parkway", "Pkwy", &["Parkwy", "Pkway", "Pky"]),
+    ("Pass", "Pass", &[]),
+    ("Passage", "Psge", &[]),
+    ("Path", "Path", &["Paths"]),
+    ("Pike", "Pike", &["Pikes"]),
+    ("Pine", "Pne", &[]),
+    ("Pines", "Pnes", &[]),
+    ("Place", "Pl", &[]),
+    ("Plain", "Pln", &[]),
+    ("Plains", "Plns", &[]),
+    ("Plaza", "Plz", &["Plza"]),
+    ("Point", "Pt", &[]),
+    ("Points", "Pts", &[]),
+    ("Port", "Prt", &[]),
+    ("Ports", "Prts", &[]),
+    ("Prairie", "Pr", &["Prr"]),
+    ("Radial", "Radl", &["Rad", "Radiel"]),
+    ("Ramp", "Ramp", &[]),
+    ("Ranch", "Rnch", &["Ranches", "Rnchs"]),
+    ("Rapid", "Rpd", &[]),
+    ("Rapids", "Rpds", &[]),
+    ("Rest", "Rst", &[]),
+    ("Ridge", "Rdg", &["Rdge"]),
+    ("Ridges", "Rdgs", &[]),
+    ("River", "Riv", &["Rvr", "Rivr"]),
+    ("Road", "Rd", &[]),
+    ("Roads", "Rds", &[]),
+    ("Route", "Rte", &[]),
+    ("Row", "Row", &[]),
+    ("Rue", "Rue", &[]),
+    ("Run", "Run", &[]),
+    ("Shoal", "Shl", &[]),
+    ("Shoals", "Shls", &[]),
+    ("Shore", "Shr", &["Shoar"]),
+    ("Shores", "Shrs", &["Shoars"]),
+    ("Skyway", "Skwy", &[]),
+    ("Spring", "Spg", &["Spng", "Sprng"]),
+    ("Springs", "Spgs", &["Spngs", "Sprngs"]),
+    ("Spur", "Spur", &["Spurs"]),
+    ("Square", "Sq", &["Sqr", "Sqre", "Squ"]),
+    ("Squares", "Sqs", &["Sqrs"]),
+    ("Station", "Sta", &["Statn", "Stn"]),
+    (
+        "Stravenue",
+        "Stra",
+        &["Strav", "Straven", "Stravn", "Strvn", "Strvnue"],
+    ),
+    ("Stream", "Strm", &["Streme"]),
+    ("Street", "St", &["Strt", "Str"]),
+    ("Streets", "Sts", &[]),
+    ("Summit", "Smt", &["Sumit", "Sumitt"]),
+    ("Terrace", "Ter", &["Terr"]),
+    ("Throughway", "Trwy", &[]),
+    ("Trace", "Trce", &["Traces"]),
+    ("Track", "Trak", &["Tracks", "Trk", "Trks"]),
+    ("Trafficway", "Trfy", &[]),
+    ("Trail", "Trl", &["Trails", "Trls"]),
+    ("Trailer", "Trlr", &["Trlrs"]),
+    ("Tunnel", "Tunl", &["Tunel", "Tunls", "Tunnels", "Tunnl"]),
+    ("Turnpike", "Tpke", &["Trnpk", "Turnpk"]),
+    ("Underpass", "Upas", &[]),
+    ("Union", "Un", &[]),
+    ("Unions", "Uns", &[]),
+    ("Valley", "Vly", &["Vally", "Vlly"]),
+    ("Valleys", "Vlys", &[]),
+    ("Viaduct", "Via", &["Vdct", "Viadct"]),
+    ("View", "Vw", &[]),
+    ("Views", "Vws", &[]),
+    ("Village", "Vlg", &["Vill", "Villag", "Villg", "Villiage"]),
+    ("Villages", "Vlgs", &[]),
+    ("Ville", "Vl", &[]),
+    ("Vista", "Vis", &["Vist", "Vst", "Vsta"]),
+    ("Walk", "Walk", &["Walks"]),
+    ("Wall", "Wall", &[]),
+    ("Way", "Way", &["Wy"]),
+    ("Ways", "Ways", &[]),
+    ("Well", "Wl", &[]),
+    ("Wells", "Wls", &[]),
+];
+
+/// Directionals and their standard abbreviations.
+const DIRECTIONALS: [(&str, &str); 8] = [
+    ("North", "N"),
+    ("South", "S"),
+    ("East", "E"),
+    ("West", "W"),
+    ("Northeast", "NE"),
+    ("Northwest", "NW"),
+    ("Southeast", "SE"),
+    ("Southwest", "SW"),
+];
+
+const ORDINALS: [&str; 19] = [
+    "First",
+    "Second",
+    "Third",
+    "Fourth",
+    "Fifth",
+    "Sixth",
+    "Seventh",
+    "Eighth",
+    "Ninth",
+    "Tenth",
+    "Eleventh",
+    "Twelfth",
+    "Thirteenth",
+    "Fourteenth",
+    "Fifteenth",
+    "Sixteenth",
+    "Seventeenth",
+    "Eighteenth",
+    "Nineteenth",
+];
+
+const TENS: [(&str, &str); 8] = [
+    ("Twenty", "Twentieth"),
+    ("Thirty", "Thirtieth"),
+    ("Forty", "Fortieth"),
+    ("Fifty", "Fiftieth"),
+    ("Sixty", "Sixtieth"),
+    ("Seventy", "Seventieth"),
+    ("Eighty", "Eightieth"),
+    ("Ninety", "Ninetieth"),
+];
+
+/// The highest ordinal street name spelled out ("Ninety-Ninth")
+const MAX_SPELLED_ORDINAL: u32 = 99;
+
+/// `word` without a trailing abbreviation period ("St." -> "St").
+fn bare(word: &str) -> &str {
+    word.strip_suffix('.').unwrap_or(word)
+}
+
+/// The Publication 28 entry `word` is the primary name, standard
+/// abbreviation or a common abbreviation of.
+fn find_suffix(word: &str) -> Option<&'static Suffix> {
+    let word = bare(word);
+    STREET_SUFFIXES.iter().find(|(name, abbr, others)| {
+        name.eq_ignore_ascii_case(word)
+            || abbr.eq_ignore_ascii_case(word)
+            || others.iter().any(|o| o.eq_ignore_ascii_case(word))
+    })
+}
+
+fn find_directional(word: &str) -> Option<&'static (&'static str, &'static str)> {
+    let word = bare(word);
+    DIRECTIONALS
+        .iter()
+        .find(|(name, abbr)| name.eq_ignore_ascii_case(word) || abbr.eq_ignore_ascii_case(word))
+}
+
+/// A directional's full name, for generating clean addresses.
+pub fn random_directional<R: Rng + ?Sized>(rng: &mut R) -> &'static str {
+    DIRECTIONALS[rng.gen_range(0..DIRECTIONALS.len())].0
+}
+
+/// The numeric ordinal of `n`: "1st", "2nd", "11th", "23rd".
+pub fn numeric_ordinal(n: u32) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+/// The spelled-out ordinal of `n` (1 to 99): "Fifth", "Twentieth",
+/// "Thirty-Third".
+fn spelled_ordinal(n: u32) -> String {
+    let n = n as usize;
+    match n {
+        1..=19 => ORDINALS[n - 1].to_string(),
+        _ if n.is_multiple_of(10) => TENS[n / 10 - 2].1.to_string(),
+        _ => format!("{}-{}", TENS[n / 10 - 2].0, ORDINALS[n % 10 - 1]),
+    }
+}
+
+/// The number an ordinal street name stands for, written either way.
+fn parse_ordinal(word: &str) -> Option<u32> {
+    if word.starts_with(|c: char| c.is_ascii_digit()) {
+        let digits = word.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+        let n: u32 = digits.parse().ok()?;
+        return (n > 0 && numeric_ordinal(n).eq_ignore_ascii_case(word)).then_some(n);
+    }
+    (1..=MAX_SPELLED_ORDINAL).find(|&n| spelled_ordinal(n).eq_ignore_ascii_case(word))
+}
+
+fn is_house_number(word: &str) -> bool {
+    word.starts_with(|c: char| c.is_ascii_digit()) && parse_ordinal(word).is_none()
+}
+
+fn is_fraction(word: &str) -> bool {
+    word.split_once('/').is_some_and(|(num, den)| {
+        !num.is_empty()
+            && !den.is_empty()
+            && num.chars().all(|c| c.is_ascii_digit())
+            && den.chars().all(|c| c.is_ascii_digit())
+    })
+}
+
+/// The word positions of a street line.
+struct StreetLine<'a> {
+    line: &'a str,
+    words: Vec<&'a str>,
+    /// Byte range of each word in `line`
+    spans: Vec<Range<usize>>,
+    /// Index of the first word after the house number (and its fraction)
+    start: usize,
+    suffix: Option<usize>,
+    pre_directional: Option<usize>,
+    post_directional: Option<usize>,
+}
+
+impl<'a> StreetLine<'a> {
+    fn parse(line: &'a str) -> Self {
+        let mut spans = Vec::new();
+        let mut word_start = None;
+        for (i, c) in line.char_indices() {
+            match (c.is_whitespace(), word_start) {
+                (true, Some(start)) => {
+                    spans.push(start..i);
+                    word_start = None;
+                }
+                (false, None) => word_start = Some(i),
+                _ => {}
+            }
+        }
+        if let Some(start) = word_start {
+            spans.push(start..line.len());
+        }
+        let words: Vec<&str> = spans.iter().map(|span| &line[span.clone()]).collect();
+
+        let mut start = 0;
+        if words.first().is_some_and(|w| is_house_number(w)) {
+            start = 1;
+            if words.get(1).is_some_and(|w| is_fraction(w)) {
+                start = 2;
+            }
+        }
+        let suffix = (start + 1..words.len())
+            .rev()
+            .find(|&i| find_suffix(words[i]).is_some());
+        let pre_directional = suffix.and_then(|s| {
+            (s > start + 1 && find_directional(words[start]).is_some()).then_some(start)
+        });
+        let post_directional = suffix.and_then(|s| {
+            words
+                .get(s + 1)
+                .is_some_and(|w| find_directional(w).is_some())
+                .then_some(s + 1)
+        });
+        Self {
+            line,
+            words,
+            spans,
+            start,
+            suffix,
+            pre_directional,
+            post_directional,
+        }
+    }
+
+    fn has_house_number(&self) -> bool {
+        self.start > 0
+    }
+
+    /// The house number, if it is a plain number without a fraction.
+    fn plain_house_number(&self) -> Option<u32> {
+        if self.start == 1 && self.words[0].chars().all(|c| c.is_ascii_digit()) {
+            self.words[0].parse().ok()
+        } else {
+            None
+        }
+    }
+
+    /// The line with the word at each index replaced, keeping the
+    /// whitespace between words as it was, or `None` if no word changed.
+    fn splice(&self, replacements: impl IntoIterator<Item = (usize, String)>) -> Option<String> {
+        let mut replacements: Vec<(usize, String)> = replacements
+            .into_iter()
+            .filter(|(index, word)| word != self.words[*index])
+            .collect();
+        if replacements.is_empty() {
+            return None;
+        }
+        replacements.sort_by_key(|(index, _)| *index);
+        let mut spliced = String::with_capacity(self.line.len());
+        let mut end = 0;
+        for (index, word) in replacements {
+            let span = &self.spans[index];
+            spliced.push_str(&self.line[end..span.start]);
+            spliced.push_str(&word);
+            end = span.end;
+        }
+        spliced.push_str(&self.line[end..]);
+        Some(spliced)
+    }
+
+    fn directionals(&self) -> impl Iterator<Item = usize> {
+        self.pre_directional
+            .into_iter()
+            .chain(self.post_directional)
+    }
+}
+
+/// Replaces each word at `indices` with `f(word)`, cased like the line,
+/// wherever `f` has a replacement that differs from it.
+fn replace_words(
+    line: &str,
+    street: &StreetLine,
+    indices: impl IntoIterator<Item = usize>,
+    f: impl Fn(&str) -> Option<&'static str>,
+) -> Option<String> {
+    street.splice(indices.into_iter().filter_map(|index| {
+        let replacement = f(street.words[index])?;
+        (replacement != bare(street.words[index])).then(|| (index, match_case(line, replacement)))
+    }))
+}
+
+/// "123 Main Street" -> "123 Main St"
+pub fn abbreviate_suffix(line: &str) -> Option<String> {
+    let street = StreetLine::parse(line);
+    replace_words(line, &street, street.suffix, |w| {
+        find_suffix(w).map(|(_, abbr, _)| *abbr)
+    })
+}
+
+/// "123 Main St" -> "123 Main Street"
+pub fn expand_suffix(line: &str) -> Option<String> {
+    let street = StreetLine::parse(line);
+    replace_words(line, &street, street.suffix, |w| {
+        find_suffix(w).map(|(name, _, _)| *name)
+    })
+}
+
+/// A commonly used, non-standard abbreviation: "123 Main Street" ->
+/// "123 Main Str", "9 Oak Avenue" -> "9 Oak Av".
+pub fn nonstandard_suffix<R: Rng + ?Sized>(line: &str, rng: &mut R) -> Option<String> {
+    let street = StreetLine::parse(line);
+    let (_, _, others) = find_suffix(street.words[street.suffix?])?;
+    if others.is_empty() {
+        return None;
+    }
+    let other = others[rng.gen_range(0..others.len())];
+    replace_words(line, &street, street.suffix, |_| Some(other))
+}
+
+/// "123 North Main St" -> "123 N Main St"
+pub fn abbreviate_directionals(line: &str) -> Option<String> {
+    let street = StreetLine::parse(line);
+    replace_words(line, &street, street.directionals(), |w| {
+        find_directional(w).map(|(_, abbr)| *abbr)
+    })
+}
+
+/// "123 NW Main St" -> "123 Northwest Main St"
+pub fn expand_directionals(line: &str) -> Option<String> {
+    let street = StreetLine::parse(line);
+    replace_words(line, &street, street.directionals(), |w| {
+        find_directional(w).map(|(name, _)| *name)
+    })
+}
+
+/// Moves a pre-directional after the suffix or a post-directional before
+/// the street name: "123 N Main St" <-> "123 Main St N". The directional
+/// takes the whitespace that followed (or preceded) it along with it.
+pub fn move_directional(line: &str) -> Option<String> {
+    let street = StreetLine::parse(line);
+    let spans = &street.spans;
+    match (
+        street.pre_directional,
+        street.post_directional,
+        street.suffix,
+    ) {
+        (Some(pre), None, Some(suffix)) => {
+            let (directional, gap) = (&spans[pre], spans[pre].end..spans[pre + 1].start);
+            Some(format!(
+                "{}{}{}{}{}",
+                &line[..directional.start],
+                &line[gap.end..spans[suffix].end],
+                &line[gap],
+                &line[directional.clone()],
+                &line[spans[suffix].end..],
+            ))
+        }
+        (None, Some(post), _) => {
+            let (directional, gap) = (&spans[post], spans[post - 1].end..spans[post].start);
+            let name = spans[street.start].start;
+            Some(format!(
+                "{}{}{}{}{}",
+                &line[..name],
+                &line[directional.clone()],
+                &line[gap.clone()],
+                &line[name..gap.start],
+                &line[directional.end..],
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// Rewrites an ordinal street name the other way: "5th Ave" <-> "Fifth Ave".
+pub fn respell_ordinal(line: &str) -> Option<String> {
+    let street = StreetLine::parse(line);
+    let end = street.suffix.unwrap_or(street.words.len());
+    let (index, n) =
+        (street.start..end).find_map(|i| parse_ordinal(street.words[i]).map(|n| (i, n)))?;
+    let word = street.words[index];
+    let respelled = if word.starts_with(|c: char| c.is_ascii_digit()) {
+        if n > MAX_SPELLED_ORDINAL {
+            return None;
+        }
+        spelled_ordinal(n)
+    } else {
+        numeric_ordinal(n)
+    };
+    street.splice([(index, match_case(line, &respelled))])
+}
+
+/// "123 Main St" -> "123 1/2 Main St", separating the fraction the way
+/// the house number is separated from the street name.
+pub fn add_house_number_fraction(line: &str) -> Option<String> {
+    let street = StreetLine::parse(line);
+    let number = street.plain_house_number()?;
+    let gap = street
+        .spans
+        .get(1)
+        .map_or(" ", |next| &line[street.spans[0].end..next.start]);
+    street.splice([(0, format!("{}{}1/2", number, gap))])
+}
+
+/// "100 Main St" -> "100-102 Main St", or `None` if the range would run
+/// past the largest house number.
+pub fn house_number_range<R: Rng + ?Sized>(line: &str, rng: &mut R) -> Option<String> {
+    let street = StreetLine::parse(line);
+    let first = street.plain_house_number()?;
+    let last = first.checked_add(2 * rng.gen_range(1..=3))?;
+    street.splice([(0, format!("{}-{}", first, last))])
+}
+
+/// "123 1/2 Main St" -> "Main St"
+pub fn remove_house_number(line: &str) -> Option<String> {
+    let street = StreetLine::parse(line);
+    if !street.has_house_number() || street.start == street.words.len() {
+        return None;
+    }
+    Some(format!(
+        "{}{}",
+        &line[..street.spans[0].start],
+        &line[street.spans[street.start].start..]
+    ))
+}
+
+/// Appends a period to every abbreviated suffix and directional in the
+/// line ("123 N Main St" -> "123 N. Main St.").
+pub fn add_periods(line: &str) -> String {
+    let street = StreetLine::parse(line);
+    let abbreviated = street.suffix.filter(|&i| {
+        STREET_SUFFIXES
+            .iter()
+            .any(|(name, abbr, _)| *abbr == street.words[i] && name != abbr)
+    });
+    let directionals = street.directionals().filter(|&i| {
+        DIRECTIONALS
+            .iter()
+            .any(|(_, abbr)| *abbr == street.words[i])
+    });
+    street
+        .splice(
+            abbreviated
+                .into_iter()
+                .chain(directionals)
+                .map(|index| (index, format!("{}.", street.words[index]))),
+        )
+        .unwrap_or_else(|| line.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_abbreviate_known_suffixes() {
+        for (suffix, abbr) in [
+            ("Street", "St"),
+            ("Avenue", "Ave"),
+            ("Road", "Rd"),
+            ("Boulevard", "Blvd"),
+            ("Crossing", "Xing"),
+            ("Avnue", "Ave"),
+        ] {
+            assert_eq!(
+                abbreviate_suffix(&format!("123 Main {}", suffix)),
+                Some(format!("123 Main {}", abbr))
+            );
+        }
+    }
+
+    #[test]
+    fn test_abbreviate_unknown_suffix() {
+        // Unknown suffixes are left alone
+        assert_eq!(find_suffix("Unknown"), None);
+        assert_eq!(abbreviate_suffix("123 Main Unknown"), None);
+    }
+
+    #[test]
+    fn test_suffix_table_is_unambiguous() {
+        let mut seen = std::collections::HashSet::new();
+        for (name, abbr, others) in STREET_SUFFIXES {
+            assert!(seen.insert(name.to_lowercase()), "{}", name);
+            if abbr != name {
+                assert!(seen.insert(abbr.to_lowercase()), "{}", abbr);
+            }
+            for other in others.iter() {
+                assert!(seen.insert(other.to_lowercase()), "{}", other);
+            }
+        }
+    }
+
+    #[test]
+    fn test_add_periods() {
+        assert_eq!(add_periods("123 Main St"), "123 Main St.");
+        assert_eq!(add_periods("9 Oak Ave Apt 4"), "9 Oak Ave. Apt 4");
+        assert_eq!(add_periods("9 N Oak Ave SW"), "9 N. Oak Ave. SW.");
+        // Full suffixes and words merely starting with an abbreviation are untouched
+        assert_eq!(add_periods("123 Main Street"), "123 Main Street");
+        assert_eq!(add_periods("5 Stone Way"), "5 Stone Way");
+    }
+
+    #[test]
+    fn test_suffix_abbreviation_and_expansion() {
+        assert_eq!(abbreviate_suffix("123 Main Street").unwrap(), "123 Main St");
+        assert_eq!(abbreviate_suffix("123 MAIN STREET").unwrap(), "123 MAIN ST");
+        assert_eq!(abbreviate_suffix("123 Main St"), None);
+        assert_eq!(expand_suffix("123 Main St.").unwrap(), "123 Main Street");
+        assert_eq!(
+            expand_suffix("9 Oak Av Apt 2").unwrap(),
+            "9 Oak Avenue Apt 2"
+        );
+        assert_eq!(expand_suffix("9 Park Pky N").unwrap(), "9 Park Parkway N");
+        // A lone word after the house number is the street name
+        assert_eq!(expand_suffix("123 Park"), None);
+    }
+
+    #[test]
+    fn test_nonstandard_suffix() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let varied = nonstandard_suffix("9 Oak Avenue", &mut rng).unwrap();
+        let suffix = varied.strip_prefix("9 Oak ").unwrap();
+        assert!(["Av", "Aven", "Avenu", "Avn", "Avnue"].contains(&suffix));
+        assert_eq!(nonstandard_suffix("9 Oak Court", &mut rng), None);
+    }
+
+    #[test]
+    fn test_directionals() {
+        assert_eq!(
+            abbreviate_directionals("123 North Main St Southwest").unwrap(),
+            "123 N Main St SW"
+        );
+        assert_eq!(
+            expand_directionals("123 NW Main St").unwrap(),
+            "123 Northwest Main St"
+        );
+        assert_eq!(
+            expand_directionals("123 NW MAIN ST").unwrap(),
+            "123 NORTHWEST MAIN ST"
+        );
+        // "West" is the street name when only a suffix follows it
+        assert_eq!(abbreviate_directionals("123 West St"), None);
+    }
+
+    #[test]
+    fn test_move_directional() {
+        assert_eq!(move_directional("123 N Main St").unwrap(), "123 Main St N");
+        assert_eq!(
+            move_directional("123 Main St North Apt 4").unwrap(),
+            "123 North Main St Apt 4"
+        );
+        assert_eq!(move_directional("123 Main St"), None);
+    }
+
+    #[test]
+    fn test_ordinals() {
+        assert_eq!(numeric_ordinal(1), "1st");
+        assert_eq!(numeric_ordinal(12), "12th");
+        assert_eq!(numeric_ordinal(23), "23rd");
+        assert_eq!(spelled_ordinal(5), "Fifth");
+        assert_eq!(spelled_ordinal(40), "Fortieth");
+        assert_eq!(spelled_ordinal(42), "Forty-Second");
+        for n in 1..=MAX_SPELLED_ORDINAL {
+            assert_eq!(parse_ordinal(&spelled_ordinal(n)), Some(n));
+            assert_eq!(parse_ordinal(&numeric_ordinal(n)), Some(n));
+        }
+        assert_eq!(parse_ordinal("5st"), None);
+    }
+
+    #[test]
+    fn test_respell_ordinal() {
+        assert_eq!(respell_ordinal("123 5th Ave").unwrap(), "123 Fifth Ave");
+        assert_eq!(respell_ordinal("123 Fifth Ave").unwrap(), "123 5th Ave");
+        assert_eq!(
+            respell_ordinal("123 W 42ND ST").unwrap(),
+            "123 W FORTY-SECOND ST"
+        );
+        assert_eq!(respell_ordinal("123 Main St"), None);
+    }
+
+    #[test]
+    fn test_house_numbers() {
+        let mut rng = StdRng::seed_from_u64(1);
+        assert_eq!(
+            add_house_number_fraction("123 Main St").unwrap(),
+            "123 1/2 Main St"
+        );
+        assert_eq!(add_house_number_fraction("123 1/2 Main St"), None);
+        let range = house_number_range("100 Main St", &mut rng).unwrap();
+        assert!(["100-102 Main St", "100-104 Main St", "100-106 Main St"].contains(&range.as_str()));
+        // No range runs past the largest house number
+        assert_eq!(house_number_range("4294967295 Main St", &mut rng), None);
+        assert_eq!(remove_house_number("123 1/2 Main St").unwrap(), "Main St");
+        assert_eq!(remove_house_number("Main St"), None);
+        assert_eq!(remove_house_number("PO Box 12"), None);
+    }
+
+    #[test]
+    fn test_whitespace_between_words_is_kept() {
+        assert_eq!(
+            abbreviate_suffix("123\u{a0}Main Street").unwrap(),
+            "123\u{a0}Main St"
+        );
+        assert_eq!(
+            move_directional("123  N  Main  St").unwrap(),
+            "123  Main  St  N"
+        );
+        assert_eq!(
+            move_directional("123 Main St\u{a0}N Apt 4").unwrap(),
+            "123 N\u{a0}Main St Apt 4"
+        );
+        assert_eq!(
+            add_house_number_fraction("123  Main  St").unwrap(),
+            "123  1/2  Main  St"
+        );
+        assert_eq!(remove_house_number(" 123  Main  St").unwrap(), " Main  St");
+        assert_eq!(add_periods("9  N\tOak  Ave"), "9  N.\tOak  Ave.");
+    }
+}
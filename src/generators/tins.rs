@@ -0,0 +1,501 @@
+//! Taxpayer identification numbers that look real but can never be issued.
+//!
+//! SSNs are drawn from the reserved `000` and `9xx` areas (avoiding the
+//! `9xx` groups used by ITINs), and EINs from campus prefixes the IRS has
+//! never assigned. Either can be shared in test data without exposing a
+//! real person or business.
+
+use rand::Rng;
+use serde::Serialize;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+use crate::generators::{NamedPattern, Traced};
+use crate::profile::VarianceProfile;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Tin {
+    pub tin: String,
+}
+
+impl Tin {
+    pub fn new(tin: String) -> Self {
+        Self { tin }
+    }
+
+    pub fn to_record(&self) -> Vec<String> {
+        vec![self.tin.clone()]
+    }
+}
+
+/// Which kind of taxpayer identification number to generate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TinKind {
+    /// Social Security number: `900-12-3456`
+    #[default]
+    Ssn,
+    /// Employer identification number: `07-1234567`
+    Ein,
+}
+
+impl TinKind {
+    /// Every kind, in declaration order
+    pub const ALL: [TinKind; 2] = [TinKind::Ssn, TinKind::Ein];
+
+    /// The name used with `--kind`
+    pub fn name(&self) -> &'static str {
+        match self {
+            TinKind::Ssn => "ssn",
+            TinKind::Ein => "ein",
+        }
+    }
+}
+
+impl fmt::Display for TinKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for TinKind {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let name = s.trim().to_lowercase();
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|kind| kind.name() == name)
+            .ok_or_else(|| {
+                let valid: Vec<&str> = Self::ALL.iter().map(|k| k.name()).collect();
+                Error::InvalidArgument(format!(
+                    "Unknown TIN kind '{}'. Valid kinds: {}",
+                    s,
+                    valid.join(", ")
+                ))
+            })
+    }
+}
+
+/// `9xx` group numbers that belong to ITINs, which are issued to real
+/// taxpayers and so must not be generated.
+fn is_itin_group(group: u16) -> bool {
+    matches!(group, 50..=65 | 70..=88 | 90..=92 | 94..=99)
+}
+
+/// EIN prefixes the IRS has never assigned to a campus.
+pub const UNASSIGNED_EIN_PREFIXES: &[u8] = &[
+    0, 7, 8, 9, 17, 18, 19, 28, 29, 49, 69, 70, 78, 79, 89, 96, 97,
+];
+
+/// Generates an SSN formatted as `900-12-3456` that can never be issued:
+/// its area is `000` or `900`-`999`, and `9xx` areas skip ITIN groups.
+pub fn generate_clean_ssn<R: Rng + ?Sized>(rng: &mut R) -> String {
+    let area: u16 = if rng.gen_bool(0.1) {
+        0
+    } else {
+        rng.gen_range(900..1000)
+    };
+    let group = loop {
+        let group = rng.gen_range(1..100);
+        if area == 0 || !is_itin_group(group) {
+            break group;
+        }
+    };
+    let serial: u16 = rng.gen_range(1..10_000);
+    format!("{:03}-{:02}-{:04}", area, group, serial)
+}
+
+/// Generates an EIN formatted as `07-1234567` whose prefix has never been
+/// assigned.
+pub fn generate_clean_ein<R: Rng + ?Sized>(rng: &mut R) -> String {
+    let prefix = UNASSIGNED_EIN_PREFIXES[rng.gen_range(0..UNASSIGNED_EIN_PREFIXES.len())];
+    let serial: u32 = rng.gen_range(0..10_000_000);
+    format!("{:02}-{:07}", prefix, serial)
+}
+
+/// Generates a clean identifier of the given kind.
+pub fn generate_clean_tin<R: Rng + ?Sized>(kind: TinKind, rng: &mut R) -> String {
+    match kind {
+        TinKind::Ssn => generate_clean_ssn(rng),
+        TinKind::Ein => generate_clean_ein(rng),
+    }
+}
+
+/// Applies profile variance to a single identifier: record-level variance
+/// with probability `error_rate`, then any per-field error rates.
+pub fn vary_tin<R: Rng + ?Sized>(
+    tin: Tin,
+    error_rate: f64,
+    profile: &VarianceProfile<VariancePattern>,
+    rng: &mut R,
+) -> Traced<Tin> {
+    profile.vary(tin, error_rate, rng, apply_pattern)
+}
+
+/// A single, individually selectable identifier variance pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VariancePattern {
+    /// "900123456"
+    NoDashes,
+    /// One digit replaced by another: "900-12-3457"
+    DigitTypo,
+    /// Two adjacent digits swapped: "900-21-3456"
+    TransposedDigits,
+    /// Trailing digits cut off: "900-12-34"
+    Truncated,
+    /// One digit repeated, as entered when the real value is unknown:
+    /// "000-00-0000", or "900-12-1111" for a nonzero digit
+    RepeatedDigit,
+    /// All but the last four digits masked: "XXX-XX-3456"
+    Masked,
+}
+
+impl NamedPattern for VariancePattern {
+    const ALL: &'static [Self] = &[
+        VariancePattern::NoDashes,
+        VariancePattern::DigitTypo,
+        VariancePattern::TransposedDigits,
+        VariancePattern::Truncated,
+        VariancePattern::RepeatedDigit,
+        VariancePattern::Masked,
+    ];
+
+    fn name(&self) -> &'static str {
+        match self {
+            VariancePattern::NoDashes => "no_dashes",
+            VariancePattern::DigitTypo => "digit_typo",
+            VariancePattern::TransposedDigits => "transposed_digits",
+            VariancePattern::Truncated => "truncated",
+            VariancePattern::RepeatedDigit => "repeated_digit",
+            VariancePattern::Masked => "masked",
+        }
+    }
+
+    fn fields(&self) -> &'static [&'static str] {
+        &["tin"]
+    }
+}
+
+impl fmt::Display for VariancePattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Byte offsets of the digits in `tin`.
+fn digit_positions(tin: &str) -> Vec<usize> {
+    tin.char_indices()
+        .filter(|(_, c)| c.is_ascii_digit())
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Byte offsets of the digits in `tin` that can be edited without leaving
+/// the never-issued range.
+///
+/// Everything before the last dash is held back: the SSN area and group
+/// (a typo in a `9xx` group can land in an ITIN range) or the EIN prefix.
+/// Once the dashes are gone the first five digits are held back, which
+/// covers either kind.
+fn editable_digit_positions(tin: &str) -> Vec<usize> {
+    let reserved = match tin.rfind('-') {
+        Some(i) => tin[..i].bytes().filter(u8::is_ascii_digit).count(),
+        None => 5,
+    };
+    digit_positions(tin).into_iter().skip(reserved).collect()
+}
+
+/// Applies a single variance pattern to an identifier in place.
+///
+/// Returns the ground-truth label of the change, or `None` if the pattern
+/// had nothing to act on (e.g. masking a value with four or fewer digits).
+pub fn apply_pattern<R: Rng + ?Sized>(
+    tin: &mut Tin,
+    pattern: VariancePattern,
+    rng: &mut R,
+) -> Option<String> {
+    let before = tin.clone();
+    let value = &mut tin.tin;
+
+    match pattern {
+        VariancePattern::NoDashes => {
+            value.retain(|c| c != '-');
+        }
+        VariancePattern::DigitTypo => {
+            let digits = editable_digit_positions(value);
+            if !digits.is_empty() {
+                let i = digits[rng.gen_range(0..digits.len())];
+                let old = value.as_bytes()[i] - b'0';
+                let new = (old + rng.gen_range(1..10)) % 10;
+                value.replace_range(i..i + 1, &new.to_string());
+            }
+        }
+        VariancePattern::TransposedDigits => {
+            let bytes = value.as_bytes();
+            let digits = editable_digit_positions(value);
+            let pairs: Vec<usize> = digits
+                .windows(2)
+                .filter(|w| w[1] == w[0] + 1 && bytes[w[0]] != bytes[w[1]])
+                .map(|w| w[0])
+                .collect();
+            if !pairs.is_empty() {
+                let i = pairs[rng.gen_range(0..pairs.len())];
+                let swapped = format!("{}{}", &value[i + 1..i + 2], &value[i..i + 1]);
+                value.replace_range(i..i + 2, &swapped);
+            }
+        }
+        VariancePattern::Truncated => {
+            let digits = digit_positions(value);
+            if digits.len() > 4 {
+                let keep = digits.len() - rng.gen_range(1..=3);
+                value.truncate(digits[keep]);
+                let trimmed = value.trim_end_matches('-').len();
+                value.truncate(trimmed);
+            }
+        }
+        VariancePattern::RepeatedDigit => {
+            // Zero is the only digit that stays unissued when repeated
+            // through the area or prefix (`000`, `00`); "111-11-1111" would
+            // be issuable, so other digits only fill the serial.
+            let digit = rng.gen_range(0..10);
+            let positions = if digit == 0 {
+                digit_positions(value)
+            } else {
+                editable_digit_positions(value)
+            };
+            let digit = digit.to_string();
+            for i in positions {
+                value.replace_range(i..i + 1, &digit);
+            }
+        }
+        VariancePattern::Masked => {
+            let digits = digit_positions(value);
+            if digits.len() > 4 {
+                let mask = if rng.gen_bool(0.5) { "X" } else { "*" };
+                for &i in &digits[..digits.len() - 4] {
+                    value.replace_range(i..i + 1, mask);
+                }
+            }
+        }
+    }
+
+    if *tin == before {
+        None
+    } else {
+        Some(pattern.name().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn apply(value: &str, pattern: VariancePattern) -> (String, Option<String>) {
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut tin = Tin::new(value.to_string());
+        let label = apply_pattern(&mut tin, pattern, &mut rng);
+        (tin.tin, label)
+    }
+
+    #[test]
+    fn test_ssns_are_never_issuable() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..1000 {
+            let ssn = generate_clean_ssn(&mut rng);
+            let parts: Vec<&str> = ssn.split('-').collect();
+            assert_eq!(
+                parts.iter().map(|p| p.len()).collect::<Vec<_>>(),
+                [3, 2, 4],
+                "{}",
+                ssn
+            );
+            let area: u16 = parts[0].parse().unwrap();
+            let group: u16 = parts[1].parse().unwrap();
+            assert!(area == 0 || area >= 900, "{}", ssn);
+            assert!(area == 0 || !is_itin_group(group), "ITIN: {}", ssn);
+            assert_ne!(group, 0, "{}", ssn);
+            assert_ne!(parts[2], "0000", "{}", ssn);
+        }
+    }
+
+    #[test]
+    fn test_eins_use_unassigned_prefixes() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..500 {
+            let ein = generate_clean_ein(&mut rng);
+            let (prefix, serial) = ein.split_once('-').unwrap();
+            assert_eq!(serial.len(), 7, "{}", ein);
+            assert!(UNASSIGNED_EIN_PREFIXES.contains(&prefix.parse().unwrap()));
+        }
+    }
+
+    #[test]
+    fn test_parse_tin_kind() {
+        assert_eq!("SSN".parse::<TinKind>().unwrap(), TinKind::Ssn);
+        assert_eq!("ein".parse::<TinKind>().unwrap(), TinKind::Ein);
+        assert!("itin".parse::<TinKind>().is_err());
+    }
+
+    #[test]
+    fn test_no_dashes() {
+        assert_eq!(
+            apply("900-12-3456", VariancePattern::NoDashes),
+            ("900123456".to_string(), Some("no_dashes".to_string()))
+        );
+        assert_eq!(apply("900123456", VariancePattern::NoDashes).1, None);
+    }
+
+    #[test]
+    fn test_digit_typo_changes_one_digit() {
+        let (value, label) = apply("900-12-3456", VariancePattern::DigitTypo);
+        let changed = value
+            .chars()
+            .zip("900-12-3456".chars())
+            .filter(|(a, b)| a != b)
+            .count();
+        assert_eq!(changed, 1, "{}", value);
+        assert_eq!(label.as_deref(), Some("digit_typo"));
+    }
+
+    #[test]
+    fn test_transposed_digits() {
+        let (value, _) = apply("07-1234567", VariancePattern::TransposedDigits);
+        assert_ne!(value, "07-1234567");
+        let mut sorted: Vec<char> = value.chars().collect();
+        sorted.sort();
+        let mut expected: Vec<char> = "07-1234567".chars().collect();
+        expected.sort();
+        assert_eq!(sorted, expected);
+        assert_eq!(
+            apply("111-11-1111", VariancePattern::TransposedDigits).1,
+            None
+        );
+    }
+
+    #[test]
+    fn test_truncated_drops_trailing_digits() {
+        let (value, label) = apply("900-12-3456", VariancePattern::Truncated);
+        assert!("900-12-3456".starts_with(&value), "{}", value);
+        assert!((7..=10).contains(&value.len()), "{}", value);
+        assert!(!value.ends_with('-'));
+        assert_eq!(label.as_deref(), Some("truncated"));
+        assert_eq!(apply("1234", VariancePattern::Truncated).1, None);
+    }
+
+    #[test]
+    fn test_repeated_digit_keeps_format() {
+        let mut seen = Vec::new();
+        for seed in 0..100 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            for (value, prefix) in [("912-34-5678", "912-34-"), ("07-1234567", "07-")] {
+                let mut tin = Tin::new(value.to_string());
+                apply_pattern(&mut tin, VariancePattern::RepeatedDigit, &mut rng);
+                let repeated = tin.tin.as_bytes()[tin.tin.len() - 1];
+                let expected: String = value
+                    .chars()
+                    .map(|c| if c == '-' { c } else { repeated as char })
+                    .collect();
+                if repeated == b'0' {
+                    assert_eq!(tin.tin, expected);
+                } else {
+                    assert_eq!(tin.tin[..prefix.len()], *prefix, "{}", tin.tin);
+                    assert_eq!(tin.tin[prefix.len()..], expected[prefix.len()..]);
+                }
+                seen.push(repeated);
+            }
+        }
+        assert!(seen.contains(&b'0'));
+        assert!(seen.iter().any(|&d| d != b'0'));
+    }
+
+    #[test]
+    fn test_typos_leave_reserved_prefix_alone() {
+        for pattern in [
+            VariancePattern::DigitTypo,
+            VariancePattern::TransposedDigits,
+        ] {
+            for seed in 0..50 {
+                let mut rng = StdRng::seed_from_u64(seed);
+                for (value, prefix) in [
+                    ("912-34-5678", "912-34-"),
+                    ("07-1234567", "07-"),
+                    ("912345678", "91234"),
+                ] {
+                    let mut tin = Tin::new(value.to_string());
+                    apply_pattern(&mut tin, pattern, &mut rng);
+                    assert!(tin.tin.starts_with(prefix), "{}: {}", pattern, tin.tin);
+                }
+            }
+        }
+    }
+
+    /// Checks that whatever digits of the SSN area/group or EIN prefix
+    /// survive still mark the value as never issued.
+    fn assert_never_issued(kind: TinKind, value: &str) {
+        let digits: String = value.chars().filter(|&c| c != '-').collect();
+        match kind {
+            TinKind::Ssn => {
+                if let Some(Ok(area)) = digits.get(..3).map(str::parse::<u16>) {
+                    assert!(area == 0 || area >= 900, "{}", value);
+                    if let Some(Ok(group)) = digits.get(3..5).map(str::parse::<u16>) {
+                        assert!(area == 0 || !is_itin_group(group), "ITIN: {}", value);
+                    }
+                }
+            }
+            TinKind::Ein => {
+                if let Some(Ok(prefix)) = digits.get(..2).map(str::parse::<u8>) {
+                    assert!(UNASSIGNED_EIN_PREFIXES.contains(&prefix), "{}", value);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_variance_never_makes_tins_issuable() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut profiles: Vec<VarianceProfile<VariancePattern>> = VariancePattern::ALL
+            .iter()
+            .map(|&pattern| VarianceProfile::uniform(&[pattern]))
+            .collect();
+        profiles.push(VarianceProfile::uniform(VariancePattern::ALL));
+        for profile in &profiles {
+            for kind in TinKind::ALL {
+                for _ in 0..500 {
+                    let tin = Tin::new(generate_clean_tin(kind, &mut rng));
+                    let traced = vary_tin(tin, 1.0, profile, &mut rng);
+                    assert_never_issued(kind, &traced.record.tin);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_masked_keeps_last_four() {
+        let (value, label) = apply("900-12-3456", VariancePattern::Masked);
+        assert!(
+            ["XXX-XX-3456", "***-**-3456"].contains(&value.as_str()),
+            "{}",
+            value
+        );
+        assert_eq!(label.as_deref(), Some("masked"));
+        assert_eq!(apply("XXX-XX-3456", VariancePattern::Masked).1, None);
+    }
+
+    #[test]
+    fn test_zero_error_rate_keeps_tins_clean() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let profile = VarianceProfile::uniform(VariancePattern::ALL);
+        for kind in TinKind::ALL {
+            for _ in 0..50 {
+                let tin = Tin::new(generate_clean_tin(kind, &mut rng));
+                let traced = vary_tin(tin, 0.0, &profile, &mut rng);
+                assert_eq!(traced.record, traced.clean);
+                assert!(traced.variances.is_empty());
+            }
+        }
+    }
+}
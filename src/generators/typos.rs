@@ -0,0 +1,574 @@
+//! Typo models shared by the name and address generators.
+//!
+//! Each model reproduces one source of data-entry error, so a fuzzy
+//! matcher can be tested against the edit-distance profile it will meet in
+//! production: random slips, neighbouring keys, sound-alike spellings,
+//! OCR misreads and speech-to-text mishearings. Every variance pattern
+//! that applies a typo is backed by one model; the applied typo is labeled
+//! `typo:<kind>` in ground truth.
+
+use rand::Rng;
+use std::fmt;
+
+/// A source of typos.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TypoModel {
+    /// A doubled, transposed or missing character
+    #[default]
+    Basic,
+    /// A neighbouring QWERTY key hit instead of, or as well as, the
+    /// intended one: "John" -> "Jihn", "Jojhn"
+    Keyboard,
+    /// A sound-alike spelling: "Catherine" -> "Katherine",
+    /// "Stephen" -> "Steven", "Phillips" -> "Fillips"
+    Phonetic,
+    /// A character misread by OCR: "rn" -> "m", "O" -> "0", "l" -> "1"
+    Ocr,
+    /// A speech-to-text mishearing: a homophone ("Main" -> "Maine"), a
+    /// teen heard as a ten ("15" -> "50") or a split prefix ("McDonald" ->
+    /// "Mc Donald")
+    Voice,
+}
+
+impl TypoModel {
+    pub const ALL: [TypoModel; 5] = [
+        TypoModel::Basic,
+        TypoModel::Keyboard,
+        TypoModel::Phonetic,
+        TypoModel::Ocr,
+        TypoModel::Voice,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TypoModel::Basic => "basic",
+            TypoModel::Keyboard => "keyboard",
+            TypoModel::Phonetic => "phonetic",
+            TypoModel::Ocr => "ocr",
+            TypoModel::Voice => "voice",
+        }
+    }
+
+    /// Applies one typo to `value`.
+    ///
+    /// Returns the misspelled value and its ground-truth label
+    /// (`typo:double`, `typo:transpose` or `typo:remove` for the basic
+    /// model, `typo:<model>` otherwise), or `None` if the model has
+    /// nothing to act on.
+    pub fn apply<R: Rng + ?Sized>(
+        &self,
+        value: &str,
+        rng: &mut R,
+    ) -> Option<(String, &'static str)> {
+        let (typo, label) = match self {
+            TypoModel::Basic => return basic_typo(value, rng),
+            TypoModel::Keyboard => (keyboard_typo(value, rng), "typo:keyboard"),
+            TypoModel::Phonetic => (phonetic_typo(value, rng), "typo:phonetic"),
+            TypoModel::Ocr => (ocr_typo(value, rng), "typo:ocr"),
+            TypoModel::Voice => (voice_typo(value, rng), "typo:voice"),
+        };
+        typo.filter(|typo| typo != value).map(|typo| (typo, label))
+    }
+}
+
+/// Applies a typo from `model` to one of two fields, chosen at random and
+/// falling back to the other if the model has nothing to act on, returning
+/// its label, or `None` if neither field changed.
+pub fn misspell<R: Rng + ?Sized>(
+    mut fields: [&mut String; 2],
+    model: TypoModel,
+    rng: &mut R,
+) -> Option<&'static str> {
+    if rng.gen_bool(0.5) {
+        fields.swap(0, 1);
+    }
+    for field in fields {
+        if let Some((typo, label)) = model.apply(field, rng) {
+            *field = typo;
+            return Some(label);
+        }
+    }
+    None
+}
+
+impl fmt::Display for TypoModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Adds a random slip to a string.
+///
+/// Randomly applies one of three typo types:
+/// - Double a letter (e.g., "John" -> "Johhn")
+/// - Transpose two adjacent letters (e.g., "John" -> "Jhon")
+/// - Remove a letter (e.g., "John" -> "Jon")
+///
+/// Strings of fewer than two characters are left alone.
+fn basic_typo<R: Rng + ?Sized>(value: &str, rng: &mut R) -> Option<(String, &'static str)> {
+    let mut chars: Vec<char> = value.chars().collect();
+
+    if chars.len() < 2 {
+        return None;
+    }
+
+    let typo_type = rng.gen_range(0..3);
+    let label = match typo_type {
+        0 => {
+            // Double a letter
+            let pos = rng.gen_range(0..chars.len());
+            chars.insert(pos, chars[pos]);
+            "typo:double"
+        }
+        1 => {
+            // Transpose two letters
+            let pos = rng.gen_range(0..chars.len() - 1);
+            chars.swap(pos, pos + 1);
+            "typo:transpose"
+        }
+        _ => {
+            // Remove a letter (but keep at least one)
+            let pos = rng.gen_range(0..chars.len());
+            chars.remove(pos);
+            "typo:remove"
+        }
+    };
+
+    Some((chars.into_iter().collect(), label))
+}
+
+const QWERTY_ROWS: [&str; 4] = ["1234567890", "qwertyuiop", "asdfghjkl", "zxcvbnm"];
+
+/// Keys touching `key` on a QWERTY keyboard, whose rows are staggered so
+/// each key sits below the key of the same index and the one to its right.
+fn adjacent_keys(key: char) -> Vec<char> {
+    let lower = key.to_ascii_lowercase();
+    let Some((row, index)) = QWERTY_ROWS
+        .iter()
+        .enumerate()
+        .find_map(|(row, keys)| keys.find(lower).map(|index| (row, index)))
+    else {
+        return Vec::new();
+    };
+
+    let mut neighbours = Vec::new();
+    let mut push = |row: usize, index: Option<usize>| {
+        if let Some(c) = index.and_then(|i| QWERTY_ROWS[row].chars().nth(i)) {
+            neighbours.push(if key.is_ascii_uppercase() {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            });
+        }
+    };
+    push(row, index.checked_sub(1));
+    push(row, Some(index + 1));
+    if row > 0 {
+        push(row - 1, Some(index));
+        push(row - 1, Some(index + 1));
+    }
+    if row + 1 < QWERTY_ROWS.len() {
+        push(row + 1, index.checked_sub(1));
+        push(row + 1, Some(index));
+    }
+    // Digits keep to digits and letters to letters, as a slip on a form
+    // field that validates its input would
+    neighbours.retain(|c| c.is_ascii_digit() == key.is_ascii_digit());
+    neighbours
+}
+
+fn keyboard_typo<R: Rng + ?Sized>(value: &str, rng: &mut R) -> Option<String> {
+    let mut chars: Vec<char> = value.chars().collect();
+    let positions: Vec<usize> = (0..chars.len())
+        .filter(|&i| chars[i].is_ascii_alphanumeric())
+        .collect();
+    if positions.is_empty() {
+        return None;
+    }
+    let pos = positions[rng.gen_range(0..positions.len())];
+    let neighbours = adjacent_keys(chars[pos]);
+    if neighbours.is_empty() {
+        return None;
+    }
+    let neighbour = neighbours[rng.gen_range(0..neighbours.len())];
+
+    if rng.gen_bool(0.6) {
+        // Hit the neighbour instead
+        chars[pos] = neighbour;
+    } else {
+        // Hit both keys at once
+        let at = if rng.gen_bool(0.5) { pos } else { pos + 1 };
+        chars.insert(at, neighbour);
+    }
+    Some(chars.into_iter().collect())
+}
+
+/// Names and words spelled differently but said the same
+const SOUND_ALIKES: &[&[&str]] = &[
+    &["Catherine", "Katherine", "Kathryn", "Cathryn"],
+    &["Stephen", "Steven"],
+    &["Jeffrey", "Geoffrey", "Jeffery"],
+    &["Philip", "Phillip", "Filip"],
+    &["Sean", "Shawn", "Shaun"],
+    &["Brian", "Bryan", "Brien"],
+    &["Eric", "Erik", "Erick"],
+    &["Carl", "Karl"],
+    &["Jon", "John"],
+    &["Ann", "Anne"],
+    &["Sara", "Sarah"],
+    &["Alan", "Allan", "Allen"],
+    &["Matthew", "Mathew"],
+    &["Nicholas", "Nicolas", "Nikolas"],
+    &["Teresa", "Theresa"],
+    &["Kristin", "Kristen", "Christen"],
+    &["Caitlin", "Kaitlyn", "Katelyn"],
+    &["Marc", "Mark"],
+    &["Smith", "Smyth"],
+    &["Thompson", "Thomson", "Tomson"],
+    &["Reid", "Reed", "Read"],
+    &["Meyer", "Meier", "Mayer"],
+    &["Schmidt", "Schmitt", "Smidt"],
+    &["Gray", "Grey"],
+];
+
+/// Spelling rewrites that keep the sound: (written, sounds like)
+const SOUND_REWRITES: &[(&str, &str)] = &[
+    ("ph", "f"),
+    ("ck", "k"),
+    ("ca", "ka"),
+    ("co", "ko"),
+    ("cu", "ku"),
+    ("ie", "ei"),
+    ("ei", "ie"),
+    ("ee", "ea"),
+    ("ea", "ee"),
+    ("ll", "l"),
+    ("tt", "t"),
+    ("nn", "n"),
+    ("ss", "s"),
+    ("y", "i"),
+    ("z", "s"),
+    ("x", "ks"),
+    ("qu", "kw"),
+    ("gh", "g"),
+    ("wr", "r"),
+    ("kn", "n"),
+];
+
+fn phonetic_typo<R: Rng + ?Sized>(value: &str, rng: &mut R) -> Option<String> {
+    let alternatives = word_alternatives(value, SOUND_ALIKES);
+    if !alternatives.is_empty() {
+        return Some(alternatives[rng.gen_range(0..alternatives.len())].clone());
+    }
+    rewrite_once(value, SOUND_REWRITES, rng)
+}
+
+/// Characters and pairs OCR engines confuse: (printed, read as)
+const OCR_CONFUSIONS: &[(&str, &str)] = &[
+    ("rn", "m"),
+    ("m", "rn"),
+    ("cl", "d"),
+    ("d", "cl"),
+    ("vv", "w"),
+    ("w", "vv"),
+    ("li", "h"),
+    ("O", "0"),
+    ("0", "O"),
+    ("o", "0"),
+    ("l", "1"),
+    ("1", "l"),
+    ("I", "1"),
+    ("I", "l"),
+    ("S", "5"),
+    ("5", "S"),
+    ("B", "8"),
+    ("8", "B"),
+    ("Z", "2"),
+    ("2", "Z"),
+    ("G", "6"),
+    ("6", "G"),
+    ("e", "c"),
+    ("c", "e"),
+    ("h", "b"),
+    ("u", "v"),
+    ("n", "ri"),
+];
+
+fn ocr_typo<R: Rng + ?Sized>(value: &str, rng: &mut R) -> Option<String> {
+    let matches = find_all(value, OCR_CONFUSIONS, false);
+    if matches.is_empty() {
+        return None;
+    }
+    let (at, from, to) = matches[rng.gen_range(0..matches.len())];
+    Some(format!(
+        "{}{}{}",
+        &value[..at],
+        to,
+        &value[at + from.len()..]
+    ))
+}
+
+/// Words a transcription engine hears as another
+const HOMOPHONES: &[&[&str]] = &[
+    &["Main", "Maine", "Mane"],
+    &["Lane", "Lain"],
+    &["Road", "Rode", "Rowed"],
+    &["Fourth", "Forth"],
+    &["Way", "Weigh"],
+    &["Park", "Parc"],
+    &["Hill", "Hil"],
+    &["Knoll", "Noll"],
+    &["Pier", "Peer"],
+    &["Ridge", "Rich"],
+    &["Claire", "Clare", "Clair"],
+    &["Mary", "Merry", "Marry"],
+    &["Lee", "Leigh", "Li"],
+    &["Rose", "Rows"],
+    &["Neil", "Neal", "Kneel"],
+    &["Stevens", "Stephens"],
+    &["Wright", "Right", "Rite"],
+    &["Knight", "Night"],
+    &["Fisher", "Fischer"],
+];
+
+/// Teens and tens that sound alike
+const TEENS_AND_TENS: &[(&str, &str)] = &[
+    ("13", "30"),
+    ("14", "40"),
+    ("15", "50"),
+    ("16", "60"),
+    ("17", "70"),
+    ("18", "80"),
+    ("19", "90"),
+    ("30", "13"),
+    ("40", "14"),
+    ("50", "15"),
+    ("60", "16"),
+    ("70", "17"),
+    ("80", "18"),
+    ("90", "19"),
+];
+
+fn voice_typo<R: Rng + ?Sized>(value: &str, rng: &mut R) -> Option<String> {
+    let mut options: Vec<String> = word_alternatives(value, HOMOPHONES);
+
+    for (at, from, to) in find_all(value, TEENS_AND_TENS, true) {
+        options.push(format!(
+            "{}{}{}",
+            &value[..at],
+            to,
+            &value[at + from.len()..]
+        ));
+    }
+
+    // "McDonald" -> "Mc Donald", "MacArthur" -> "Mac Arthur"
+    for (i, word) in value.split(' ').enumerate() {
+        for prefix in ["Mc", "Mac"] {
+            let split = word
+                .strip_prefix(prefix)
+                .filter(|rest| rest.starts_with(|c: char| c.is_ascii_uppercase()));
+            if let Some(rest) = split {
+                let mut words: Vec<String> = value.split(' ').map(String::from).collect();
+                words[i] = format!("{} {}", prefix, rest);
+                options.push(words.join(" "));
+            }
+        }
+    }
+
+    if options.is_empty() {
+        None
+    } else {
+        Some(options.swap_remove(rng.gen_range(0..options.len())))
+    }
+}
+
+/// Every way of replacing one word of `value` with another member of its
+/// group, matching case-insensitively and keeping an all-caps word in
+/// caps.
+fn word_alternatives(value: &str, groups: &[&[&str]]) -> Vec<String> {
+    let words: Vec<&str> = value.split(' ').collect();
+    let mut alternatives = Vec::new();
+    for (i, word) in words.iter().enumerate() {
+        let upper = word.len() > 1 && word.chars().all(|c| !c.is_lowercase());
+        for group in groups {
+            if !group.iter().any(|w| w.eq_ignore_ascii_case(word)) {
+                continue;
+            }
+            for spelling in group.iter().filter(|w| !w.eq_ignore_ascii_case(word)) {
+                let mut replaced: Vec<String> = words.iter().map(|w| w.to_string()).collect();
+                replaced[i] = if upper {
+                    spelling.to_uppercase()
+                } else {
+                    spelling.to_string()
+                };
+                alternatives.push(replaced.join(" "));
+            }
+        }
+    }
+    alternatives
+}
+
+/// Every byte offset in `value` where a rule's pattern occurs, with the
+/// pattern and its replacement. Digit rules match only whole numbers'
+/// edges when `whole_digits` is set, so "150" never becomes "500".
+fn find_all<'a>(
+    value: &str,
+    rules: &[(&'a str, &'a str)],
+    whole_digits: bool,
+) -> Vec<(usize, &'a str, &'a str)> {
+    let mut matches = Vec::new();
+    for &(from, to) in rules {
+        for (at, _) in value.match_indices(from) {
+            if whole_digits {
+                let bytes = value.as_bytes();
+                let before = at.checked_sub(1).map(|i| bytes[i]);
+                let after = bytes.get(at + from.len()).copied();
+                if before.is_some_and(|b| b.is_ascii_digit())
+                    || after.is_some_and(|b| b.is_ascii_digit())
+                {
+                    continue;
+                }
+            }
+            matches.push((at, from, to));
+        }
+    }
+    matches
+}
+
+/// Applies one lowercase rewrite rule at one random place, matching
+/// case-insensitively and keeping the case of the replaced text's first
+/// letter.
+fn rewrite_once<R: Rng + ?Sized>(
+    value: &str,
+    rules: &[(&str, &str)],
+    rng: &mut R,
+) -> Option<String> {
+    // ASCII lowercasing keeps byte offsets, so matches map back to `value`
+    let lower = value.to_ascii_lowercase();
+    let matches = find_all(&lower, rules, false);
+    if matches.is_empty() {
+        return None;
+    }
+    let (at, from, to) = matches[rng.gen_range(0..matches.len())];
+    let original = &value[at..at + from.len()];
+
+    let replacement = if original.len() > 1 && original.chars().all(|c| c.is_ascii_uppercase()) {
+        to.to_uppercase()
+    } else if original.starts_with(|c: char| c.is_ascii_uppercase()) {
+        let mut chars = to.chars();
+        chars
+            .next()
+            .map(|c| c.to_ascii_uppercase().to_string() + chars.as_str())
+            .unwrap_or_default()
+    } else {
+        to.to_string()
+    };
+    Some(format!(
+        "{}{}{}",
+        &value[..at],
+        replacement,
+        &value[at + from.len()..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn typos(model: TypoModel, value: &str) -> Vec<String> {
+        let mut rng = StdRng::seed_from_u64(7);
+        (0..200)
+            .filter_map(|_| model.apply(value, &mut rng))
+            .map(|(typo, _)| typo)
+            .collect()
+    }
+
+    #[test]
+    fn test_misspell_falls_back_to_the_other_field() {
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..50 {
+            let mut bob = "Bob".to_string();
+            let mut catherine = "Catherine".to_string();
+            let label = misspell([&mut bob, &mut catherine], TypoModel::Phonetic, &mut rng);
+            assert_eq!(label, Some("typo:phonetic"));
+            assert_eq!(bob, "Bob");
+            assert_ne!(catherine, "Catherine");
+        }
+
+        let (mut first, mut last) = ("Bob".to_string(), "Bob".to_string());
+        let label = misspell([&mut first, &mut last], TypoModel::Phonetic, &mut rng);
+        assert_eq!(label, None);
+    }
+
+    #[test]
+    fn test_basic_typo() {
+        let (result, label) = TypoModel::Basic
+            .apply("Joshua", &mut rand::thread_rng())
+            .unwrap();
+        assert!(label.starts_with("typo:"));
+        // Length changes by at most one character
+        assert!((result.len() as i32 - "Joshua".len() as i32).abs() <= 1);
+        assert_eq!(TypoModel::Basic.apply("J", &mut rand::thread_rng()), None);
+    }
+
+    #[test]
+    fn test_adjacent_keys() {
+        assert_eq!(adjacent_keys('g'), ['f', 'h', 't', 'y', 'v', 'b']);
+        assert_eq!(adjacent_keys('Q'), ['W', 'A']);
+        assert_eq!(adjacent_keys('5'), ['4', '6']);
+        assert!(adjacent_keys('-').is_empty());
+    }
+
+    #[test]
+    fn test_keyboard_typos_use_neighbouring_keys() {
+        for typo in typos(TypoModel::Keyboard, "Jo") {
+            assert!(
+                [
+                    "Ho", "Ko", "Uo", "Io", "No", "Mo", "Ji", "Jp", "J9", "J0", "Jk", "Jl", "HJo",
+                    "JHo", "KJo", "JKo", "UJo", "JUo", "IJo", "JIo", "NJo", "JNo", "MJo", "JMo",
+                    "Jio", "Joi", "Jpo", "Jop", "Jko", "Jok", "Jlo", "Jol"
+                ]
+                .contains(&typo.as_str()),
+                "{}",
+                typo
+            );
+        }
+        assert_eq!(
+            TypoModel::Keyboard.apply("--", &mut rand::thread_rng()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_phonetic_typos() {
+        let catherine = typos(TypoModel::Phonetic, "Catherine");
+        assert!(catherine.contains(&"Katherine".to_string()));
+        assert!(typos(TypoModel::Phonetic, "Stephen")
+            .iter()
+            .all(|t| t == "Steven"));
+        assert!(typos(TypoModel::Phonetic, "Phelps").contains(&"Felps".to_string()));
+        assert!(typos(TypoModel::Phonetic, "BECKER").contains(&"BEKER".to_string()));
+        assert!(typos(TypoModel::Phonetic, "Bob").is_empty());
+    }
+
+    #[test]
+    fn test_ocr_typos() {
+        let typos = typos(TypoModel::Ocr, "Burns 10");
+        for expected in ["Bums 10", "Burns 1O", "Burns l0", "8urns 10"] {
+            assert!(typos.contains(&expected.to_string()), "{:?}", typos);
+        }
+    }
+
+    #[test]
+    fn test_voice_typos() {
+        assert!(typos(TypoModel::Voice, "15 Main St").contains(&"50 Main St".to_string()));
+        assert!(typos(TypoModel::Voice, "15 Main St").contains(&"15 Maine St".to_string()));
+        assert!(typos(TypoModel::Voice, "150 Oak St").is_empty());
+        assert_eq!(
+            typos(TypoModel::Voice, "McDonald")[0],
+            "Mc Donald".to_string()
+        );
+    }
+}
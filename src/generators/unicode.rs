@@ -0,0 +1,401 @@
+//! Unicode stress variance shared by the name and address generators.
+//!
+//! Each kind produces text that renders (nearly) the same as the clean
+//! value but differs code point by code point: the inputs a normalizer
+//! has to fold before comparing. Applied values are labeled
+//! `unicode:<kind>` in ground truth.
+
+use rand::Rng;
+
+/// Zero-width space (U+200B)
+pub const ZERO_WIDTH_SPACE: char = '\u{200B}';
+
+/// No-break space (U+00A0)
+pub const NO_BREAK_SPACE: char = '\u{00A0}';
+
+const COMBINING_GRAVE: char = '\u{0300}';
+const COMBINING_ACUTE: char = '\u{0301}';
+const COMBINING_CIRCUMFLEX: char = '\u{0302}';
+const COMBINING_TILDE: char = '\u{0303}';
+const COMBINING_MACRON: char = '\u{0304}';
+const COMBINING_DIAERESIS: char = '\u{0308}';
+const COMBINING_CEDILLA: char = '\u{0327}';
+
+/// Precomposed lowercase letters and their canonical decompositions.
+/// Uppercase letters are handled through their lowercase forms.
+const DECOMPOSITIONS: &[(char, char, char)] = &[
+    ('à', 'a', COMBINING_GRAVE),
+    ('á', 'a', COMBINING_ACUTE),
+    ('â', 'a', COMBINING_CIRCUMFLEX),
+    ('ã', 'a', COMBINING_TILDE),
+    ('ā', 'a', COMBINING_MACRON),
+    ('ä', 'a', COMBINING_DIAERESIS),
+    ('ç', 'c', COMBINING_CEDILLA),
+    ('è', 'e', COMBINING_GRAVE),
+    ('é', 'e', COMBINING_ACUTE),
+    ('ê', 'e', COMBINING_CIRCUMFLEX),
+    ('ẽ', 'e', COMBINING_TILDE),
+    ('ē', 'e', COMBINING_MACRON),
+    ('ë', 'e', COMBINING_DIAERESIS),
+    ('ì', 'i', COMBINING_GRAVE),
+    ('í', 'i', COMBINING_ACUTE),
+    ('î', 'i', COMBINING_CIRCUMFLEX),
+    ('ĩ', 'i', COMBINING_TILDE),
+    ('ī', 'i', COMBINING_MACRON),
+    ('ï', 'i', COMBINING_DIAERESIS),
+    ('ñ', 'n', COMBINING_TILDE),
+    ('ò', 'o', COMBINING_GRAVE),
+    ('ó', 'o', COMBINING_ACUTE),
+    ('ô', 'o', COMBINING_CIRCUMFLEX),
+    ('õ', 'o', COMBINING_TILDE),
+    ('ō', 'o', COMBINING_MACRON),
+    ('ö', 'o', COMBINING_DIAERESIS),
+    ('ù', 'u', COMBINING_GRAVE),
+    ('ú', 'u', COMBINING_ACUTE),
+    ('û', 'u', COMBINING_CIRCUMFLEX),
+    ('ũ', 'u', COMBINING_TILDE),
+    ('ū', 'u', COMBINING_MACRON),
+    ('ü', 'u', COMBINING_DIAERESIS),
+    ('ỳ', 'y', COMBINING_GRAVE),
+    ('ý', 'y', COMBINING_ACUTE),
+    ('ÿ', 'y', COMBINING_DIAERESIS),
+];
+
+/// Latin letters and the Cyrillic letters drawn identically
+const HOMOGLYPHS: &[(char, char)] = &[
+    ('a', 'а'),
+    ('c', 'с'),
+    ('e', 'е'),
+    ('i', 'і'),
+    ('o', 'о'),
+    ('p', 'р'),
+    ('s', 'ѕ'),
+    ('x', 'х'),
+    ('y', 'у'),
+    ('A', 'А'),
+    ('B', 'В'),
+    ('C', 'С'),
+    ('E', 'Е'),
+    ('H', 'Н'),
+    ('I', 'І'),
+    ('K', 'К'),
+    ('M', 'М'),
+    ('O', 'О'),
+    ('P', 'Р'),
+    ('S', 'Ѕ'),
+    ('T', 'Т'),
+    ('X', 'Х'),
+];
+
+/// One way of stressing a string's Unicode handling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnicodeKind {
+    /// An unaccented letter given a precomposed accent: "Jose" -> "Josè"
+    Accent,
+    /// Precomposed letters decomposed into base letter and combining mark
+    /// (NFC -> NFD), or a combining accent added to a plain letter
+    Decomposed,
+    /// A zero-width space inside the value
+    ZeroWidthSpace,
+    /// A space replaced by a no-break space
+    NonBreakingSpace,
+    /// Straight quotes and apostrophes curled: "O'Brien" -> "O’Brien"
+    SmartQuotes,
+    /// ASCII digits replaced by full-width digits: "123" -> "１２３"
+    FullWidthDigits,
+    /// A Latin letter replaced by its Cyrillic look-alike
+    Homoglyph,
+}
+
+impl UnicodeKind {
+    pub const ALL: [UnicodeKind; 7] = [
+        UnicodeKind::Accent,
+        UnicodeKind::Decomposed,
+        UnicodeKind::ZeroWidthSpace,
+        UnicodeKind::NonBreakingSpace,
+        UnicodeKind::SmartQuotes,
+        UnicodeKind::FullWidthDigits,
+        UnicodeKind::Homoglyph,
+    ];
+
+    /// The ground-truth label, e.g. `unicode:nfd`
+    pub fn label(&self) -> &'static str {
+        match self {
+            UnicodeKind::Accent => "unicode:accent",
+            UnicodeKind::Decomposed => "unicode:nfd",
+            UnicodeKind::ZeroWidthSpace => "unicode:zero_width_space",
+            UnicodeKind::NonBreakingSpace => "unicode:nbsp",
+            UnicodeKind::SmartQuotes => "unicode:smart_quotes",
+            UnicodeKind::FullWidthDigits => "unicode:full_width_digits",
+            UnicodeKind::Homoglyph => "unicode:homoglyph",
+        }
+    }
+
+    /// Applies this kind to `value`, or returns `None` if it has nothing to
+    /// act on (e.g. full-width digits in a value without digits).
+    pub fn apply<R: Rng + ?Sized>(&self, value: &str, rng: &mut R) -> Option<String> {
+        let chars: Vec<char> = value.chars().collect();
+        let stressed = match self {
+            UnicodeKind::Accent => {
+                let (i, accented) = choose(&chars, rng, |c| {
+                    let forms: Vec<char> = DECOMPOSITIONS
+                        .iter()
+                        .filter(|(_, base, _)| *base == c.to_ascii_lowercase())
+                        .map(|(composed, _, _)| *composed)
+                        .collect();
+                    (!forms.is_empty()).then_some(forms)
+                })?;
+                let composed = accented[rng.gen_range(0..accented.len())];
+                let mut chars = chars;
+                chars[i] = if chars[i].is_ascii_uppercase() {
+                    composed.to_uppercase().next().unwrap_or(composed)
+                } else {
+                    composed
+                };
+                chars.into_iter().collect()
+            }
+            UnicodeKind::Decomposed => {
+                if chars.iter().any(|&c| decompose(c).is_some()) {
+                    chars
+                        .iter()
+                        .flat_map(|&c| match decompose(c) {
+                            Some((base, mark)) => vec![base, mark],
+                            None => vec![c],
+                        })
+                        .collect()
+                } else {
+                    let (i, _) = choose(&chars, rng, |c| {
+                        matches!(c.to_ascii_lowercase(), 'a' | 'e' | 'i' | 'o' | 'u').then_some(())
+                    })?;
+                    let mut chars = chars;
+                    chars.insert(i + 1, COMBINING_ACUTE);
+                    chars.into_iter().collect()
+                }
+            }
+            UnicodeKind::ZeroWidthSpace => {
+                if chars.len() < 2 {
+                    return None;
+                }
+                let mut chars = chars;
+                let i = rng.gen_range(1..chars.len());
+                chars.insert(i, ZERO_WIDTH_SPACE);
+                chars.into_iter().collect()
+            }
+            UnicodeKind::NonBreakingSpace => {
+                let (i, _) = choose(&chars, rng, |c| (c == ' ').then_some(()))?;
+                let mut chars = chars;
+                chars[i] = NO_BREAK_SPACE;
+                chars.into_iter().collect()
+            }
+            UnicodeKind::SmartQuotes => {
+                if !chars.iter().any(|&c| c == '\'' || c == '"') {
+                    return None;
+                }
+                let mut closing = false;
+                chars
+                    .iter()
+                    .map(|&c| match c {
+                        '\'' => '\u{2019}',
+                        '"' => {
+                            closing = !closing;
+                            if closing {
+                                '\u{201C}'
+                            } else {
+                                '\u{201D}'
+                            }
+                        }
+                        _ => c,
+                    })
+                    .collect()
+            }
+            UnicodeKind::FullWidthDigits => {
+                if !chars.iter().any(char::is_ascii_digit) {
+                    return None;
+                }
+                chars
+                    .iter()
+                    .map(|&c| match c.to_digit(10) {
+                        Some(d) if c.is_ascii_digit() => char::from_u32(0xFF10 + d).unwrap_or(c),
+                        _ => c,
+                    })
+                    .collect()
+            }
+            UnicodeKind::Homoglyph => {
+                let (i, glyph) = choose(&chars, rng, |c| {
+                    HOMOGLYPHS
+                        .iter()
+                        .find(|(latin, _)| *latin == c)
+                        .map(|(_, cyrillic)| *cyrillic)
+                })?;
+                let mut chars = chars;
+                chars[i] = glyph;
+                chars.into_iter().collect()
+            }
+        };
+        Some(stressed)
+    }
+}
+
+/// The canonical decomposition of a precomposed letter, either case.
+fn decompose(c: char) -> Option<(char, char)> {
+    if c.is_ascii() {
+        return None;
+    }
+    let lower = c.to_lowercase().next()?;
+    let &(_, base, mark) = DECOMPOSITIONS
+        .iter()
+        .find(|(composed, _, _)| *composed == lower)?;
+    let base = if c.is_uppercase() {
+        base.to_ascii_uppercase()
+    } else {
+        base
+    };
+    Some((base, mark))
+}
+
+/// Picks a random position whose character `select` accepts, returning it
+/// with what `select` returned for it.
+fn choose<T, R: Rng + ?Sized>(
+    chars: &[char],
+    rng: &mut R,
+    select: impl Fn(char) -> Option<T>,
+) -> Option<(usize, T)> {
+    let mut candidates: Vec<(usize, T)> = chars
+        .iter()
+        .enumerate()
+        .filter_map(|(i, &c)| select(c).map(|t| (i, t)))
+        .collect();
+    if candidates.is_empty() {
+        None
+    } else {
+        let pick = rng.gen_range(0..candidates.len());
+        Some(candidates.swap_remove(pick))
+    }
+}
+
+/// Applies a random applicable [`UnicodeKind`] to a random one of
+/// `fields`, returning its label, or `None` if no kind applies to any of
+/// them.
+pub fn stress<R: Rng + ?Sized>(fields: &mut [&mut String], rng: &mut R) -> Option<&'static str> {
+    let mut options: Vec<(usize, UnicodeKind)> = Vec::new();
+    for (i, field) in fields.iter().enumerate() {
+        for kind in UnicodeKind::ALL {
+            if applies(kind, field) {
+                options.push((i, kind));
+            }
+        }
+    }
+    if options.is_empty() {
+        return None;
+    }
+
+    let (i, kind) = options[rng.gen_range(0..options.len())];
+    let stressed = kind.apply(fields[i], rng)?;
+    *fields[i] = stressed;
+    Some(kind.label())
+}
+
+/// Whether `kind` has anything to act on in `value`.
+fn applies(kind: UnicodeKind, value: &str) -> bool {
+    match kind {
+        UnicodeKind::Accent => value.chars().any(|c| {
+            DECOMPOSITIONS
+                .iter()
+                .any(|(_, base, _)| *base == c.to_ascii_lowercase())
+        }),
+        UnicodeKind::Decomposed => value.chars().any(|c| {
+            decompose(c).is_some() || matches!(c.to_ascii_lowercase(), 'a' | 'e' | 'i' | 'o' | 'u')
+        }),
+        UnicodeKind::ZeroWidthSpace => value.chars().nth(1).is_some(),
+        UnicodeKind::NonBreakingSpace => value.contains(' '),
+        UnicodeKind::SmartQuotes => value.contains(['\'', '"']),
+        UnicodeKind::FullWidthDigits => value.chars().any(|c| c.is_ascii_digit()),
+        UnicodeKind::Homoglyph => value
+            .chars()
+            .any(|c| HOMOGLYPHS.iter().any(|(latin, _)| *latin == c)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn apply(kind: UnicodeKind, value: &str) -> Option<String> {
+        kind.apply(value, &mut StdRng::seed_from_u64(5))
+    }
+
+    #[test]
+    fn test_decomposed_splits_precomposed_letters() {
+        assert_eq!(
+            apply(UnicodeKind::Decomposed, "José Ñúñez").unwrap(),
+            "Jose\u{301} N\u{303}u\u{301}n\u{303}ez"
+        );
+        let added = apply(UnicodeKind::Decomposed, "Main").unwrap();
+        assert_eq!(added.chars().count(), 5);
+        assert!(added.contains(COMBINING_ACUTE));
+        assert_eq!(apply(UnicodeKind::Decomposed, "123"), None);
+    }
+
+    #[test]
+    fn test_accent_keeps_case() {
+        let accented = apply(UnicodeKind::Accent, "ANN").unwrap();
+        assert_eq!(accented.chars().count(), 3);
+        assert!(accented.chars().all(|c| !c.is_lowercase()), "{}", accented);
+        assert_ne!(accented, "ANN");
+    }
+
+    #[test]
+    fn test_spaces_and_quotes() {
+        let zwsp = apply(UnicodeKind::ZeroWidthSpace, "Ann").unwrap();
+        assert_eq!(zwsp.replace(ZERO_WIDTH_SPACE, ""), "Ann");
+        assert!(!zwsp.starts_with(ZERO_WIDTH_SPACE));
+        assert_eq!(apply(UnicodeKind::ZeroWidthSpace, "A"), None);
+
+        assert_eq!(
+            apply(UnicodeKind::NonBreakingSpace, "Main St").unwrap(),
+            "Main\u{A0}St"
+        );
+        assert_eq!(apply(UnicodeKind::NonBreakingSpace, "Main"), None);
+
+        assert_eq!(
+            apply(UnicodeKind::SmartQuotes, "O'Brien \"Bob\"").unwrap(),
+            "O\u{2019}Brien \u{201C}Bob\u{201D}"
+        );
+        assert_eq!(apply(UnicodeKind::SmartQuotes, "Smith"), None);
+    }
+
+    #[test]
+    fn test_full_width_digits_and_homoglyphs() {
+        assert_eq!(
+            apply(UnicodeKind::FullWidthDigits, "123 Main St").unwrap(),
+            "１２３ Main St"
+        );
+        assert_eq!(apply(UnicodeKind::FullWidthDigits, "Main"), None);
+
+        let glyph = apply(UnicodeKind::Homoglyph, "Oak").unwrap();
+        assert_ne!(glyph, "Oak");
+        assert_eq!(glyph.chars().count(), 3);
+        assert!(!glyph.is_ascii());
+        assert_eq!(apply(UnicodeKind::Homoglyph, "123"), None);
+    }
+
+    #[test]
+    fn test_stress_picks_an_applicable_kind() {
+        let mut rng = StdRng::seed_from_u64(1);
+        for _ in 0..50 {
+            let mut empty = String::new();
+            let mut digits = "62701".to_string();
+            let label = stress(&mut [&mut empty, &mut digits], &mut rng).unwrap();
+            assert!(
+                ["unicode:zero_width_space", "unicode:full_width_digits"].contains(&label),
+                "{}",
+                label
+            );
+            assert!(empty.is_empty());
+            assert_ne!(digits, "62701");
+        }
+        assert_eq!(stress(&mut [&mut String::new()], &mut rng), None);
+    }
+}
@@ -0,0 +1,632 @@
+//! Bundled ZIP code reference table for geographically consistent addresses.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::regions::{Country, ALL_STATES};
+
+/// A ZIP code together with the city and state it belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZipLocation {
+    pub zip: &'static str,
+    pub city: &'static str,
+    pub state: &'static str,
+}
+
+impl ZipLocation {
+    const fn new(zip: &'static str, city: &'static str, state: &'static str) -> Self {
+        Self { zip, city, state }
+    }
+}
+
+/// Real ZIP/city/state combinations, grouped by state in the order of
+/// [`ALL_STATES`]. Every state code in `ALL_STATES` has at least one entry.
+pub const ZIP_LOCATIONS: &[ZipLocation] = &[
+    ZipLocation::new("99501", "Anchorage", "AK"),
+    ZipLocation::new("99701", "Fairbanks", "AK"),
+    ZipLocation::new("99801", "Juneau", "AK"),
+    ZipLocation::new("99654", "Wasilla", "AK"),
+    ZipLocation::new("99835", "Sitka", "AK"),
+    ZipLocation::new("35203", "Birmingham", "AL"),
+    ZipLocation::new("36104", "Montgomery", "AL"),
+    ZipLocation::new("36602", "Mobile", "AL"),
+    ZipLocation::new("35801", "Huntsville", "AL"),
+    ZipLocation::new("35401", "Tuscaloosa", "AL"),
+    ZipLocation::new("72201", "Little Rock", "AR"),
+    ZipLocation::new("72701", "Fayetteville", "AR"),
+    ZipLocation::new("72901", "Fort Smith", "AR"),
+    ZipLocation::new("72401", "Jonesboro", "AR"),
+    ZipLocation::new("72032", "Conway", "AR"),
+    ZipLocation::new("85004", "Phoenix", "AZ"),
+    ZipLocation::new("85701", "Tucson", "AZ"),
+    ZipLocation::new("85201", "Mesa", "AZ"),
+    ZipLocation::new("86001", "Flagstaff", "AZ"),
+    ZipLocation::new("85251", "Scottsdale", "AZ"),
+    ZipLocation::new("90012", "Los Angeles", "CA"),
+    ZipLocation::new("94102", "San Francisco", "CA"),
+    ZipLocation::new("92101", "San Diego", "CA"),
+    ZipLocation::new("95814", "Sacramento", "CA"),
+    ZipLocation::new("93721", "Fresno", "CA"),
+    ZipLocation::new("94612", "Oakland", "CA"),
+    ZipLocation::new("80202", "Denver", "CO"),
+    ZipLocation::new("80903", "Colorado Springs", "CO"),
+    ZipLocation::new("80302", "Boulder", "CO"),
+    ZipLocation::new("80524", "Fort Collins", "CO"),
+    ZipLocation::new("81003", "Pueblo", "CO"),
+    ZipLocation::new("06103", "Hartford", "CT"),
+    ZipLocation::new("06510", "New Haven", "CT"),
+    ZipLocation::new("06901", "Stamford", "CT"),
+    ZipLocation::new("06604", "Bridgeport", "CT"),
+    ZipLocation::new("06702", "Waterbury", "CT"),
+    ZipLocation::new("20001", "Washington", "DC"),
+    ZipLocation::new("20002", "Washington", "DC"),
+    ZipLocation::new("20004", "Washington", "DC"),
+    ZipLocation::new("20009", "Washington", "DC"),
+    ZipLocation::new("20016", "Washington", "DC"),
+    ZipLocation::new("19801", "Wilmington", "DE"),
+    ZipLocation::new("19901", "Dover", "DE"),
+    ZipLocation::new("19711", "Newark", "DE"),
+    ZipLocation::new("19958", "Lewes", "DE"),
+    ZipLocation::new("19709", "Middletown", "DE"),
+    ZipLocation::new("33130", "Miami", "FL"),
+    ZipLocation::new("32801", "Orlando", "FL"),
+    ZipLocation::new("33602", "Tampa", "FL"),
+    ZipLocation::new("32202", "Jacksonville", "FL"),
+    ZipLocation::new("32301", "Tallahassee", "FL"),
+    ZipLocation::new("30303", "Atlanta", "GA"),
+    ZipLocation::new("31401", "Savannah", "GA"),
+    ZipLocation::new("30901", "Augusta", "GA"),
+    ZipLocation::new("31201", "Macon", "GA"),
+    ZipLocation::new("30601", "Athens", "GA"),
+    ZipLocation::new("96813", "Honolulu", "HI"),
+    ZipLocation::new("96720", "Hilo", "HI"),
+    ZipLocation::new("96734", "Kailua", "HI"),
+    ZipLocation::new("96761", "Lahaina", "HI"),
+    ZipLocation::new("96707", "Kapolei", "HI"),
+    ZipLocation::new("50309", "Des Moines", "IA"),
+    ZipLocation::new("52401", "Cedar Rapids", "IA"),
+    ZipLocation::new("52801", "Davenport", "IA"),
+    ZipLocation::new("52240", "Iowa City", "IA"),
+    ZipLocation::new("51101", "Sioux City", "IA"),
+    ZipLocation::new("83702", "Boise", "ID"),
+    ZipLocation::new("83402", "Idaho Falls", "ID"),
+    ZipLocation::new("83201", "Pocatello", "ID"),
+    ZipLocation::new("83814", "Coeur d'Alene", "ID"),
+    ZipLocation::new("83651", "Nampa", "ID"),
+    ZipLocation::new("60601", "Chicago", "IL"),
+    ZipLocation::new("62701", "Springfield", "IL"),
+    ZipLocation::new("61602", "Peoria", "IL"),
+    ZipLocation::new("61101", "Rockford", "IL"),
+    ZipLocation::new("60540", "Naperville", "IL"),
+    ZipLocation::new("46204", "Indianapolis", "IN"),
+    ZipLocation::new("46802", "Fort Wayne", "IN"),
+    ZipLocation::new("47708", "Evansville", "IN"),
+    ZipLocation::new("46601", "South Bend", "IN"),
+    ZipLocation::new("47401", "Bloomington", "IN"),
+    ZipLocation::new("67202", "Wichita", "KS"),
+    ZipLocation::new("66603", "Topeka", "KS"),
+    ZipLocation::new("66101", "Kansas City", "KS"),
+    ZipLocation::new("66044", "Lawrence", "KS"),
+    ZipLocation::new("66210", "Overland Park", "KS"),
+    ZipLocation::new("40202", "Louisville", "KY"),
+    ZipLocation::new("40507", "Lexington", "KY"),
+    ZipLocation::new("40601", "Frankfort", "KY"),
+    ZipLocation::new("42101", "Bowling Green", "KY"),
+    ZipLocation::new("41011", "Covington", "KY"),
+    ZipLocation::new("70112", "New Orleans", "LA"),
+    ZipLocation::new("70801", "Baton Rouge", "LA"),
+    ZipLocation::new("71101", "Shreveport", "LA"),
+    ZipLocation::new("70501", "Lafayette", "LA"),
+    ZipLocation::new("70601", "Lake Charles", "LA"),
+    ZipLocation::new("02108", "Boston", "MA"),
+    ZipLocation::new("01608", "Worcester", "MA"),
+    ZipLocation::new("01103", "Springfield", "MA"),
+    ZipLocation::new("02139", "Cambridge", "MA"),
+    ZipLocation::new("01852", "Lowell", "MA"),
+    ZipLocation::new("21202", "Baltimore", "MD"),
+    ZipLocation::new("21401", "Annapolis", "MD"),
+    ZipLocation::new("21701", "Frederick", "MD"),
+    ZipLocation::new("20850", "Rockville", "MD"),
+    ZipLocation::new("21740", "Hagerstown", "MD"),
+    ZipLocation::new("04101", "Portland", "ME"),
+    ZipLocation::new("04401", "Bangor", "ME"),
+    ZipLocation::new("04330", "Augusta", "ME"),
+    ZipLocation::new("04240", "Lewiston", "ME"),
+    ZipLocation::new("04005", "Biddeford", "ME"),
+    ZipLocation::new("48226", "Detroit", "MI"),
+    ZipLocation::new("49503", "Grand Rapids", "MI"),
+    ZipLocation::new("48933", "Lansing", "MI"),
+    ZipLocation::new("48104", "Ann Arbor", "MI"),
+    ZipLocation::new("48502", "Flint", "MI"),
+    ZipLocation::new("55401", "Minneapolis", "MN"),
+    ZipLocation::new("55102", "Saint Paul", "MN"),
+    ZipLocation::new("55802", "Duluth", "MN"),
+    ZipLocation::new("55902", "Rochester", "MN"),
+    ZipLocation::new("55420", "Bloomington", "MN"),
+    ZipLocation::new("63101", "Saint Louis", "MO"),
+    ZipLocation::new("64105", "Kansas City", "MO"),
+    ZipLocation::new("65806", "Springfield", "MO"),
+    ZipLocation::new("65201", "Columbia", "MO"),
+    ZipLocation::new("65101", "Jefferson City", "MO"),
+    ZipLocation::new("39201", "Jackson", "MS"),
+    ZipLocation::new("39501", "Gulfport", "MS"),
+    ZipLocation::new("39401", "Hattiesburg", "MS"),
+    ZipLocation::new("39530", "Biloxi", "MS"),
+    ZipLocation::new("38804", "Tupelo", "MS"),
+    ZipLocation::new("59101", "Billings", "MT"),
+    ZipLocation::new("59802", "Missoula", "MT"),
+    ZipLocation::new("59601", "Helena", "MT"),
+    ZipLocation::new("59715", "Bozeman", "MT"),
+    ZipLocation::new("59401", "Great Falls", "MT"),
+    ZipLocation::new("28202", "Charlotte", "NC"),
+    ZipLocation::new("27601", "Raleigh", "NC"),
+    ZipLocation::new("27401", "Greensboro", "NC"),
+    ZipLocation::new("27701", "Durham", "NC"),
+    ZipLocation::new("28801", "Asheville", "NC"),
+    ZipLocation::new("58102", "Fargo", "ND"),
+    ZipLocation::new("58501", "Bismarck", "ND"),
+    ZipLocation::new("58201", "Grand Forks", "ND"),
+    ZipLocation::new("58701", "Minot", "ND"),
+    ZipLocation::new("58801", "Williston", "ND"),
+    ZipLocation::new("68102", "Omaha", "NE"),
+    ZipLocation::new("68508", "Lincoln", "NE"),
+    ZipLocation::new("68801", "Grand Island", "NE"),
+    ZipLocation::new("68847", "Kearney", "NE"),
+    ZipLocation::new("69101", "North Platte", "NE"),
+    ZipLocation::new("03101", "Manchester", "NH"),
+    ZipLocation::new("03301", "Concord", "NH"),
+    ZipLocation::new("03060", "Nashua", "NH"),
+    ZipLocation::new("03801", "Portsmouth", "NH"),
+    ZipLocation::new("03431", "Keene", "NH"),
+    ZipLocation::new("07102", "Newark", "NJ"),
+    ZipLocation::new("07302", "Jersey City", "NJ"),
+    ZipLocation::new("08608", "Trenton", "NJ"),
+    ZipLocation::new("08102", "Camden", "NJ"),
+    ZipLocation::new("07505", "Paterson", "NJ"),
+    ZipLocation::new("87102", "Albuquerque", "NM"),
+    ZipLocation::new("87501", "Santa Fe", "NM"),
+    ZipLocation::new("88001", "Las Cruces", "NM"),
+    ZipLocation::new("88201", "Roswell", "NM"),
+    ZipLocation::new("87401", "Farmington", "NM"),
+    ZipLocation::new("89101", "Las Vegas", "NV"),
+    ZipLocation::new("89501", "Reno", "NV"),
+    ZipLocation::new("89002", "Henderson", "NV"),
+    ZipLocation::new("89701", "Carson City", "NV"),
+    ZipLocation::new("89801", "Elko", "NV"),
+    ZipLocation::new("10001", "New York", "NY"),
+    ZipLocation::new("14202", "Buffalo", "NY"),
+    ZipLocation::new("14604", "Rochester", "NY"),
+    ZipLocation::new("12207", "Albany", "NY"),
+    ZipLocation::new("13202", "Syracuse", "NY"),
+    ZipLocation::new("43215", "Columbus", "OH"),
+    ZipLocation::new("44113", "Cleveland", "OH"),
+    ZipLocation::new("45202", "Cincinnati", "OH"),
+    ZipLocation::new("43604", "Toledo", "OH"),
+    ZipLocation::new("44308", "Akron", "OH"),
+    ZipLocation::new("73102", "Oklahoma City", "OK"),
+    ZipLocation::new("74103", "Tulsa", "OK"),
+    ZipLocation::new("73069", "Norman", "OK"),
+    ZipLocation::new("73501", "Lawton", "OK"),
+    ZipLocation::new("74074", "Stillwater", "OK"),
+    ZipLocation::new("97204", "Portland", "OR"),
+    ZipLocation::new("97301", "Salem", "OR"),
+    ZipLocation::new("97401", "Eugene", "OR"),
+    ZipLocation::new("97701", "Bend", "OR"),
+    ZipLocation::new("97501", "Medford", "OR"),
+    ZipLocation::new("19102", "Philadelphia", "PA"),
+    ZipLocation::new("15222", "Pittsburgh", "PA"),
+    ZipLocation::new("17101", "Harrisburg", "PA"),
+    ZipLocation::new("18101", "Allentown", "PA"),
+    ZipLocation::new("16501", "Erie", "PA"),
+    ZipLocation::new("02903", "Providence", "RI"),
+    ZipLocation::new("02886", "Warwick", "RI"),
+    ZipLocation::new("02910", "Cranston", "RI"),
+    ZipLocation::new("02840", "Newport", "RI"),
+    ZipLocation::new("02860", "Pawtucket", "RI"),
+    ZipLocation::new("29201", "Columbia", "SC"),
+    ZipLocation::new("29401", "Charleston", "SC"),
+    ZipLocation::new("29601", "Greenville", "SC"),
+    ZipLocation::new("29301", "Spartanburg", "SC"),
+    ZipLocation::new("29577", "Myrtle Beach", "SC"),
+    ZipLocation::new("57104", "Sioux Falls", "SD"),
+    ZipLocation::new("57701", "Rapid City", "SD"),
+    ZipLocation::new("57501", "Pierre", "SD"),
+    ZipLocation::new("57401", "Aberdeen", "SD"),
+    ZipLocation::new("57006", "Brookings", "SD"),
+    ZipLocation::new("37203", "Nashville", "TN"),
+    ZipLocation::new("38103", "Memphis", "TN"),
+    ZipLocation::new("37902", "Knoxville", "TN"),
+    ZipLocation::new("37402", "Chattanooga", "TN"),
+    ZipLocation::new("37040", "Clarksville", "TN"),
+    ZipLocation::new("77002", "Houston", "TX"),
+    ZipLocation::new("75201", "Dallas", "TX"),
+    ZipLocation::new("78701", "Austin", "TX"),
+    ZipLocation::new("78205", "San Antonio", "TX"),
+    ZipLocation::new("79901", "El Paso", "TX"),
+    ZipLocation::new("84111", "Salt Lake City", "UT"),
+    ZipLocation::new("84601", "Provo", "UT"),
+    ZipLocation::new("84401", "Ogden", "UT"),
+    ZipLocation::new("84321", "Logan", "UT"),
+    ZipLocation::new("84770", "Saint George", "UT"),
+    ZipLocation::new("23219", "Richmond", "VA"),
+    ZipLocation::new("23451", "Virginia Beach", "VA"),
+    ZipLocation::new("23510", "Norfolk", "VA"),
+    ZipLocation::new("22201", "Arlington", "VA"),
+    ZipLocation::new("24011", "Roanoke", "VA"),
+    ZipLocation::new("05401", "Burlington", "VT"),
+    ZipLocation::new("05602", "Montpelier", "VT"),
+    ZipLocation::new("05701", "Rutland", "VT"),
+    ZipLocation::new("05301", "Brattleboro", "VT"),
+    ZipLocation::new("05201", "Bennington", "VT"),
+    ZipLocation::new("98101", "Seattle", "WA"),
+    ZipLocation::new("99201", "Spokane", "WA"),
+    ZipLocation::new("98402", "Tacoma", "WA"),
+    ZipLocation::new("98501", "Olympia", "WA"),
+    ZipLocation::new("98225", "Bellingham", "WA"),
+    ZipLocation::new("53202", "Milwaukee", "WI"),
+    ZipLocation::new("53703", "Madison", "WI"),
+    ZipLocation::new("54301", "Green Bay", "WI"),
+    ZipLocation::new("53140", "Kenosha", "WI"),
+    ZipLocation::new("54601", "La Crosse", "WI"),
+    ZipLocation::new("25301", "Charleston", "WV"),
+    ZipLocation::new("25701", "Huntington", "WV"),
+    ZipLocation::new("26505", "Morgantown", "WV"),
+    ZipLocation::new("26003", "Wheeling", "WV"),
+    ZipLocation::new("26101", "Parkersburg", "WV"),
+    ZipLocation::new("82001", "Cheyenne", "WY"),
+    ZipLocation::new("82601", "Casper", "WY"),
+    ZipLocation::new("82070", "Laramie", "WY"),
+    ZipLocation::new("82716", "Gillette", "WY"),
+    ZipLocation::new("83001", "Jackson", "WY"),
+];
+
+/// Returns every bundled location for a state code (case-insensitive).
+///
+/// # Examples
+/// ```
+/// use rust_faker::geography::locations_for_state;
+///
+/// let illinois = locations_for_state("il");
+/// assert!(illinois.iter().any(|loc| loc.city == "Chicago"));
+/// assert!(locations_for_state("ZZ").is_empty());
+/// ```
+pub fn locations_for_state(state: &str) -> Vec<&'static ZipLocation> {
+    let state_upper = state.to_uppercase();
+    ZIP_LOCATIONS
+        .iter()
+        .filter(|loc| loc.state == state_upper)
+        .collect()
+}
+
+/// Picks a location in a random state, each state in [`ALL_STATES`] being
+/// equally likely.
+pub fn random_location<R: Rng + ?Sized>(rng: &mut R) -> &'static ZipLocation {
+    let state = ALL_STATES[rng.gen_range(0..ALL_STATES.len())];
+    random_location_in_state(state, rng).expect("every state has bundled locations")
+}
+
+/// Picks a random location within a state, or `None` for an unknown state.
+pub fn random_location_in_state<R: Rng + ?Sized>(
+    state: &str,
+    rng: &mut R,
+) -> Option<&'static ZipLocation> {
+    locations_for_state(state).choose(rng).copied()
+}
+
+/// The bundled location in `state` whose ZIP is numerically closest to
+/// `zip` without being it, or the closest in any state when `state` has no
+/// other bundled ZIP. A ZIP+4 is matched on its first five digits; `None`
+/// if `zip` does not start with five digits.
+///
+/// # Examples
+/// ```
+/// use rust_faker::geography::neighboring_zip;
+///
+/// let neighbor = neighboring_zip("60601-1234", "IL").unwrap();
+/// assert_ne!(neighbor.zip, "60601");
+/// assert_eq!(neighbor.state, "IL");
+/// ```
+pub fn neighboring_zip(zip: &str, state: &str) -> Option<&'static ZipLocation> {
+    let five = zip
+        .get(..5)
+        .filter(|z| z.chars().all(|c| c.is_ascii_digit()))?;
+    let target: u32 = five.parse().ok()?;
+    let nearest = |in_state: bool| {
+        ZIP_LOCATIONS
+            .iter()
+            .filter(|loc| loc.zip != five)
+            .filter(|loc| !in_state || loc.state.eq_ignore_ascii_case(state))
+            .min_by_key(|loc| {
+                loc.zip
+                    .parse::<u32>()
+                    .map_or(u32::MAX, |z| z.abs_diff(target))
+            })
+    };
+    nearest(true).or_else(|| nearest(false))
+}
+
+/// A city outside the US with its region and postal codes.
+///
+/// For Canada and the UK, `postal_codes` holds the outward half of the code
+/// (`M5V`, `SW1A`) and the inward half is generated; German and Mexican
+/// entries are complete codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorldLocation {
+    pub country: Country,
+    pub city: &'static str,
+    /// The region's code (`ON`, `BY`, `JAL`), or its name for UK counties
+    pub region: &'static str,
+    pub postal_codes: &'static [&'static str],
+}
+
+impl WorldLocation {
+    const fn new(
+        country: Country,
+        city: &'static str,
+        region: &'static str,
+        postal_codes: &'static [&'static str],
+    ) -> Self {
+        Self {
+            country,
+            city,
+            region,
+            postal_codes,
+        }
+    }
+}
+
+/// Real city/region/postal-code combinations for supported countries
+/// other than the US, which uses [`ZIP_LOCATIONS`].
+pub const WORLD_LOCATIONS: &[WorldLocation] = &[
+    WorldLocation::new(
+        Country::Canada,
+        "Toronto",
+        "ON",
+        &["M5V", "M4C", "M6G", "M5A"],
+    ),
+    WorldLocation::new(Country::Canada, "Ottawa", "ON", &["K1P", "K2P"]),
+    WorldLocation::new(Country::Canada, "Montréal", "QC", &["H2X", "H3B", "H1A"]),
+    WorldLocation::new(Country::Canada, "Québec", "QC", &["G1R", "G1K"]),
+    WorldLocation::new(Country::Canada, "Vancouver", "BC", &["V6B", "V5K", "V6Z"]),
+    WorldLocation::new(Country::Canada, "Calgary", "AB", &["T2P", "T3A"]),
+    WorldLocation::new(Country::Canada, "Edmonton", "AB", &["T5J", "T6E"]),
+    WorldLocation::new(Country::Canada, "Winnipeg", "MB", &["R3C", "R2W"]),
+    WorldLocation::new(Country::Canada, "Regina", "SK", &["S4P"]),
+    WorldLocation::new(Country::Canada, "Saskatoon", "SK", &["S7K"]),
+    WorldLocation::new(Country::Canada, "Halifax", "NS", &["B3H", "B3J"]),
+    WorldLocation::new(Country::Canada, "Fredericton", "NB", &["E3B"]),
+    WorldLocation::new(Country::Canada, "St. John's", "NL", &["A1C"]),
+    WorldLocation::new(Country::Canada, "Charlottetown", "PE", &["C1A"]),
+    WorldLocation::new(Country::Canada, "Whitehorse", "YT", &["Y1A"]),
+    WorldLocation::new(Country::Canada, "Yellowknife", "NT", &["X1A"]),
+    WorldLocation::new(Country::Canada, "Iqaluit", "NU", &["X0A"]),
+    WorldLocation::new(
+        Country::UnitedKingdom,
+        "London",
+        "Greater London",
+        &["SW1A", "EC1A", "W1D", "N1", "SE1", "E1"],
+    ),
+    WorldLocation::new(
+        Country::UnitedKingdom,
+        "Manchester",
+        "Greater Manchester",
+        &["M1", "M4", "M13"],
+    ),
+    WorldLocation::new(
+        Country::UnitedKingdom,
+        "Birmingham",
+        "West Midlands",
+        &["B1", "B5", "B15"],
+    ),
+    WorldLocation::new(
+        Country::UnitedKingdom,
+        "Leeds",
+        "West Yorkshire",
+        &["LS1", "LS6"],
+    ),
+    WorldLocation::new(
+        Country::UnitedKingdom,
+        "Liverpool",
+        "Merseyside",
+        &["L1", "L8"],
+    ),
+    WorldLocation::new(
+        Country::UnitedKingdom,
+        "Bristol",
+        "Bristol",
+        &["BS1", "BS8"],
+    ),
+    WorldLocation::new(
+        Country::UnitedKingdom,
+        "Newcastle upon Tyne",
+        "Tyne and Wear",
+        &["NE1", "NE4"],
+    ),
+    WorldLocation::new(
+        Country::UnitedKingdom,
+        "Edinburgh",
+        "City of Edinburgh",
+        &["EH1", "EH3"],
+    ),
+    WorldLocation::new(
+        Country::UnitedKingdom,
+        "Glasgow",
+        "Glasgow City",
+        &["G1", "G12"],
+    ),
+    WorldLocation::new(
+        Country::UnitedKingdom,
+        "Cardiff",
+        "Cardiff",
+        &["CF10", "CF24"],
+    ),
+    WorldLocation::new(
+        Country::UnitedKingdom,
+        "Belfast",
+        "County Antrim",
+        &["BT1", "BT7"],
+    ),
+    WorldLocation::new(
+        Country::UnitedKingdom,
+        "Oxford",
+        "Oxfordshire",
+        &["OX1", "OX4"],
+    ),
+    WorldLocation::new(
+        Country::UnitedKingdom,
+        "Cambridge",
+        "Cambridgeshire",
+        &["CB1", "CB2"],
+    ),
+    WorldLocation::new(
+        Country::Germany,
+        "Berlin",
+        "BE",
+        &["10115", "10178", "10785", "12043", "13353"],
+    ),
+    WorldLocation::new(
+        Country::Germany,
+        "Hamburg",
+        "HH",
+        &["20095", "20354", "22767"],
+    ),
+    WorldLocation::new(
+        Country::Germany,
+        "München",
+        "BY",
+        &["80331", "80469", "81667"],
+    ),
+    WorldLocation::new(Country::Germany, "Nürnberg", "BY", &["90402", "90461"]),
+    WorldLocation::new(Country::Germany, "Köln", "NW", &["50667", "50823"]),
+    WorldLocation::new(Country::Germany, "Düsseldorf", "NW", &["40210", "40477"]),
+    WorldLocation::new(
+        Country::Germany,
+        "Frankfurt am Main",
+        "HE",
+        &["60311", "60487"],
+    ),
+    WorldLocation::new(Country::Germany, "Stuttgart", "BW", &["70173", "70597"]),
+    WorldLocation::new(Country::Germany, "Leipzig", "SN", &["04103", "04275"]),
+    WorldLocation::new(Country::Germany, "Dresden", "SN", &["01067", "01307"]),
+    WorldLocation::new(Country::Germany, "Hannover", "NI", &["30159", "30175"]),
+    WorldLocation::new(Country::Germany, "Bremen", "HB", &["28195", "28203"]),
+    WorldLocation::new(
+        Country::Mexico,
+        "Ciudad de México",
+        "CMX",
+        &["06000", "06700", "03100", "11560"],
+    ),
+    WorldLocation::new(
+        Country::Mexico,
+        "Guadalajara",
+        "JAL",
+        &["44100", "44160", "44600"],
+    ),
+    WorldLocation::new(Country::Mexico, "Monterrey", "NLE", &["64000", "64620"]),
+    WorldLocation::new(Country::Mexico, "Puebla", "PUE", &["72000", "72160"]),
+    WorldLocation::new(Country::Mexico, "Mérida", "YUC", &["97000", "97070"]),
+    WorldLocation::new(Country::Mexico, "Querétaro", "QUE", &["76000"]),
+    WorldLocation::new(Country::Mexico, "León", "GUA", &["37000"]),
+    WorldLocation::new(Country::Mexico, "Tijuana", "BCN", &["22000"]),
+    WorldLocation::new(Country::Mexico, "Cancún", "ROO", &["77500"]),
+    WorldLocation::new(Country::Mexico, "Oaxaca", "OAX", &["68000"]),
+    WorldLocation::new(Country::Mexico, "Morelia", "MIC", &["58000"]),
+    WorldLocation::new(Country::Mexico, "Toluca", "MEX", &["50000"]),
+];
+
+/// Picks a random bundled location in a country other than the US, or
+/// `None` for the US (see [`random_location`]).
+pub fn random_world_location<R: Rng + ?Sized>(
+    country: Country,
+    rng: &mut R,
+) -> Option<&'static WorldLocation> {
+    let locations: Vec<&WorldLocation> = WORLD_LOCATIONS
+        .iter()
+        .filter(|loc| loc.country == country)
+        .collect();
+    locations.choose(rng).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_every_state_has_locations() {
+        for state in &ALL_STATES {
+            assert!(
+                !locations_for_state(state).is_empty(),
+                "State {} should have bundled ZIP codes",
+                state
+            );
+        }
+    }
+
+    #[test]
+    fn test_neighboring_zip() {
+        let neighbor = neighboring_zip("02108", "MA").unwrap();
+        assert_eq!((neighbor.zip, neighbor.city), ("02139", "Cambridge"));
+        assert_eq!(neighboring_zip("02139-4307", "ma").unwrap().zip, "02108");
+        assert!(neighboring_zip("2108", "MA").is_none());
+        assert!(neighboring_zip("", "MA").is_none());
+    }
+
+    #[test]
+    fn test_neighboring_zip_prefers_same_state() {
+        // 02860 (Pawtucket, RI) is numerically closer to 02840 than any
+        // Massachusetts ZIP, but a Massachusetts address stays in state.
+        assert_eq!(neighboring_zip("02840", "RI").unwrap().zip, "02860");
+        assert_eq!(neighboring_zip("02840", "MA").unwrap().state, "MA");
+        // With no other bundled ZIP in the state, fall back to the nearest.
+        assert_eq!(neighboring_zip("02840", "ZZ").unwrap().zip, "02860");
+    }
+
+    #[test]
+    fn test_locations_use_known_states_and_valid_zips() {
+        for loc in ZIP_LOCATIONS {
+            assert!(
+                ALL_STATES.contains(&loc.state),
+                "Unknown state {}",
+                loc.state
+            );
+            assert_eq!(loc.zip.len(), 5, "Bad ZIP {}", loc.zip);
+            assert!(loc.zip.chars().all(|c| c.is_ascii_digit()));
+            assert!(!loc.city.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_random_location_in_state() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let loc = random_location_in_state("tx", &mut rng).unwrap();
+        assert_eq!(loc.state, "TX");
+        assert!(random_location_in_state("ZZ", &mut rng).is_none());
+    }
+
+    #[test]
+    fn test_world_locations_use_known_regions() {
+        for loc in WORLD_LOCATIONS {
+            let subdivisions = loc.country.subdivisions();
+            assert!(
+                subdivisions
+                    .iter()
+                    .any(|(code, name)| *code == loc.region || *name == loc.region),
+                "Unknown region {} for {}",
+                loc.region,
+                loc.city
+            );
+            assert!(!loc.postal_codes.is_empty(), "{}", loc.city);
+        }
+    }
+
+    #[test]
+    fn test_every_other_country_has_world_locations() {
+        let mut rng = StdRng::seed_from_u64(1);
+        for country in Country::ALL {
+            let location = random_world_location(country, &mut rng);
+            assert_eq!(location.is_none(), country == Country::UnitedStates);
+        }
+    }
+}
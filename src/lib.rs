@@ -0,0 +1,28 @@
+//! Test data generation with configurable variance.
+//!
+//! rust-faker generates address and name datasets with intentional
+//! formatting variations for testing data standardization and
+//! deduplication systems. The `rust-faker` binary is a thin CLI over
+//! this library; everything it does is available here.
+//!
+//! # Examples
+//! ```
+//! use rust_faker::generators::names::{generate_names, VariancePattern};
+//! use rust_faker::generators::{create_rng, NamedPattern};
+//!
+//! let mut rng = create_rng(Some(42));
+//! let names = generate_names(10, 0.0, VariancePattern::ALL, &mut rng);
+//! assert_eq!(names.len(), 10);
+//! ```
+
+pub mod cache;
+pub mod download;
+pub mod error;
+pub mod generators;
+pub mod geography;
+pub mod output;
+pub mod profile;
+pub mod regions;
+pub mod writer;
+
+pub use error::{Error, Result};
@@ -1,18 +1,43 @@
-use clap::{Parser, Subcommand};
+use chrono::{Local, NaiveDate};
+use clap::{Args, Parser, Subcommand};
+use std::io;
 use std::process;
 
-mod cache;
-mod download;
-mod generators;
-mod regions;
-mod writer;
-
-use generators::addresses::{
-    apply_variance_to_addresses, generate_addresses, load_addresses_from_cache,
-    load_addresses_from_csv,
+use rand::rngs::StdRng;
+use rand::Rng;
+use rust_faker::generators::addresses::{
+    self, generate_clean_address, sample_addresses, stream_addresses_from_cache, vary_address,
+    Address, AddressReader, LoadWarning,
+};
+use rust_faker::generators::clusters::{generate_clusters, ClusterSizes, Clustered};
+use rust_faker::generators::companies::{
+    self, generate_clean_company, vary_company, CompanyVariance,
+};
+use rust_faker::generators::dates::{
+    self, generate_clean_date, vary_date, AgeDistribution, BirthDates, Date,
+};
+use rust_faker::generators::emails::{self, generate_clean_email, vary_email, Email};
+use rust_faker::generators::international::{
+    self, generate_clean_international_address, vary_international_address,
 };
-use generators::names::generate_names;
-use writer::CsvWriter;
+use rust_faker::generators::locales::{generate_clean_locale_name, NameLocale};
+use rust_faker::generators::names::{self, generate_clean_name, vary_name};
+use rust_faker::generators::parallel::{
+    default_threads, generate_parallel, validate_threads, CHUNK_SIZE,
+};
+use rust_faker::generators::people::{
+    generate_clean_person, vary_person, ContactFields, PersonVariance,
+};
+use rust_faker::generators::phones::{self, generate_clean_phone, vary_phone, Phone};
+use rust_faker::generators::tins::{self, generate_clean_tin, vary_tin, Tin, TinKind};
+use rust_faker::generators::{
+    create_rng, select_patterns, traced, validate_count, validate_error_rate, NamedPattern, Traced,
+};
+use rust_faker::output::{parse_delimiter, OutputFormat, Quoting, STDIO_PATH};
+use rust_faker::profile::{load_profile, ProfileFile, VarianceProfile};
+use rust_faker::regions::Country;
+use rust_faker::writer::{is_stdout_closed, CsvWriter, Record, RecordSink};
+use rust_faker::{cache, download, regions};
 
 #[derive(Parser)]
 #[command(name = "rust-faker")]
@@ -22,6 +47,106 @@ struct Cli {
     command: Commands,
 }
 
+/// Layout flags for CSV and TSV output
+#[derive(Args)]
+#[command(next_help_heading = "CSV Options")]
+struct CsvArgs {
+    /// Field delimiter: a single character, or `tab` (default: `|` for csv, tab for tsv)
+    #[arg(long, value_name = "CHAR", value_parser = parse_delimiter)]
+    delimiter: Option<u8>,
+
+    /// When to quote fields: necessary, always, non-numeric or never
+    #[arg(long, value_name = "STYLE", default_value_t = Quoting::Necessary)]
+    quote: Quoting,
+
+    /// Omit the header row
+    #[arg(long)]
+    no_header: bool,
+
+    /// End lines with CRLF instead of LF
+    #[arg(long)]
+    crlf: bool,
+
+    /// Custom column names (comma-separated, one per column), e.g. ADDR_LINE_1,ADDR_LINE_2,CITY,ST,ZIP
+    #[arg(long, value_name = "NAMES", value_delimiter = ',')]
+    columns: Vec<String>,
+}
+
+impl CsvArgs {
+    /// Builds a writer with these layout options applied.
+    fn writer(self, quiet: bool, format: Option<OutputFormat>) -> CsvWriter {
+        let mut writer = CsvWriter::new(quiet)
+            .quoting(self.quote)
+            .header(!self.no_header)
+            .crlf(self.crlf);
+        if let Some(format) = format {
+            writer = writer.format(format);
+        }
+        if let Some(delimiter) = self.delimiter {
+            writer = writer.delimiter(delimiter);
+        }
+        if !self.columns.is_empty() {
+            writer = writer.columns(self.columns);
+        }
+        writer
+    }
+}
+
+/// Output and generation flags shared by the subcommands that generate records
+#[derive(Args)]
+struct GenerateArgs {
+    /// Output file path, or `-` for stdout
+    #[arg(short, long)]
+    output: String,
+
+    /// Output format: csv, tsv, jsonl, json or parquet (default: from the --output extension, else csv)
+    #[arg(short, long, value_name = "FORMAT")]
+    format: Option<OutputFormat>,
+
+    /// Error rate (0.0-1.0) - percentage of records with variance applied [default: 0.5, or the profile's error_rate]
+    #[arg(short, long)]
+    error_rate: Option<f64>,
+
+    /// Also write a ground-truth file pairing each row with its clean record and applied variances
+    #[arg(long, value_name = "PATH")]
+    ground_truth: Option<String>,
+
+    /// Variance profile (TOML or JSON) with pattern weights and per-field error rates
+    #[arg(long, value_name = "PATH")]
+    profile: Option<String>,
+
+    /// Threads used to generate records (default: all cores); output is identical for any value
+    #[arg(long, value_name = "N")]
+    threads: Option<usize>,
+
+    /// Treat --count as distinct entities and emit each one several times, varied per copy: a size (3) or size:weight pairs (1:50,2:30,3:20). Requires --ground-truth, which records each row's EntityId
+    #[arg(long, value_name = "DIST")]
+    clusters: Option<ClusterSizes>,
+
+    #[command(flatten)]
+    csv: CsvArgs,
+
+    /// Suppress progress output
+    #[arg(short, long)]
+    quiet: bool,
+
+    /// Random seed; the same seed and arguments reproduce identical output
+    #[arg(long)]
+    seed: Option<u64>,
+}
+
+/// Variance pattern selection for subcommands generating one record type
+#[derive(Args)]
+struct PatternArgs {
+    /// Only apply these variance patterns (comma-separated, default: all)
+    #[arg(long, value_name = "PATTERNS", value_delimiter = ',')]
+    variance: Vec<String>,
+
+    /// Never apply these variance patterns (comma-separated)
+    #[arg(long, value_name = "PATTERNS", value_delimiter = ',')]
+    exclude_variance: Vec<String>,
+}
+
 #[derive(Subcommand)]
 enum Commands {
     /// Generate address records
@@ -30,7 +155,7 @@ enum Commands {
         #[arg(short, long)]
         count: Option<usize>,
 
-        /// Input CSV file with real addresses to load
+        /// Input CSV file with real addresses to load, or `-` for stdin
         #[arg(short, long)]
         input: Option<String>,
 
@@ -38,35 +163,154 @@ enum Commands {
         #[arg(short, long)]
         state: Option<String>,
 
-        /// Output file path
+        #[command(flatten)]
+        patterns: PatternArgs,
+
+        #[command(flatten)]
+        generate: GenerateArgs,
+    },
+    /// Generate name records
+    Names {
+        /// Number of records to generate
         #[arg(short, long)]
-        output: String,
+        count: usize,
 
-        /// Error rate (0.0-1.0) - percentage of records with variance applied
-        #[arg(short, long, default_value = "0.5")]
-        error_rate: f64,
+        /// Name locales to draw from (comma-separated: en, es, zh, ja, ko, vi, ar, nl) [default: en]
+        #[arg(long, value_name = "LOCALES", value_delimiter = ',')]
+        locale: Vec<NameLocale>,
 
-        /// Suppress progress output
+        #[command(flatten)]
+        patterns: PatternArgs,
+
+        #[command(flatten)]
+        generate: GenerateArgs,
+    },
+    /// Generate email addresses derived from generated names
+    Emails {
+        /// Number of records to generate
         #[arg(short, long)]
-        quiet: bool,
+        count: usize,
+
+        #[command(flatten)]
+        patterns: PatternArgs,
+
+        #[command(flatten)]
+        generate: GenerateArgs,
     },
-    /// Generate name records
-    Names {
+    /// Generate US phone numbers
+    Phones {
         /// Number of records to generate
         #[arg(short, long)]
         count: usize,
 
-        /// Output file path
+        /// Take area codes from these states (comma-separated, default: all states)
+        #[arg(long, value_name = "STATES", value_delimiter = ',')]
+        state: Vec<String>,
+
+        #[command(flatten)]
+        patterns: PatternArgs,
+
+        #[command(flatten)]
+        generate: GenerateArgs,
+    },
+    /// Generate dates of birth
+    Dates {
+        /// Number of records to generate
         #[arg(short, long)]
-        output: String,
+        count: usize,
 
-        /// Error rate (0.0-1.0) - percentage of records with issues
-        #[arg(short, long, default_value = "0.5")]
-        error_rate: f64,
+        /// Age distribution: an age range (18-90) or range:weight pairs (18-29:20,30-64:60,65-90:20) [default: 18-29:21,30-44:25,45-64:33,65-90:21]
+        #[arg(long, value_name = "DIST")]
+        ages: Option<AgeDistribution>,
 
-        /// Suppress progress output
+        /// Date ages are measured on, as YYYY-MM-DD [default: today]; set it to reproduce output on a later day
+        #[arg(long, value_name = "DATE")]
+        as_of: Option<NaiveDate>,
+
+        #[command(flatten)]
+        patterns: PatternArgs,
+
+        #[command(flatten)]
+        generate: GenerateArgs,
+    },
+    /// Generate SSNs or EINs from ranges that are never issued
+    Tins {
+        /// Number of records to generate
         #[arg(short, long)]
-        quiet: bool,
+        count: usize,
+
+        /// Identifier kind: ssn or ein
+        #[arg(long, value_name = "KIND", default_value_t = TinKind::Ssn)]
+        kind: TinKind,
+
+        #[command(flatten)]
+        patterns: PatternArgs,
+
+        #[command(flatten)]
+        generate: GenerateArgs,
+    },
+    /// Generate company records: an organization name and an address
+    #[command(
+        after_help = "--variance and --exclude-variance select company-name patterns. --error-rate applies to each of the name and the address, and the profile's [companies] and [addresses] sections to the name and the address respectively."
+    )]
+    Companies {
+        /// Number of records to generate
+        #[arg(short, long)]
+        count: usize,
+
+        #[command(flatten)]
+        patterns: PatternArgs,
+
+        #[command(flatten)]
+        generate: GenerateArgs,
+    },
+    /// Generate addresses outside the US: Canada, the UK, Germany and Mexico
+    IntlAddresses {
+        /// Number of records to generate
+        #[arg(short, long)]
+        count: usize,
+
+        /// Countries to draw addresses from (comma-separated: us, ca, gb, de, mx) [default: ca,gb,de,mx]
+        #[arg(long, value_name = "COUNTRIES", value_delimiter = ',')]
+        country: Vec<Country>,
+
+        #[command(flatten)]
+        patterns: PatternArgs,
+
+        #[command(flatten)]
+        generate: GenerateArgs,
+    },
+    /// Generate person records: a name, an address and optional contact fields
+    #[command(
+        after_help = "--error-rate applies to each of the name, address, email, phone and date of birth, and the profile's [names], [addresses], [emails], [phones] and [dates] sections to each component."
+    )]
+    People {
+        /// Number of records to generate
+        #[arg(short, long)]
+        count: usize,
+
+        /// Include an email address derived from the name
+        #[arg(long)]
+        email: bool,
+
+        /// Include a phone number with an area code from the address's state
+        #[arg(long)]
+        phone: bool,
+
+        /// Include a date of birth
+        #[arg(long)]
+        dob: bool,
+
+        /// Age distribution for --dob: an age range (18-90) or range:weight pairs (18-29:20,30-64:60,65-90:20) [default: 18-29:21,30-44:25,45-64:33,65-90:21]
+        #[arg(long, value_name = "DIST", requires = "dob")]
+        ages: Option<AgeDistribution>,
+
+        /// Date ages are measured on for --dob, as YYYY-MM-DD [default: today]
+        #[arg(long, value_name = "DATE", requires = "dob")]
+        as_of: Option<NaiveDate>,
+
+        #[command(flatten)]
+        generate: GenerateArgs,
     },
     /// Download address data from OpenAddresses.io
     Download {
@@ -93,152 +337,707 @@ enum Commands {
         /// Suppress progress output
         #[arg(short, long)]
         quiet: bool,
+
+        /// Random seed; the same seed and arguments reproduce identical output
+        #[arg(long)]
+        seed: Option<u64>,
     },
 }
 
-fn validate_error_rate(error_rate: f64) -> Result<(), String> {
-    if !(0.0..=1.0).contains(&error_rate) {
-        return Err("Error rate must be between 0.0 and 1.0".to_string());
+fn main() {
+    let cli = Cli::parse();
+
+    // A closed stdout means the reader downstream of `-o -` (e.g. `head`)
+    // has stopped reading, which is not an error; exit quietly
+    if let Err(RunError::Failed(e)) = run(cli) {
+        eprintln!("Error: {}", e);
+        process::exit(1);
     }
-    Ok(())
 }
 
-fn validate_count(count: usize) -> Result<(), String> {
-    if count == 0 {
-        return Err("Count must be greater than 0".to_string());
+/// Why a subcommand stopped before completing its output.
+enum RunError {
+    /// The reader of stdout closed it; any other output has been completed
+    StdoutClosed,
+    Failed(String),
+}
+
+impl From<String> for RunError {
+    fn from(e: String) -> Self {
+        RunError::Failed(e)
     }
-    Ok(())
 }
 
-fn main() {
-    let cli = Cli::parse();
+/// Error rate used when neither --error-rate nor the profile sets one.
+const DEFAULT_ERROR_RATE: f64 = 0.5;
+
+/// Countries `intl-addresses` draws from when --country is not given.
+const DEFAULT_INTL_COUNTRIES: [Country; 4] = [
+    Country::Canada,
+    Country::UnitedKingdom,
+    Country::Germany,
+    Country::Mexico,
+];
 
+/// A profile file read from --profile, kept with its path for error messages.
+struct LoadedProfile {
+    path: String,
+    file: ProfileFile,
+}
+
+/// Reads the --profile file, if given, once for all the sections a
+/// subcommand builds from it.
+fn read_profile(path: Option<&str>) -> Result<Option<LoadedProfile>, String> {
+    path.map(|path| {
+        let file = load_profile(path).map_err(|e| e.to_string())?;
+        Ok(LoadedProfile {
+            path: path.to_string(),
+            file,
+        })
+    })
+    .transpose()
+}
+
+/// Builds the variance profile for one section from the selected patterns
+/// and an optional profile file, and resolves the effective error rate
+/// (--error-rate, then the profile's error_rate, then the default).
+fn resolve_profile<P: NamedPattern>(
+    loaded: Option<&LoadedProfile>,
+    section: &str,
+    variance: &[String],
+    exclude_variance: &[String],
+    error_rate: Option<f64>,
+) -> Result<(VarianceProfile<P>, f64), String> {
+    if let Some(rate) = error_rate {
+        validate_error_rate(rate).map_err(|e| e.to_string())?;
+    }
+    let patterns: Vec<P> =
+        select_patterns(variance, exclude_variance).map_err(|e| e.to_string())?;
+
+    let profile = match loaded {
+        Some(loaded) => VarianceProfile::from_section(&patterns, &loaded.file.section(section))
+            .map_err(|e| format!("Invalid profile {}: {}", loaded.path, e))?,
+        None => VarianceProfile::uniform(&patterns),
+    };
+
+    let error_rate = error_rate
+        .or(profile.error_rate())
+        .unwrap_or(DEFAULT_ERROR_RATE);
+    Ok((profile, error_rate))
+}
+
+/// Converts a write failure for `what` into a [`RunError`].
+fn write_error(what: &str, e: io::Error) -> RunError {
+    if is_stdout_closed(&e) {
+        RunError::StdoutClosed
+    } else {
+        RunError::Failed(format!("Error writing {}: {}", what, e))
+    }
+}
+
+/// Converts a failed write to `sink` into a [`RunError`], first completing
+/// the sink's other file when the failure was stdout being closed.
+fn write_failed<T: Record>(sink: RecordSink<T>, what: &str, e: io::Error) -> RunError {
+    if is_stdout_closed(&e) {
+        if let Err(e) = sink.finish() {
+            return write_error(what, e);
+        }
+    }
+    write_error(what, e)
+}
+
+/// Checks that at most one output goes to stdout and reports whether any
+/// does, in which case status messages must go to stderr.
+fn stdout_in_use(output: &str, ground_truth: Option<&str>) -> Result<bool, String> {
+    let ground_truth_to_stdout = ground_truth == Some(STDIO_PATH);
+    if output == STDIO_PATH && ground_truth_to_stdout {
+        return Err("--output and --ground-truth cannot both be '-'".to_string());
+    }
+    Ok(output == STDIO_PATH || ground_truth_to_stdout)
+}
+
+/// Prints a status message, on stderr when stdout carries data.
+fn status(to_stderr: bool, message: &str) {
+    if to_stderr {
+        eprintln!("{}", message);
+    } else {
+        println!("{}", message);
+    }
+}
+
+/// Opens the output sink, attaching the ground-truth file if requested.
+fn open_sink<T: Record>(
+    writer: &CsvWriter,
+    output: &str,
+    ground_truth: Option<&str>,
+    expected: Option<usize>,
+    message: &str,
+) -> Result<RecordSink<T>, RunError> {
+    let sink = writer
+        .open_sink(output, expected, message)
+        .map_err(|e| write_error(T::NOUN, e))?;
+    match ground_truth {
+        Some(path) => sink
+            .with_ground_truth(path)
+            .map_err(|e| write_error("ground truth", e)),
+        None => Ok(sink),
+    }
+}
+
+/// Opens the output sink for a clustered dataset; the ground-truth file is
+/// required because it is the only place entity ids are recorded.
+fn open_clustered_sink<T: Record>(
+    writer: &CsvWriter,
+    output: &str,
+    ground_truth: Option<&str>,
+    message: &str,
+) -> Result<RecordSink<T>, RunError> {
+    let path = ground_truth
+        .ok_or_else(|| "--clusters requires --ground-truth to record entity ids".to_string())?;
+    writer
+        .open_sink(output, None, message)
+        .map_err(|e| write_error(T::NOUN, e))?
+        .with_clustered_ground_truth(path)
+        .map_err(|e| write_error("ground truth", e))
+}
+
+/// Resolves --threads, defaulting to every available core.
+fn resolve_threads(threads: Option<usize>) -> Result<usize, String> {
+    let threads = threads.unwrap_or_else(default_threads);
+    validate_threads(threads).map_err(|e| e.to_string())?;
+    Ok(threads)
+}
+
+/// Generates `count` traced records in seeded chunks across `threads`
+/// threads and streams them, in order, to the sink.
+fn write_generated<T, G>(
+    mut sink: RecordSink<T>,
+    count: usize,
+    threads: usize,
+    base_seed: u64,
+    generate: G,
+) -> Result<usize, RunError>
+where
+    T: Record + Send,
+    G: Fn(&mut StdRng, usize) -> Vec<Traced<T>> + Sync,
+{
+    let generated = generate_parallel(count, threads, base_seed, generate, |chunk| {
+        chunk.into_iter().try_for_each(|traced| sink.write(traced))
+    });
+    if let Err(e) = generated {
+        return Err(write_failed(sink, T::NOUN, e));
+    }
+
+    sink.finish().map_err(|e| write_error(T::NOUN, e))
+}
+
+/// Generates `entities` duplicate clusters in seeded chunks across
+/// `threads` threads and streams them, in order, to the sink.
+fn write_clusters<T, G>(
+    mut sink: RecordSink<T>,
+    entities: usize,
+    threads: usize,
+    base_seed: u64,
+    generate: G,
+) -> Result<usize, RunError>
+where
+    T: Record + Send,
+    G: Fn(&mut StdRng, usize) -> Vec<Clustered<T>> + Sync,
+{
+    // Every chunk but the last holds CHUNK_SIZE entities, numbered from 0
+    let mut first_entity_id = 1;
+    let generated = generate_parallel(entities, threads, base_seed, generate, |chunk| {
+        for row in chunk {
+            sink.write_entity(first_entity_id + row.entity, row.traced)?;
+        }
+        first_entity_id += CHUNK_SIZE;
+        Ok(())
+    });
+    if let Err(e) = generated {
+        return Err(write_failed(sink, T::NOUN, e));
+    }
+
+    sink.finish().map_err(|e| write_error(T::NOUN, e))
+}
+
+/// Applies variance to loaded addresses and streams them to the output as
+/// they are read, returning the number written.
+#[allow(clippy::too_many_arguments)]
+fn write_loaded_addresses<R: Rng + ?Sized>(
+    writer: &CsvWriter,
+    output: &str,
+    ground_truth: Option<&str>,
+    addresses: impl Iterator<Item = rust_faker::Result<Address>>,
+    load_error: &str,
+    error_rate: f64,
+    profile: &VarianceProfile<addresses::VariancePattern>,
+    rng: &mut R,
+) -> Result<usize, RunError> {
+    let expected = match addresses.size_hint() {
+        (lower, Some(upper)) if lower == upper => Some(upper),
+        _ => None,
+    };
+    let mut sink =
+        open_sink::<Address>(writer, output, ground_truth, expected, "Writing addresses")?;
+
+    for address in addresses {
+        let address = address.map_err(|e| format!("{}: {}", load_error, e))?;
+        let traced = vary_address(address, error_rate, profile, rng);
+        if let Err(e) = sink.write(traced) {
+            return Err(write_failed(sink, "addresses", e));
+        }
+    }
+
+    sink.finish().map_err(|e| write_error("addresses", e))
+}
+
+/// Generates `count` records, or with --clusters `count` entities emitted
+/// as several varied copies each, from the `clean` and `vary` functions of
+/// a record type, and streams them to the output.
+fn generate_records<T, C, V>(
+    count: usize,
+    args: GenerateArgs,
+    clean: C,
+    vary: V,
+) -> Result<(), RunError>
+where
+    T: Record + Clone + Send,
+    C: Fn(&mut StdRng) -> T + Sync,
+    V: Fn(T, &mut StdRng) -> Traced<T> + Sync,
+{
+    validate_count(count).map_err(|e| e.to_string())?;
+    let threads = resolve_threads(args.threads)?;
+    let base_seed = create_rng(args.seed).gen();
+
+    let output = args.output;
+    let ground_truth = args.ground_truth.as_deref();
+    let to_stderr = stdout_in_use(&output, ground_truth)?;
+    let writer = args.csv.writer(args.quiet, args.format);
+    let message = format!("Generating {}", T::NOUN);
+    let written = match &args.clusters {
+        Some(sizes) => {
+            let sink = open_clustered_sink::<T>(&writer, &output, ground_truth, &message)?;
+            write_clusters(sink, count, threads, base_seed, |chunk_rng, n| {
+                generate_clusters(n, sizes, chunk_rng, &clean, &vary)
+            })?
+        }
+        None => {
+            let sink = open_sink::<T>(&writer, &output, ground_truth, Some(count), &message)?;
+            write_generated(sink, count, threads, base_seed, |chunk_rng, n| {
+                traced(chunk_rng, &clean, &vary).take(n).collect()
+            })?
+        }
+    };
+
+    if !args.quiet {
+        status(
+            to_stderr,
+            &format!(
+                "Successfully generated {} {} to {}",
+                written,
+                T::NOUN,
+                output
+            ),
+        );
+    }
+    Ok(())
+}
+
+fn run(cli: Cli) -> Result<(), RunError> {
     match cli.command {
         Commands::Addresses {
             count,
             input,
             state,
-            output,
-            error_rate,
-            quiet,
+            patterns,
+            generate,
         } => {
-            if let Err(e) = validate_error_rate(error_rate) {
-                eprintln!("Error: {}", e);
-                process::exit(1);
-            }
-
+            let (profile, error_rate) = resolve_profile::<addresses::VariancePattern>(
+                read_profile(generate.profile.as_deref())?.as_ref(),
+                "addresses",
+                &patterns.variance,
+                &patterns.exclude_variance,
+                generate.error_rate,
+            )?;
             // Check mutual exclusivity
             if input.is_some() && state.is_some() {
-                eprintln!("Error: Cannot use --input and --state together. Choose one.");
-                process::exit(1);
+                return Err("Cannot use --input and --state together. Choose one."
+                    .to_string()
+                    .into());
+            }
+            if generate.clusters.is_some() && (input.is_some() || state.is_some()) {
+                return Err("--clusters only applies to generated addresses"
+                    .to_string()
+                    .into());
+            }
+            if generate.threads.is_some() && (input.is_some() || state.is_some()) {
+                return Err("--threads only applies to generated addresses"
+                    .to_string()
+                    .into());
             }
 
-            let addresses = if let Some(input_path) = input {
-                // Load addresses from input CSV
-                match load_addresses_from_csv(&input_path, count) {
-                    Ok(loaded) => {
-                        if !quiet {
-                            println!("Loaded {} addresses from {}", loaded.len(), input_path);
-                        }
-                        // Apply variance to loaded addresses
-                        apply_variance_to_addresses(loaded, error_rate)
-                    }
-                    Err(e) => {
-                        eprintln!("Error loading addresses from {}: {}", input_path, e);
-                        process::exit(1);
-                    }
+            if input.is_none() && state.is_none() {
+                // Generate fake addresses (count is required in this case)
+                let count = count.ok_or_else(|| {
+                    "--count is required when not using --input or --state".to_string()
+                })?;
+                return generate_records(
+                    count,
+                    generate,
+                    generate_clean_address,
+                    |address, rng| vary_address(address, error_rate, &profile, rng),
+                );
+            }
+
+            let GenerateArgs {
+                output,
+                format,
+                ground_truth,
+                csv,
+                quiet,
+                seed,
+                ..
+            } = generate;
+            let mut rng = create_rng(seed);
+            let to_stderr = stdout_in_use(&output, ground_truth.as_deref())?;
+            let writer = csv.writer(quiet, format);
+            let written = if let Some(input_path) = input {
+                let load_error = format!("Error loading addresses from {}", input_path);
+                let reader = AddressReader::open(&input_path)
+                    .map_err(|e| format!("{}: {}", load_error, e))?;
+                for warning in reader.warnings() {
+                    eprintln!("Warning: {}", warning);
                 }
-            } else if let Some(state_input) = state {
-                // Load addresses from cache
-                let states_to_load: Vec<String> = if state_input.to_lowercase() == "all" {
-                    match cache::list_cached_states() {
-                        Ok(cached) => {
-                            if cached.is_empty() {
-                                eprintln!(
-                                    "Error: No states specified or cached. Run 'rust-faker download <STATE>' first."
-                                );
-                                process::exit(1);
-                            }
-                            cached.into_iter().map(|(state, _)| state).collect()
+
+                let source: Box<dyn Iterator<Item = rust_faker::Result<Address>>> = match count {
+                    Some(requested) => {
+                        let (sample, available) = sample_addresses(reader, requested, &mut rng)
+                            .map_err(|e| format!("{}: {}", load_error, e))?;
+                        if requested > available {
+                            eprintln!(
+                                "Warning: {}",
+                                LoadWarning::InsufficientRecords {
+                                    requested,
+                                    available
+                                }
+                            );
                         }
-                        Err(e) => {
-                            eprintln!("Error listing cached states: {}", e);
-                            process::exit(1);
+                        if !quiet {
+                            status(
+                                to_stderr,
+                                &format!("Loaded {} addresses from {}", sample.len(), input_path),
+                            );
                         }
+                        Box::new(sample.into_iter().map(Ok))
                     }
-                } else {
-                    state_input
-                        .split(',')
-                        .map(|s| s.trim().to_string())
-                        .collect()
+                    None => Box::new(reader),
                 };
+                write_loaded_addresses(
+                    &writer,
+                    &output,
+                    ground_truth.as_deref(),
+                    source,
+                    &load_error,
+                    error_rate,
+                    &profile,
+                    &mut rng,
+                )?
+            } else if let Some(state_input) = state {
+                // Load addresses from cache
+                let states_to_load =
+                    cache::resolve_states(&state_input).map_err(|e| e.to_string())?;
+                let load_error = "Error loading addresses from cache";
 
-                match load_addresses_from_cache(&states_to_load, count) {
-                    Ok(loaded) => {
+                let cached = stream_addresses_from_cache(&states_to_load)
+                    .map_err(|e| format!("{}: {}", load_error, e))?;
+                let source: Box<dyn Iterator<Item = rust_faker::Result<Address>>> = match count {
+                    Some(requested) => {
+                        let (sample, _) = sample_addresses(cached, requested, &mut rng)
+                            .map_err(|e| format!("{}: {}", load_error, e))?;
                         if !quiet {
-                            println!(
-                                "Loaded {} addresses from cache (states: {})",
-                                loaded.len(),
-                                states_to_load.join(", ")
+                            status(
+                                to_stderr,
+                                &format!(
+                                    "Loaded {} addresses from cache (states: {})",
+                                    sample.len(),
+                                    states_to_load.join(", ")
+                                ),
                             );
                         }
-                        // Apply variance to loaded addresses
-                        apply_variance_to_addresses(loaded, error_rate)
-                    }
-                    Err(e) => {
-                        eprintln!("Error loading addresses from cache: {}", e);
-                        process::exit(1);
+                        Box::new(sample.into_iter().map(Ok))
                     }
-                }
+                    None => Box::new(cached),
+                };
+                write_loaded_addresses(
+                    &writer,
+                    &output,
+                    ground_truth.as_deref(),
+                    source,
+                    load_error,
+                    error_rate,
+                    &profile,
+                    &mut rng,
+                )?
             } else {
-                // Generate fake addresses (count is required in this case)
-                let count = count.unwrap_or_else(|| {
-                    eprintln!("Error: --count is required when not using --input or --state");
-                    process::exit(1);
-                });
-                if let Err(e) = validate_count(count) {
-                    eprintln!("Error: {}", e);
-                    process::exit(1);
-                }
-                generate_addresses(count, error_rate)
+                unreachable!("addresses without --input or --state are generated above")
             };
 
-            let final_count = addresses.len();
-            let writer = CsvWriter::new(quiet);
-            if let Err(e) = writer.write_addresses(&output, &addresses) {
-                eprintln!("Error writing addresses: {}", e);
-                process::exit(1);
-            }
-
             if !quiet {
-                println!("Successfully wrote {} addresses to {}", final_count, output);
+                status(
+                    to_stderr,
+                    &format!("Successfully wrote {} addresses to {}", written, output),
+                );
             }
         }
         Commands::Names {
             count,
-            output,
-            error_rate,
-            quiet,
+            locale,
+            patterns,
+            generate,
         } => {
-            if let Err(e) = validate_count(count) {
-                eprintln!("Error: {}", e);
-                process::exit(1);
-            }
-            if let Err(e) = validate_error_rate(error_rate) {
-                eprintln!("Error: {}", e);
-                process::exit(1);
-            }
-
-            let names = generate_names(count, error_rate);
-            let writer = CsvWriter::new(quiet);
-            if let Err(e) = writer.write_names(&output, &names) {
-                eprintln!("Error writing names: {}", e);
-                process::exit(1);
-            }
-
-            if !quiet {
-                println!("Successfully generated {} names to {}", count, output);
+            let (profile, error_rate) = resolve_profile::<names::VariancePattern>(
+                read_profile(generate.profile.as_deref())?.as_ref(),
+                "names",
+                &patterns.variance,
+                &patterns.exclude_variance,
+                generate.error_rate,
+            )?;
+            let locales = if locale.is_empty() {
+                vec![NameLocale::English]
+            } else {
+                locale
+            };
+            generate_records(
+                count,
+                generate,
+                |rng| generate_clean_locale_name(&locales, rng),
+                |name, rng| vary_name(name, error_rate, &profile, rng),
+            )?;
+        }
+        Commands::Emails {
+            count,
+            patterns,
+            generate,
+        } => {
+            let (profile, error_rate) = resolve_profile::<emails::VariancePattern>(
+                read_profile(generate.profile.as_deref())?.as_ref(),
+                "emails",
+                &patterns.variance,
+                &patterns.exclude_variance,
+                generate.error_rate,
+            )?;
+            generate_records(
+                count,
+                generate,
+                |rng| Email::new(generate_clean_email(&generate_clean_name(rng), rng)),
+                |email, rng| vary_email(email, error_rate, &profile, rng),
+            )?;
+        }
+        Commands::Phones {
+            count,
+            state,
+            patterns,
+            generate,
+        } => {
+            if let Some(invalid) = state.iter().find(|s| !regions::is_valid_state(s)) {
+                return Err(format!("Invalid state code: {}", invalid).into());
             }
+            let (profile, error_rate) = resolve_profile::<phones::VariancePattern>(
+                read_profile(generate.profile.as_deref())?.as_ref(),
+                "phones",
+                &patterns.variance,
+                &patterns.exclude_variance,
+                generate.error_rate,
+            )?;
+            generate_records(
+                count,
+                generate,
+                |rng| {
+                    let state =
+                        (!state.is_empty()).then(|| state[rng.gen_range(0..state.len())].as_str());
+                    Phone::new(generate_clean_phone(state, rng))
+                },
+                |phone, rng| vary_phone(phone, error_rate, &profile, rng),
+            )?;
+        }
+        Commands::Dates {
+            count,
+            ages,
+            as_of,
+            patterns,
+            generate,
+        } => {
+            let (profile, error_rate) = resolve_profile::<dates::VariancePattern>(
+                read_profile(generate.profile.as_deref())?.as_ref(),
+                "dates",
+                &patterns.variance,
+                &patterns.exclude_variance,
+                generate.error_rate,
+            )?;
+            let birth_dates = BirthDates::new(
+                ages.unwrap_or_default(),
+                as_of.unwrap_or_else(|| Local::now().date_naive()),
+            )
+            .map_err(|e| e.to_string())?;
+            generate_records(
+                count,
+                generate,
+                |rng| Date::new(generate_clean_date(&birth_dates, rng)),
+                |date, rng| vary_date(date, error_rate, &profile, rng),
+            )?;
+        }
+        Commands::Tins {
+            count,
+            kind,
+            patterns,
+            generate,
+        } => {
+            let (profile, error_rate) = resolve_profile::<tins::VariancePattern>(
+                read_profile(generate.profile.as_deref())?.as_ref(),
+                "tins",
+                &patterns.variance,
+                &patterns.exclude_variance,
+                generate.error_rate,
+            )?;
+            generate_records(
+                count,
+                generate,
+                |rng| Tin::new(generate_clean_tin(kind, rng)),
+                |tin, rng| vary_tin(tin, error_rate, &profile, rng),
+            )?;
+        }
+        Commands::Companies {
+            count,
+            patterns,
+            generate,
+        } => {
+            let loaded = read_profile(generate.profile.as_deref())?;
+            let (names, name_error_rate) = resolve_profile::<companies::VariancePattern>(
+                loaded.as_ref(),
+                "companies",
+                &patterns.variance,
+                &patterns.exclude_variance,
+                generate.error_rate,
+            )?;
+            let (addresses, address_error_rate) = resolve_profile::<addresses::VariancePattern>(
+                loaded.as_ref(),
+                "addresses",
+                &[],
+                &[],
+                generate.error_rate,
+            )?;
+            let variance = CompanyVariance {
+                names,
+                name_error_rate,
+                addresses,
+                address_error_rate,
+            };
+            generate_records(count, generate, generate_clean_company, |company, rng| {
+                vary_company(company, &variance, rng)
+            })?;
+        }
+        Commands::IntlAddresses {
+            count,
+            country,
+            patterns,
+            generate,
+        } => {
+            let (profile, error_rate) = resolve_profile::<international::VariancePattern>(
+                read_profile(generate.profile.as_deref())?.as_ref(),
+                "intl_addresses",
+                &patterns.variance,
+                &patterns.exclude_variance,
+                generate.error_rate,
+            )?;
+            let countries = if country.is_empty() {
+                DEFAULT_INTL_COUNTRIES.to_vec()
+            } else {
+                country
+            };
+            generate_records(
+                count,
+                generate,
+                |rng| generate_clean_international_address(&countries, rng),
+                |address, rng| vary_international_address(address, error_rate, &profile, rng),
+            )?;
+        }
+        Commands::People {
+            count,
+            email,
+            phone,
+            dob,
+            ages,
+            as_of,
+            generate,
+        } => {
+            let loaded = read_profile(generate.profile.as_deref())?;
+            let error_rate = generate.error_rate;
+            let (names, name_error_rate) = resolve_profile::<names::VariancePattern>(
+                loaded.as_ref(),
+                "names",
+                &[],
+                &[],
+                error_rate,
+            )?;
+            let (addresses, address_error_rate) = resolve_profile::<addresses::VariancePattern>(
+                loaded.as_ref(),
+                "addresses",
+                &[],
+                &[],
+                error_rate,
+            )?;
+            let (emails, email_error_rate) = resolve_profile::<emails::VariancePattern>(
+                loaded.as_ref(),
+                "emails",
+                &[],
+                &[],
+                error_rate,
+            )?;
+            let (phones, phone_error_rate) = resolve_profile::<phones::VariancePattern>(
+                loaded.as_ref(),
+                "phones",
+                &[],
+                &[],
+                error_rate,
+            )?;
+            let (dates, date_error_rate) = resolve_profile::<dates::VariancePattern>(
+                loaded.as_ref(),
+                "dates",
+                &[],
+                &[],
+                error_rate,
+            )?;
+            let variance = PersonVariance {
+                names,
+                name_error_rate,
+                addresses,
+                address_error_rate,
+                emails,
+                email_error_rate,
+                phones,
+                phone_error_rate,
+                dates,
+                date_error_rate,
+            };
+            let fields = ContactFields {
+                email,
+                phone,
+                date_of_birth: dob
+                    .then(|| {
+                        BirthDates::new(
+                            ages.unwrap_or_default(),
+                            as_of.unwrap_or_else(|| Local::now().date_naive()),
+                        )
+                    })
+                    .transpose()
+                    .map_err(|e| e.to_string())?,
+            };
+            generate_records(
+                count,
+                generate,
+                |rng| generate_clean_person(&fields, rng),
+                |person, rng| vary_person(person, &variance, rng),
+            )?;
         }
         Commands::Download {
             states,
@@ -247,60 +1046,42 @@ fn main() {
             limit,
             force,
             quiet,
+            seed,
         } => {
             if list {
-                if let Err(e) = download::print_cache_list() {
-                    eprintln!("Error listing cache: {}", e);
-                    process::exit(1);
-                }
-                return;
+                return download::print_cache_list()
+                    .map_err(|e| format!("Error listing cache: {}", e).into());
             }
 
             let states_to_download: Vec<String> = if all {
                 regions::ALL_STATES.iter().map(|s| s.to_string()).collect()
             } else if states.is_empty() {
-                eprintln!("Error: Specify states to download or use --all");
-                process::exit(1);
+                return Err("Specify states to download or use --all".to_string().into());
             } else {
                 states
             };
 
-            if let Err(e) = download::download_states(&states_to_download, limit, force, quiet) {
-                eprintln!("Error downloading: {}", e);
-                process::exit(1);
-            }
+            download::download_states(
+                &states_to_download,
+                limit,
+                force,
+                quiet,
+                &mut create_rng(seed),
+            )
+            .map_err(|e| format!("Error downloading: {}", e))?;
         }
     }
+
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use clap::CommandFactory;
 
     #[test]
-    fn test_cli_help_works() {
-        // This will test that CLI parsing doesn't panic
-        // More detailed CLI tests will come later
-    }
-
-    #[test]
-    fn test_validate_zero_count() {
-        assert!(validate_count(0).is_err());
-    }
-
-    #[test]
-    fn test_validate_error_rate_below_zero() {
-        assert!(validate_error_rate(-0.1).is_err());
-    }
-
-    #[test]
-    fn test_validate_error_rate_above_one() {
-        assert!(validate_error_rate(1.5).is_err());
-    }
-
-    #[test]
-    fn test_validate_valid_inputs() {
-        assert!(validate_count(100).is_ok());
-        assert!(validate_error_rate(0.5).is_ok());
+    fn test_cli_definition_is_valid() {
+        Cli::command().debug_assert();
     }
 }
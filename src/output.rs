@@ -0,0 +1,617 @@
+//! Output file formats and the writers behind them.
+//!
+//! Every format is written through the [`OutputWriter`] trait, one row at a
+//! time, so any format can be streamed in constant memory.
+
+use parquet::basic::{LogicalType, Repetition, Type as PhysicalType};
+use parquet::data_type::{ByteArray, ByteArrayType};
+use parquet::file::properties::WriterProperties;
+use parquet::file::writer::SerializedFileWriter;
+use parquet::schema::types::Type;
+use serde::Serialize;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::error::Error;
+
+/// A file format records can be written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    /// Pipe-delimited CSV with a header row
+    #[default]
+    Csv,
+    /// Tab-delimited with a header row
+    Tsv,
+    /// One JSON object per line
+    Jsonl,
+    /// A single JSON array of objects
+    Json,
+    /// Apache Parquet with one UTF-8 column per field
+    Parquet,
+}
+
+impl OutputFormat {
+    /// Every format, in declaration order
+    pub const ALL: [OutputFormat; 5] = [
+        OutputFormat::Csv,
+        OutputFormat::Tsv,
+        OutputFormat::Jsonl,
+        OutputFormat::Json,
+        OutputFormat::Parquet,
+    ];
+
+    /// The name used with `--format`
+    pub fn name(&self) -> &'static str {
+        match self {
+            OutputFormat::Csv => "csv",
+            OutputFormat::Tsv => "tsv",
+            OutputFormat::Jsonl => "jsonl",
+            OutputFormat::Json => "json",
+            OutputFormat::Parquet => "parquet",
+        }
+    }
+
+    /// Infers the format from a file extension (`.jsonl`/`.ndjson`, `.json`,
+    /// `.parquet`, `.tsv`, `.csv`), if recognised.
+    pub fn from_extension(path: &str) -> Option<Self> {
+        let ext = Path::new(path).extension()?.to_str()?.to_lowercase();
+        match ext.as_str() {
+            "csv" => Some(OutputFormat::Csv),
+            "tsv" => Some(OutputFormat::Tsv),
+            "jsonl" | "ndjson" => Some(OutputFormat::Jsonl),
+            "json" => Some(OutputFormat::Json),
+            "parquet" => Some(OutputFormat::Parquet),
+            _ => None,
+        }
+    }
+
+    /// Resolves the format for `path`: the explicit format if given, else
+    /// the one implied by its extension, else CSV.
+    pub fn resolve(format: Option<Self>, path: &str) -> Self {
+        format
+            .or_else(|| Self::from_extension(path))
+            .unwrap_or_default()
+    }
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim().to_lowercase();
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|format| format.name() == name)
+            .ok_or_else(|| {
+                let valid: Vec<&str> = Self::ALL.iter().map(|f| f.name()).collect();
+                Error::InvalidArgument(format!(
+                    "Unknown output format '{}'. Valid formats: {}",
+                    s,
+                    valid.join(", ")
+                ))
+            })
+    }
+}
+
+/// How fields are quoted in delimited output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Quoting {
+    /// Quote only fields containing the delimiter, quotes or line breaks
+    #[default]
+    Necessary,
+    /// Quote every field
+    Always,
+    /// Quote every field that is not a number
+    NonNumeric,
+    /// Never quote; fields containing the delimiter are written as-is
+    Never,
+}
+
+impl Quoting {
+    /// Every quoting style, in declaration order
+    pub const ALL: [Quoting; 4] = [
+        Quoting::Necessary,
+        Quoting::Always,
+        Quoting::NonNumeric,
+        Quoting::Never,
+    ];
+
+    /// The name used with `--quote`
+    pub fn name(&self) -> &'static str {
+        match self {
+            Quoting::Necessary => "necessary",
+            Quoting::Always => "always",
+            Quoting::NonNumeric => "non-numeric",
+            Quoting::Never => "never",
+        }
+    }
+
+    fn quote_style(self) -> csv::QuoteStyle {
+        match self {
+            Quoting::Necessary => csv::QuoteStyle::Necessary,
+            Quoting::Always => csv::QuoteStyle::Always,
+            Quoting::NonNumeric => csv::QuoteStyle::NonNumeric,
+            Quoting::Never => csv::QuoteStyle::Never,
+        }
+    }
+}
+
+impl fmt::Display for Quoting {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Quoting {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim().to_lowercase();
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|quoting| quoting.name() == name)
+            .ok_or_else(|| {
+                let valid: Vec<&str> = Self::ALL.iter().map(|q| q.name()).collect();
+                Error::InvalidArgument(format!(
+                    "Unknown quoting style '{}'. Valid styles: {}",
+                    s,
+                    valid.join(", ")
+                ))
+            })
+    }
+}
+
+/// Layout options for delimited (CSV and TSV) output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvOptions {
+    /// Field delimiter; `None` uses the format's default (`|` for CSV, tab for TSV)
+    pub delimiter: Option<u8>,
+    pub quoting: Quoting,
+    /// Write a header row first
+    pub header: bool,
+    /// End lines with `\r\n` instead of `\n`
+    pub crlf: bool,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: None,
+            quoting: Quoting::default(),
+            header: true,
+            crlf: false,
+        }
+    }
+}
+
+/// Parses a `--delimiter` value: a single ASCII character, or `tab` / `\t`.
+pub fn parse_delimiter(s: &str) -> Result<u8, Error> {
+    match s {
+        "tab" | "\\t" | "\t" => Ok(b'\t'),
+        _ if s.len() == 1 && s.is_ascii() && !matches!(s, "\"" | "\n" | "\r") => {
+            Ok(s.as_bytes()[0])
+        }
+        _ => Err(Error::InvalidArgument(format!(
+            "Invalid delimiter '{}'. Use a single character other than a quote or line break, or 'tab'",
+            s
+        ))),
+    }
+}
+
+/// A row that can be written in any [`OutputFormat`].
+///
+/// JSON formats use the `Serialize` representation; tabular formats use
+/// [`Row::values`] under a header supplied when the writer is opened.
+pub trait Row: Serialize {
+    /// Field values, in header order
+    fn values(&self) -> Vec<String>;
+}
+
+/// Streams rows of one type to a file in a particular format.
+pub trait OutputWriter<R> {
+    /// Writes a single row.
+    fn write(&mut self, row: &R) -> io::Result<()>;
+
+    /// Completes the file (closing JSON arrays, Parquet footers) and flushes it.
+    fn finish(self: Box<Self>) -> io::Result<()>;
+}
+
+/// Path that stands for standard output (and standard input, for `--input`).
+pub const STDIO_PATH: &str = "-";
+
+/// Destination of an output file: a [`File`] or standard output.
+type Sink = Box<dyn Write + Send>;
+
+/// Opens a writer for `format` at `path`, creating parent directories if
+/// needed. `header` names the columns for tabular formats. A `path` of
+/// [`STDIO_PATH`] writes to standard output.
+///
+/// `options` apply to CSV and TSV only; `header` is also used for the
+/// Parquet column names.
+pub fn open_writer<R: Row + 'static>(
+    format: OutputFormat,
+    path: &str,
+    header: &[&str],
+    options: &CsvOptions,
+) -> io::Result<Box<dyn OutputWriter<R>>> {
+    let file: Sink = if path == STDIO_PATH {
+        Box::new(io::stdout())
+    } else {
+        if let Some(parent) = Path::new(path).parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        Box::new(File::create(path)?)
+    };
+
+    Ok(match format {
+        OutputFormat::Csv => Box::new(DelimitedWriter::new(file, b'|', header, options)?),
+        OutputFormat::Tsv => Box::new(DelimitedWriter::new(file, b'\t', header, options)?),
+        OutputFormat::Jsonl => Box::new(JsonLinesWriter {
+            out: BufWriter::new(file),
+        }),
+        OutputFormat::Json => Box::new(JsonArrayWriter::new(file)?),
+        OutputFormat::Parquet => Box::new(ParquetWriter::new(file, header)?),
+    })
+}
+
+/// Delimited text (CSV, TSV) with a header row.
+struct DelimitedWriter {
+    writer: csv::Writer<Sink>,
+}
+
+impl DelimitedWriter {
+    fn new(
+        file: Sink,
+        default_delimiter: u8,
+        header: &[&str],
+        options: &CsvOptions,
+    ) -> io::Result<Self> {
+        let terminator = if options.crlf {
+            csv::Terminator::CRLF
+        } else {
+            csv::Terminator::Any(b'\n')
+        };
+        let mut writer = csv::WriterBuilder::new()
+            .delimiter(options.delimiter.unwrap_or(default_delimiter))
+            .quote_style(options.quoting.quote_style())
+            .terminator(terminator)
+            .from_writer(file);
+        if options.header {
+            writer.write_record(header).map_err(csv_io_error)?;
+        }
+        Ok(Self { writer })
+    }
+}
+
+impl<R: Row> OutputWriter<R> for DelimitedWriter {
+    fn write(&mut self, row: &R) -> io::Result<()> {
+        self.writer.write_record(row.values()).map_err(csv_io_error)
+    }
+
+    fn finish(mut self: Box<Self>) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// Converts a CSV error to an I/O error, keeping the kind of underlying I/O
+/// failures (so a closed stdout still reads as a broken pipe).
+fn csv_io_error(e: csv::Error) -> io::Error {
+    if e.is_io_error() {
+        if let csv::ErrorKind::Io(e) = e.into_kind() {
+            return e;
+        }
+        unreachable!("is_io_error implies an Io kind");
+    }
+    io::Error::other(e)
+}
+
+/// Newline-delimited JSON objects.
+struct JsonLinesWriter {
+    out: BufWriter<Sink>,
+}
+
+impl<R: Row> OutputWriter<R> for JsonLinesWriter {
+    fn write(&mut self, row: &R) -> io::Result<()> {
+        serde_json::to_writer(&mut self.out, row)?;
+        self.out.write_all(b"\n")
+    }
+
+    fn finish(mut self: Box<Self>) -> io::Result<()> {
+        self.out.flush()
+    }
+}
+
+/// A JSON array written incrementally, one element per line.
+struct JsonArrayWriter {
+    out: BufWriter<Sink>,
+    empty: bool,
+}
+
+impl JsonArrayWriter {
+    fn new(file: Sink) -> io::Result<Self> {
+        let mut out = BufWriter::new(file);
+        out.write_all(b"[")?;
+        Ok(Self { out, empty: true })
+    }
+}
+
+impl<R: Row> OutputWriter<R> for JsonArrayWriter {
+    fn write(&mut self, row: &R) -> io::Result<()> {
+        self.out
+            .write_all(if self.empty { b"\n" } else { b",\n" })?;
+        self.empty = false;
+        Ok(serde_json::to_writer(&mut self.out, row)?)
+    }
+
+    fn finish(mut self: Box<Self>) -> io::Result<()> {
+        self.out
+            .write_all(if self.empty { b"]\n" } else { b"\n]\n" })?;
+        self.out.flush()
+    }
+}
+
+/// Rows buffered per Parquet row group; bounds memory for large outputs.
+const PARQUET_ROW_GROUP_SIZE: usize = 100_000;
+
+/// Parquet with one required UTF-8 column per header field.
+struct ParquetWriter {
+    writer: SerializedFileWriter<Sink>,
+    columns: Vec<Vec<ByteArray>>,
+}
+
+impl ParquetWriter {
+    fn new(file: Sink, header: &[&str]) -> io::Result<Self> {
+        let fields = header
+            .iter()
+            .map(|name| {
+                Type::primitive_type_builder(name, PhysicalType::BYTE_ARRAY)
+                    .with_repetition(Repetition::REQUIRED)
+                    .with_logical_type(Some(LogicalType::String))
+                    .build()
+                    .map(Arc::new)
+            })
+            .collect::<parquet::errors::Result<Vec<_>>>()
+            .map_err(io::Error::other)?;
+        let schema = Type::group_type_builder("schema")
+            .with_fields(fields)
+            .build()
+            .map_err(io::Error::other)?;
+
+        let properties = WriterProperties::builder().build();
+        let writer = SerializedFileWriter::new(file, Arc::new(schema), Arc::new(properties))
+            .map_err(io::Error::other)?;
+
+        Ok(Self {
+            writer,
+            columns: vec![Vec::new(); header.len()],
+        })
+    }
+
+    fn flush_row_group(&mut self) -> parquet::errors::Result<()> {
+        if self.columns.first().is_none_or(|c| c.is_empty()) {
+            return Ok(());
+        }
+
+        let mut row_group = self.writer.next_row_group()?;
+        for column in &mut self.columns {
+            let mut column_writer = row_group
+                .next_column()?
+                .expect("schema has a column per header field");
+            column_writer
+                .typed::<ByteArrayType>()
+                .write_batch(column, None, None)?;
+            column_writer.close()?;
+            column.clear();
+        }
+        row_group.close()?;
+        Ok(())
+    }
+}
+
+impl<R: Row> OutputWriter<R> for ParquetWriter {
+    fn write(&mut self, row: &R) -> io::Result<()> {
+        let values = row.values();
+        if values.len() != self.columns.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Row has a different number of fields than the header",
+            ));
+        }
+        for (column, value) in self.columns.iter_mut().zip(values) {
+            column.push(ByteArray::from(value.into_bytes()));
+        }
+
+        if self.columns[0].len() >= PARQUET_ROW_GROUP_SIZE {
+            self.flush_row_group().map_err(io::Error::other)?;
+        }
+        Ok(())
+    }
+
+    fn finish(mut self: Box<Self>) -> io::Result<()> {
+        self.flush_row_group().map_err(io::Error::other)?;
+        self.writer.close().map_err(io::Error::other)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parquet::file::reader::{FileReader, SerializedFileReader};
+
+    #[derive(Serialize)]
+    struct Pair {
+        left: String,
+        right: String,
+    }
+
+    impl Row for Pair {
+        fn values(&self) -> Vec<String> {
+            vec![self.left.clone(), self.right.clone()]
+        }
+    }
+
+    fn pair(left: &str, right: &str) -> Pair {
+        Pair {
+            left: left.to_string(),
+            right: right.to_string(),
+        }
+    }
+
+    fn write_pairs(format: OutputFormat, path: &str, pairs: &[Pair]) {
+        write_pairs_with(format, path, pairs, &CsvOptions::default());
+    }
+
+    fn write_pairs_with(format: OutputFormat, path: &str, pairs: &[Pair], options: &CsvOptions) {
+        let mut writer = open_writer::<Pair>(format, path, &["Left", "Right"], options).unwrap();
+        for p in pairs {
+            writer.write(p).unwrap();
+        }
+        writer.finish().unwrap();
+    }
+
+    #[test]
+    fn test_format_from_str() {
+        assert_eq!(
+            "JSONL".parse::<OutputFormat>().unwrap(),
+            OutputFormat::Jsonl
+        );
+        let err = "xml".parse::<OutputFormat>().unwrap_err().to_string();
+        assert!(err.contains("Unknown output format 'xml'"));
+        assert!(err.contains("csv, tsv, jsonl, json, parquet"));
+    }
+
+    #[test]
+    fn test_format_resolve() {
+        assert_eq!(
+            OutputFormat::resolve(None, "out.parquet"),
+            OutputFormat::Parquet
+        );
+        assert_eq!(
+            OutputFormat::resolve(None, "out.NDJSON"),
+            OutputFormat::Jsonl
+        );
+        assert_eq!(OutputFormat::resolve(None, "out.txt"), OutputFormat::Csv);
+        assert_eq!(
+            OutputFormat::resolve(Some(OutputFormat::Tsv), "out.json"),
+            OutputFormat::Tsv
+        );
+    }
+
+    #[test]
+    fn test_tsv_writer() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("out.tsv");
+        let path = path.to_str().unwrap();
+        write_pairs(OutputFormat::Tsv, path, &[pair("a", "b")]);
+        assert_eq!(
+            std::fs::read_to_string(path).unwrap(),
+            "Left\tRight\na\tb\n"
+        );
+    }
+
+    #[test]
+    fn test_csv_options() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("out.csv");
+        let path = path.to_str().unwrap();
+        let options = CsvOptions {
+            delimiter: Some(b','),
+            quoting: Quoting::Always,
+            header: false,
+            crlf: true,
+        };
+        write_pairs_with(
+            OutputFormat::Csv,
+            path,
+            &[pair("a", "b"), pair("c", "")],
+            &options,
+        );
+        assert_eq!(
+            std::fs::read_to_string(path).unwrap(),
+            "\"a\",\"b\"\r\n\"c\",\"\"\r\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_delimiter() {
+        assert_eq!(parse_delimiter(",").unwrap(), b',');
+        assert_eq!(parse_delimiter("tab").unwrap(), b'\t');
+        assert_eq!(parse_delimiter("\\t").unwrap(), b'\t');
+        assert!(parse_delimiter("ab").is_err());
+        assert!(parse_delimiter("\"").is_err());
+        assert!(parse_delimiter("").is_err());
+    }
+
+    #[test]
+    fn test_quoting_from_str() {
+        assert_eq!(
+            "Non-Numeric".parse::<Quoting>().unwrap(),
+            Quoting::NonNumeric
+        );
+        assert!("sometimes".parse::<Quoting>().is_err());
+    }
+
+    #[test]
+    fn test_jsonl_writer() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("out.jsonl");
+        let path = path.to_str().unwrap();
+        write_pairs(OutputFormat::Jsonl, path, &[pair("a", "b"), pair("c", "")]);
+        assert_eq!(
+            std::fs::read_to_string(path).unwrap(),
+            "{\"left\":\"a\",\"right\":\"b\"}\n{\"left\":\"c\",\"right\":\"\"}\n"
+        );
+    }
+
+    #[test]
+    fn test_json_array_writer() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("out.json");
+        let path = path.to_str().unwrap();
+
+        write_pairs(OutputFormat::Json, path, &[pair("a", "b"), pair("c", "d")]);
+        let parsed: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+        assert_eq!(parsed.as_array().unwrap().len(), 2);
+        assert_eq!(parsed[1]["left"], "c");
+
+        write_pairs(OutputFormat::Json, path, &[]);
+        assert_eq!(std::fs::read_to_string(path).unwrap(), "[]\n");
+    }
+
+    #[test]
+    fn test_parquet_writer() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("out.parquet");
+        let path = path.to_str().unwrap();
+        write_pairs(
+            OutputFormat::Parquet,
+            path,
+            &[pair("a", "b"), pair("c", "d")],
+        );
+
+        let reader = SerializedFileReader::new(File::open(path).unwrap()).unwrap();
+        let metadata = reader.metadata().file_metadata();
+        assert_eq!(metadata.num_rows(), 2);
+        let columns: Vec<&str> = metadata
+            .schema_descr()
+            .columns()
+            .iter()
+            .map(|c| c.name())
+            .collect();
+        assert_eq!(columns, ["Left", "Right"]);
+    }
+}
@@ -0,0 +1,476 @@
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::Rng;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use crate::error::{Error, Result};
+use crate::generators::{NamedPattern, Traced};
+
+/// A variance profile file, with one section per subcommand.
+///
+/// ```toml
+/// [addresses]
+/// error_rate = 0.4
+/// num_variances = [0.7, 0.2, 0.1]   # weights for 1, 2 and 3 variances
+///
+/// [addresses.weights]
+/// add_unit = 5.0
+/// remove_zip = 2.0
+/// po_box = 0.0                      # 0 disables a pattern
+///
+/// [addresses.field_error_rates]
+/// zip = 0.05
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(try_from = "BTreeMap<String, ProfileSection>")]
+pub struct ProfileFile {
+    pub sections: BTreeMap<String, ProfileSection>,
+}
+
+/// The profile sections the subcommands read; `people` reads the
+/// sections of its components.
+pub const SECTIONS: &[&str] = &[
+    "addresses",
+    "names",
+    "emails",
+    "phones",
+    "dates",
+    "tins",
+    "companies",
+    "intl_addresses",
+];
+
+/// Variance settings for a single record type.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProfileSection {
+    /// Probability that a record gets record-level variance (overrides the default)
+    pub error_rate: Option<f64>,
+    /// Relative weights for applying 1, 2, 3, ... patterns to a varied record
+    pub num_variances: Option<Vec<f64>>,
+    /// Relative weight per pattern name; unlisted patterns weigh 1.0
+    #[serde(default)]
+    pub weights: BTreeMap<String, f64>,
+    /// Independent probability per field of an extra variance on that field
+    #[serde(default)]
+    pub field_error_rates: BTreeMap<String, f64>,
+}
+
+impl TryFrom<BTreeMap<String, ProfileSection>> for ProfileFile {
+    type Error = String;
+
+    fn try_from(sections: BTreeMap<String, ProfileSection>) -> std::result::Result<Self, String> {
+        if let Some(name) = sections
+            .keys()
+            .find(|name| !SECTIONS.contains(&name.as_str()))
+        {
+            return Err(format!(
+                "Unknown profile section '{}'. Valid sections: {}",
+                name,
+                SECTIONS.join(", ")
+            ));
+        }
+        Ok(Self { sections })
+    }
+}
+
+impl ProfileFile {
+    /// Returns the section for a subcommand, or an empty section if absent.
+    pub fn section(&self, name: &str) -> ProfileSection {
+        self.sections.get(name).cloned().unwrap_or_default()
+    }
+}
+
+/// Loads a profile from a `.json` file, or from TOML for any other extension.
+pub fn load_profile(path: &str) -> Result<ProfileFile> {
+    let contents = fs::read_to_string(path)?;
+    let is_json = Path::new(path)
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+
+    let parsed = if is_json {
+        serde_json::from_str(&contents).map_err(|e| e.to_string())
+    } else {
+        toml::from_str(&contents).map_err(|e| e.to_string())
+    };
+
+    parsed.map_err(|e| Error::InvalidArgument(format!("Invalid profile {}: {}", path, e)))
+}
+
+/// How many times a pattern that changed nothing is redrawn before giving up.
+pub const MAX_PATTERN_DRAWS: usize = 8;
+
+/// Weighted variance settings used by the generators.
+///
+/// Built from the patterns enabled on the command line, optionally
+/// reweighted by a [`ProfileSection`]. [`VarianceProfile::uniform`] reproduces
+/// the default behaviour: every pattern equally likely, 1-3 per record.
+#[derive(Debug, Clone)]
+pub struct VarianceProfile<P> {
+    error_rate: Option<f64>,
+    patterns: Vec<P>,
+    weights: Vec<f64>,
+    pattern_index: Option<WeightedIndex<f64>>,
+    count_index: WeightedIndex<f64>,
+    field_error_rates: Vec<(&'static str, f64)>,
+}
+
+impl<P: NamedPattern> VarianceProfile<P> {
+    /// Every pattern in `patterns` equally likely, 1-3 patterns per varied record.
+    ///
+    /// An empty `patterns` slice yields a profile that never changes a record.
+    pub fn uniform(patterns: &[P]) -> Self {
+        Self {
+            error_rate: None,
+            patterns: patterns.to_vec(),
+            weights: vec![1.0; patterns.len()],
+            pattern_index: WeightedIndex::new(vec![1.0; patterns.len()]).ok(),
+            count_index: WeightedIndex::new([1.0; 3]).expect("constant weights are valid"),
+            field_error_rates: Vec::new(),
+        }
+    }
+
+    /// Applies a profile section to the enabled `patterns`.
+    ///
+    /// Patterns not enabled are ignored even if the section weights them.
+    pub fn from_section(patterns: &[P], section: &ProfileSection) -> Result<Self> {
+        for name in section.weights.keys() {
+            if P::from_name(name).is_none() {
+                return Err(Error::InvalidArgument(format!(
+                    "Unknown variance pattern '{}' in profile",
+                    name
+                )));
+            }
+        }
+
+        let mut enabled = Vec::new();
+        let mut weights = Vec::new();
+        for &pattern in patterns {
+            let weight = section
+                .weights
+                .iter()
+                .find(|(name, _)| P::from_name(name) == Some(pattern))
+                .map_or(1.0, |(_, &weight)| weight);
+            if weight < 0.0 || !weight.is_finite() {
+                return Err(Error::InvalidArgument(format!(
+                    "Weight for '{}' must be a non-negative number",
+                    pattern.name()
+                )));
+            }
+            if weight > 0.0 {
+                enabled.push(pattern);
+                weights.push(weight);
+            }
+        }
+
+        let mut field_error_rates = Vec::new();
+        for (field, &rate) in &section.field_error_rates {
+            let known = P::ALL
+                .iter()
+                .flat_map(|p| p.fields().iter())
+                .find(|f| **f == field.as_str())
+                .ok_or_else(|| {
+                    Error::InvalidArgument(format!("Unknown field '{}' in profile", field))
+                })?;
+            if !(0.0..=1.0).contains(&rate) {
+                return Err(Error::InvalidArgument(format!(
+                    "Error rate for field '{}' must be between 0.0 and 1.0",
+                    field
+                )));
+            }
+            field_error_rates.push((*known, rate));
+        }
+
+        let num_variances = section.num_variances.as_deref().unwrap_or(&[1.0; 3]);
+        Self::build(
+            section.error_rate,
+            enabled,
+            weights,
+            num_variances,
+            field_error_rates,
+        )
+    }
+
+    fn build(
+        error_rate: Option<f64>,
+        patterns: Vec<P>,
+        weights: Vec<f64>,
+        num_variances: &[f64],
+        field_error_rates: Vec<(&'static str, f64)>,
+    ) -> Result<Self> {
+        if let Some(rate) = error_rate {
+            crate::generators::validate_error_rate(rate)?;
+        }
+        let pattern_index = WeightedIndex::new(&weights).map_err(|_| {
+            Error::InvalidArgument("Profile must leave at least one pattern weighted".to_string())
+        })?;
+        let count_index = WeightedIndex::new(num_variances).map_err(|_| {
+            Error::InvalidArgument(
+                "num_variances must hold non-negative weights with at least one above zero"
+                    .to_string(),
+            )
+        })?;
+
+        Ok(Self {
+            error_rate,
+            patterns,
+            weights,
+            pattern_index: Some(pattern_index),
+            count_index,
+            field_error_rates,
+        })
+    }
+
+    /// The record-level error rate set by the profile, if any.
+    pub fn error_rate(&self) -> Option<f64> {
+        self.error_rate
+    }
+
+    /// The patterns this profile can apply.
+    pub fn patterns(&self) -> &[P] {
+        &self.patterns
+    }
+
+    /// Picks how many patterns to apply to a varied record.
+    pub fn choose_count<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
+        self.count_index.sample(rng) + 1
+    }
+
+    /// Picks a pattern according to the profile weights, or `None` if the
+    /// profile has no patterns.
+    pub fn choose_pattern<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<P> {
+        let index = self.pattern_index.as_ref()?;
+        Some(self.patterns[index.sample(rng)])
+    }
+
+    /// Picks a weighted pattern among those that touch `field`, if any.
+    pub fn choose_pattern_for_field<R: Rng + ?Sized>(&self, field: &str, rng: &mut R) -> Option<P> {
+        let candidates: Vec<(P, f64)> = self
+            .patterns
+            .iter()
+            .zip(&self.weights)
+            .filter(|(p, _)| p.fields().contains(&field))
+            .map(|(p, w)| (*p, *w))
+            .collect();
+        let index = WeightedIndex::new(candidates.iter().map(|(_, w)| *w)).ok()?;
+        Some(candidates[index.sample(rng)].0)
+    }
+
+    /// Applies 1 or more weighted patterns to a record, recording what changed.
+    ///
+    /// A drawn pattern that has nothing to act on (a second surname on a
+    /// name with one, an OCR confusion in a string without any) is redrawn,
+    /// up to [`MAX_PATTERN_DRAWS`] times, so that patterns which only fit
+    /// some records do not dilute the error rate of the rest.
+    pub fn trace<T, R, F>(&self, record: T, rng: &mut R, mut apply: F) -> Traced<T>
+    where
+        T: Clone,
+        R: Rng + ?Sized,
+        F: FnMut(&mut T, P, &mut R) -> Option<String>,
+    {
+        let clean = record.clone();
+        let mut record = record;
+        let mut variances = Vec::new();
+
+        if self.pattern_index.is_some() {
+            for _ in 0..self.choose_count(rng) {
+                for _ in 0..MAX_PATTERN_DRAWS {
+                    let Some(pattern) = self.choose_pattern(rng) else {
+                        break;
+                    };
+                    if let Some(label) = apply(&mut record, pattern, rng) {
+                        variances.push(label);
+                        break;
+                    }
+                }
+            }
+        }
+
+        Traced {
+            record,
+            clean,
+            variances,
+        }
+    }
+
+    /// Applies record-level variance with probability `error_rate`, then each
+    /// configured field error rate independently.
+    pub fn vary<T, R, F>(&self, record: T, error_rate: f64, rng: &mut R, mut apply: F) -> Traced<T>
+    where
+        T: Clone,
+        R: Rng + ?Sized,
+        F: FnMut(&mut T, P, &mut R) -> Option<String>,
+    {
+        let mut traced = if rng.gen_bool(error_rate) {
+            self.trace(record, rng, &mut apply)
+        } else {
+            Traced::unchanged(record)
+        };
+
+        for &(field, rate) in &self.field_error_rates {
+            if rng.gen_bool(rate) {
+                for _ in 0..MAX_PATTERN_DRAWS {
+                    let Some(pattern) = self.choose_pattern_for_field(field, rng) else {
+                        break;
+                    };
+                    if let Some(label) = apply(&mut traced.record, pattern, rng) {
+                        traced.variances.push(label);
+                        break;
+                    }
+                }
+            }
+        }
+
+        traced
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::addresses::VariancePattern;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn section(toml_str: &str) -> ProfileSection {
+        let file: ProfileFile = toml::from_str(toml_str).unwrap();
+        file.section("addresses")
+    }
+
+    #[test]
+    fn test_profile_file_sections() {
+        let file: ProfileFile =
+            toml::from_str("[addresses]\nerror_rate = 0.2\n\n[names.weights]\ntypo = 3.0\n")
+                .unwrap();
+        assert_eq!(file.section("addresses").error_rate, Some(0.2));
+        assert_eq!(file.section("names").weights.get("typo"), Some(&3.0));
+        assert!(file.section("phones").weights.is_empty());
+    }
+
+    #[test]
+    fn test_profile_file_json() {
+        let file: ProfileFile =
+            serde_json::from_str(r#"{"addresses": {"num_variances": [1.0, 0.0]}}"#).unwrap();
+        assert_eq!(
+            file.section("addresses").num_variances,
+            Some(vec![1.0, 0.0])
+        );
+    }
+
+    #[test]
+    fn test_unknown_section_key_rejected() {
+        let result: std::result::Result<ProfileFile, _> =
+            toml::from_str("[addresses]\nerorr_rate = 0.2\n");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_unknown_section_name_rejected() {
+        for contents in [
+            "[adresses]\nerror_rate = 0.2\n",
+            "[name.weights]\ntypo = 3.0\n",
+        ] {
+            let err = toml::from_str::<ProfileFile>(contents)
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains("Unknown profile section"), "{}", err);
+        }
+        let err = serde_json::from_str::<ProfileFile>(r#"{"people": {}}"#)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Valid sections: addresses, names"), "{}", err);
+    }
+
+    #[test]
+    fn test_zero_weight_disables_pattern() {
+        let profile = VarianceProfile::from_section(
+            VariancePattern::ALL,
+            &section("[addresses.weights]\npo_box = 0.0\n"),
+        )
+        .unwrap();
+        assert!(!profile.patterns().contains(&VariancePattern::PoBox));
+        assert_eq!(profile.patterns().len(), VariancePattern::ALL.len() - 1);
+    }
+
+    #[test]
+    fn test_weights_bias_selection() {
+        let profile = VarianceProfile::from_section(
+            &[VariancePattern::RemoveZip, VariancePattern::RemoveCity],
+            &section("[addresses.weights]\nremove_zip = 99.0\nremove_city = 1.0\n"),
+        )
+        .unwrap();
+        let mut rng = StdRng::seed_from_u64(1);
+        let zips = (0..1000)
+            .filter(|_| profile.choose_pattern(&mut rng) == Some(VariancePattern::RemoveZip))
+            .count();
+        assert!(zips > 900, "expected remove_zip to dominate, got {}", zips);
+    }
+
+    #[test]
+    fn test_num_variances_distribution() {
+        let profile = VarianceProfile::from_section(
+            VariancePattern::ALL,
+            &section("[addresses]\nnum_variances = [0.0, 1.0]\n"),
+        )
+        .unwrap();
+        let mut rng = StdRng::seed_from_u64(1);
+        for _ in 0..50 {
+            assert_eq!(profile.choose_count(&mut rng), 2);
+        }
+    }
+
+    #[test]
+    fn test_unknown_pattern_and_field_rejected() {
+        assert!(VarianceProfile::from_section(
+            VariancePattern::ALL,
+            &section("[addresses.weights]\nnot_a_pattern = 1.0\n"),
+        )
+        .is_err());
+        assert!(VarianceProfile::from_section(
+            VariancePattern::ALL,
+            &section("[addresses.field_error_rates]\nplanet = 0.1\n"),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_uniform_empty_never_varies() {
+        let profile: VarianceProfile<VariancePattern> = VarianceProfile::uniform(&[]);
+        let mut rng = StdRng::seed_from_u64(1);
+        let traced = profile.trace(1, &mut rng, |n: &mut i32, _, _| {
+            *n += 1;
+            Some("changed".to_string())
+        });
+        assert_eq!(traced.record, 1);
+        assert!(traced.variances.is_empty());
+    }
+
+    #[test]
+    fn test_choose_pattern_for_field() {
+        let profile = VarianceProfile::uniform(VariancePattern::ALL);
+        let mut rng = StdRng::seed_from_u64(1);
+        for _ in 0..50 {
+            let pattern = profile.choose_pattern_for_field("zip", &mut rng).unwrap();
+            assert!(pattern.fields().contains(&"zip"));
+        }
+    }
+
+    #[test]
+    fn test_patterns_that_change_nothing_are_redrawn() {
+        let profile =
+            VarianceProfile::uniform(&[VariancePattern::RemoveZip, VariancePattern::RemoveCity]);
+        let mut rng = StdRng::seed_from_u64(1);
+        for _ in 0..50 {
+            let traced = profile.trace(0, &mut rng, |n: &mut i32, pattern, _| {
+                (pattern == VariancePattern::RemoveCity).then(|| {
+                    *n += 1;
+                    pattern.to_string()
+                })
+            });
+            assert!(!traced.variances.is_empty());
+            assert!(traced.variances.iter().all(|v| v == "remove_city"));
+        }
+    }
+}
@@ -1,3 +1,8 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::Error;
+
 /// OpenAddresses.io regional data source URLs
 pub const REGION_NORTHEAST: &str =
     "https://data.openaddresses.io/openaddr-collected-us_northeast.zip";
@@ -96,7 +101,6 @@ pub fn is_valid_state(state: &str) -> bool {
 /// assert_eq!(get_state_path_name("DC"), Some("dc".to_string()));
 /// assert_eq!(get_state_path_name("invalid"), None);
 /// ```
-#[allow(dead_code)]
 pub fn get_state_path_name(state: &str) -> Option<String> {
     if is_valid_state(state) {
         Some(state.to_lowercase())
@@ -105,6 +109,256 @@ pub fn get_state_path_name(state: &str) -> Option<String> {
     }
 }
 
+/// Full names of the states in [`ALL_STATES`], in the same order.
+pub const STATE_NAMES: [&str; 51] = [
+    "Alaska",
+    "Alabama",
+    "Arkansas",
+    "Arizona",
+    "California",
+    "Colorado",
+    "Connecticut",
+    "District of Columbia",
+    "Delaware",
+    "Florida",
+    "Georgia",
+    "Hawaii",
+    "Iowa",
+    "Idaho",
+    "Illinois",
+    "Indiana",
+    "Kansas",
+    "Kentucky",
+    "Louisiana",
+    "Massachusetts",
+    "Maryland",
+    "Maine",
+    "Michigan",
+    "Minnesota",
+    "Missouri",
+    "Mississippi",
+    "Montana",
+    "North Carolina",
+    "North Dakota",
+    "Nebraska",
+    "New Hampshire",
+    "New Jersey",
+    "New Mexico",
+    "Nevada",
+    "New York",
+    "Ohio",
+    "Oklahoma",
+    "Oregon",
+    "Pennsylvania",
+    "Rhode Island",
+    "South Carolina",
+    "South Dakota",
+    "Tennessee",
+    "Texas",
+    "Utah",
+    "Virginia",
+    "Vermont",
+    "Washington",
+    "Wisconsin",
+    "West Virginia",
+    "Wyoming",
+];
+
+/// A country with bundled address data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Country {
+    UnitedStates,
+    Canada,
+    UnitedKingdom,
+    Germany,
+    Mexico,
+}
+
+impl Country {
+    /// Every supported country, in declaration order
+    pub const ALL: [Country; 5] = [
+        Country::UnitedStates,
+        Country::Canada,
+        Country::UnitedKingdom,
+        Country::Germany,
+        Country::Mexico,
+    ];
+
+    /// The ISO 3166-1 alpha-2 code, as used with `--country`
+    pub fn code(&self) -> &'static str {
+        match self {
+            Country::UnitedStates => "US",
+            Country::Canada => "CA",
+            Country::UnitedKingdom => "GB",
+            Country::Germany => "DE",
+            Country::Mexico => "MX",
+        }
+    }
+
+    /// The English short name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Country::UnitedStates => "United States",
+            Country::Canada => "Canada",
+            Country::UnitedKingdom => "United Kingdom",
+            Country::Germany => "Germany",
+            Country::Mexico => "Mexico",
+        }
+    }
+
+    /// Other names the country goes by in real data: alpha-3 codes, common
+    /// abbreviations and local-language names.
+    pub fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            Country::UnitedStates => &["USA", "U.S.A.", "U.S.", "United States of America"],
+            Country::Canada => &["CAN"],
+            Country::UnitedKingdom => &["GBR", "UK", "U.K.", "Great Britain"],
+            Country::Germany => &["DEU", "Deutschland"],
+            Country::Mexico => &["MEX", "México", "Estados Unidos Mexicanos"],
+        }
+    }
+
+    /// Whether addresses in this country conventionally write the region
+    /// as a code (`ON`) rather than a name (`Bayern`).
+    pub fn abbreviates_region(&self) -> bool {
+        matches!(self, Country::UnitedStates | Country::Canada)
+    }
+
+    /// The country's first-level subdivisions as `(code, name)` pairs.
+    /// UK counties have no postal code, so their code is empty.
+    pub fn subdivisions(&self) -> Vec<(&'static str, &'static str)> {
+        match self {
+            Country::UnitedStates => ALL_STATES.iter().copied().zip(STATE_NAMES).collect(),
+            Country::Canada => CANADIAN_PROVINCES.to_vec(),
+            Country::UnitedKingdom => UK_COUNTIES.iter().map(|name| ("", *name)).collect(),
+            Country::Germany => GERMAN_STATES.to_vec(),
+            Country::Mexico => MEXICAN_STATES.to_vec(),
+        }
+    }
+}
+
+impl fmt::Display for Country {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl FromStr for Country {
+    type Err = Error;
+
+    /// Parses an ISO-2 code, English name or alias, case-insensitively.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim();
+        Country::ALL
+            .iter()
+            .copied()
+            .find(|country| {
+                std::iter::once(country.code())
+                    .chain(std::iter::once(country.name()))
+                    .chain(country.aliases().iter().copied())
+                    .any(|candidate| candidate.to_lowercase() == name.to_lowercase())
+            })
+            .ok_or_else(|| {
+                let valid: Vec<&str> = Country::ALL.iter().map(|c| c.code()).collect();
+                Error::InvalidArgument(format!(
+                    "Unknown country '{}'. Supported countries: {}",
+                    s,
+                    valid.join(", ")
+                ))
+            })
+    }
+}
+
+/// Canadian provinces and territories.
+pub const CANADIAN_PROVINCES: &[(&str, &str)] = &[
+    ("AB", "Alberta"),
+    ("BC", "British Columbia"),
+    ("MB", "Manitoba"),
+    ("NB", "New Brunswick"),
+    ("NL", "Newfoundland and Labrador"),
+    ("NS", "Nova Scotia"),
+    ("NT", "Northwest Territories"),
+    ("NU", "Nunavut"),
+    ("ON", "Ontario"),
+    ("PE", "Prince Edward Island"),
+    ("QC", "Quebec"),
+    ("SK", "Saskatchewan"),
+    ("YT", "Yukon"),
+];
+
+/// UK counties and council areas used in bundled addresses.
+pub const UK_COUNTIES: &[&str] = &[
+    "Bristol",
+    "Cambridgeshire",
+    "Cardiff",
+    "City of Edinburgh",
+    "County Antrim",
+    "Glasgow City",
+    "Greater London",
+    "Greater Manchester",
+    "Merseyside",
+    "Oxfordshire",
+    "Tyne and Wear",
+    "West Midlands",
+    "West Yorkshire",
+];
+
+/// German states (Länder), with their ISO 3166-2 codes.
+pub const GERMAN_STATES: &[(&str, &str)] = &[
+    ("BB", "Brandenburg"),
+    ("BE", "Berlin"),
+    ("BW", "Baden-Württemberg"),
+    ("BY", "Bayern"),
+    ("HB", "Bremen"),
+    ("HE", "Hessen"),
+    ("HH", "Hamburg"),
+    ("MV", "Mecklenburg-Vorpommern"),
+    ("NI", "Niedersachsen"),
+    ("NW", "Nordrhein-Westfalen"),
+    ("RP", "Rheinland-Pfalz"),
+    ("SH", "Schleswig-Holstein"),
+    ("SL", "Saarland"),
+    ("SN", "Sachsen"),
+    ("ST", "Sachsen-Anhalt"),
+    ("TH", "Thüringen"),
+];
+
+/// Mexican states, with their ISO 3166-2 codes.
+pub const MEXICAN_STATES: &[(&str, &str)] = &[
+    ("AGU", "Aguascalientes"),
+    ("BCN", "Baja California"),
+    ("BCS", "Baja California Sur"),
+    ("CAM", "Campeche"),
+    ("CHH", "Chihuahua"),
+    ("CHP", "Chiapas"),
+    ("CMX", "Ciudad de México"),
+    ("COA", "Coahuila"),
+    ("COL", "Colima"),
+    ("DUR", "Durango"),
+    ("GRO", "Guerrero"),
+    ("GUA", "Guanajuato"),
+    ("HID", "Hidalgo"),
+    ("JAL", "Jalisco"),
+    ("MEX", "Estado de México"),
+    ("MIC", "Michoacán"),
+    ("MOR", "Morelos"),
+    ("NAY", "Nayarit"),
+    ("NLE", "Nuevo León"),
+    ("OAX", "Oaxaca"),
+    ("PUE", "Puebla"),
+    ("QUE", "Querétaro"),
+    ("ROO", "Quintana Roo"),
+    ("SIN", "Sinaloa"),
+    ("SLP", "San Luis Potosí"),
+    ("SON", "Sonora"),
+    ("TAB", "Tabasco"),
+    ("TAM", "Tamaulipas"),
+    ("TLA", "Tlaxcala"),
+    ("VER", "Veracruz"),
+    ("YUC", "Yucatán"),
+    ("ZAC", "Zacatecas"),
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -244,4 +498,32 @@ mod tests {
         assert_eq!(get_region_url("DC"), Some(REGION_SOUTH));
         assert!(is_valid_state("DC"));
     }
+
+    #[test]
+    fn test_state_names_align_with_codes() {
+        let states = Country::UnitedStates.subdivisions();
+        assert!(states.contains(&("IL", "Illinois")));
+        assert!(states.contains(&("DC", "District of Columbia")));
+        assert!(states.contains(&("WV", "West Virginia")));
+    }
+
+    #[test]
+    fn test_country_from_str() {
+        assert_eq!("ca".parse::<Country>().unwrap(), Country::Canada);
+        assert_eq!("UK".parse::<Country>().unwrap(), Country::UnitedKingdom);
+        assert_eq!("deutschland".parse::<Country>().unwrap(), Country::Germany);
+        assert_eq!("México".parse::<Country>().unwrap(), Country::Mexico);
+        assert_eq!(
+            "United States".parse::<Country>().unwrap(),
+            Country::UnitedStates
+        );
+        assert!("FR".parse::<Country>().is_err());
+    }
+
+    #[test]
+    fn test_every_country_has_subdivisions() {
+        for country in Country::ALL {
+            assert!(!country.subdivisions().is_empty(), "{}", country);
+        }
+    }
 }
@@ -1,92 +1,513 @@
 use indicatif::{ProgressBar, ProgressStyle};
+use serde::Serialize;
+use std::fmt;
 use std::io;
-use std::path::Path;
 
 use crate::generators::addresses::Address;
+use crate::generators::companies::Company;
+use crate::generators::dates::Date;
+use crate::generators::emails::Email;
+use crate::generators::international::InternationalAddress;
 use crate::generators::names::Name;
+use crate::generators::people::Person;
+use crate::generators::phones::Phone;
+use crate::generators::tins::Tin;
+use crate::generators::Traced;
+use crate::output::{
+    open_writer, CsvOptions, OutputFormat, OutputWriter, Quoting, Row, STDIO_PATH,
+};
+
+/// Separator between variance names in the ground-truth `Variances` column
+pub const VARIANCE_SEPARATOR: &str = ";";
+
+/// A record type the writer knows how to lay out in every output format.
+pub trait Record: Row + 'static {
+    /// Plural noun used in progress and error messages ("addresses")
+    const NOUN: &'static str;
+
+    /// Column names for tabular output formats
+    const HEADER: &'static [&'static str];
+}
+
+impl Row for Address {
+    fn values(&self) -> Vec<String> {
+        self.to_record()
+    }
+}
+
+impl Record for Address {
+    const NOUN: &'static str = "addresses";
+    const HEADER: &'static [&'static str] = &["Address1", "Address2", "City", "State", "Zip"];
+}
+
+impl Row for Name {
+    fn values(&self) -> Vec<String> {
+        self.to_record()
+    }
+}
+
+impl Record for Name {
+    const NOUN: &'static str = "names";
+    const HEADER: &'static [&'static str] = &["FirstName", "MiddleName", "LastName"];
+}
+
+impl Row for Email {
+    fn values(&self) -> Vec<String> {
+        self.to_record()
+    }
+}
+
+impl Record for Email {
+    const NOUN: &'static str = "emails";
+    const HEADER: &'static [&'static str] = &["Email"];
+}
+
+impl Row for Phone {
+    fn values(&self) -> Vec<String> {
+        self.to_record()
+    }
+}
+
+impl Record for Phone {
+    const NOUN: &'static str = "phones";
+    const HEADER: &'static [&'static str] = &["Phone"];
+}
+
+impl Row for Date {
+    fn values(&self) -> Vec<String> {
+        self.to_record()
+    }
+}
+
+impl Record for Date {
+    const NOUN: &'static str = "dates";
+    const HEADER: &'static [&'static str] = &["Date"];
+}
+
+impl Row for Tin {
+    fn values(&self) -> Vec<String> {
+        self.to_record()
+    }
+}
+
+impl Record for Tin {
+    const NOUN: &'static str = "identifiers";
+    const HEADER: &'static [&'static str] = &["TIN"];
+}
+
+impl Row for Person {
+    fn values(&self) -> Vec<String> {
+        self.to_record()
+    }
+}
 
+impl Record for Person {
+    const NOUN: &'static str = "people";
+    const HEADER: &'static [&'static str] = &[
+        "FirstName",
+        "MiddleName",
+        "LastName",
+        "Address1",
+        "Address2",
+        "City",
+        "State",
+        "Zip",
+        "Email",
+        "Phone",
+        "DOB",
+    ];
+}
+
+impl Row for Company {
+    fn values(&self) -> Vec<String> {
+        self.to_record()
+    }
+}
+
+impl Record for Company {
+    const NOUN: &'static str = "companies";
+    const HEADER: &'static [&'static str] = &[
+        "CompanyName",
+        "Address1",
+        "Address2",
+        "City",
+        "State",
+        "Zip",
+    ];
+}
+
+impl Row for InternationalAddress {
+    fn values(&self) -> Vec<String> {
+        self.to_record()
+    }
+}
+
+impl Record for InternationalAddress {
+    const NOUN: &'static str = "international addresses";
+    const HEADER: &'static [&'static str] = &[
+        "Address1",
+        "Address2",
+        "City",
+        "Region",
+        "PostalCode",
+        "Country",
+    ];
+}
+
+/// One row of a ground-truth file.
+///
+/// Tabular formats flatten it to `Row|<clean fields>|Variances` with the
+/// variances joined by [`VARIANCE_SEPARATOR`]; JSON formats write
+/// `{"row": 1, "clean": {...}, "variances": [...]}`. Rows of clustered
+/// datasets also carry an `EntityId` column after `Row` (`"entity_id"`
+/// in JSON).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GroundTruthRow<T> {
+    /// 1-based row number of the record in the main output
+    pub row: usize,
+    /// 1-based id shared by every copy of the same entity
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_id: Option<usize>,
+    pub clean: T,
+    pub variances: Vec<String>,
+}
+
+impl<T: Record> Row for GroundTruthRow<T> {
+    fn values(&self) -> Vec<String> {
+        let clean = self.clean.values();
+        let mut record = Vec::with_capacity(clean.len() + 3);
+        record.push(self.row.to_string());
+        if let Some(entity_id) = self.entity_id {
+            record.push(entity_id.to_string());
+        }
+        record.extend(clean);
+        record.push(self.variances.join(VARIANCE_SEPARATOR));
+        record
+    }
+}
+
+/// Writes generated datasets with progress reporting.
+///
+/// Despite the name, any [`OutputFormat`] can be written. By default each
+/// file's format follows its extension (`.jsonl`, `.json`, `.parquet`,
+/// `.tsv`), falling back to pipe-delimited CSV.
 pub struct CsvWriter {
     quiet: bool,
+    layout: Layout,
 }
 
 impl CsvWriter {
     pub fn new(quiet: bool) -> Self {
-        Self { quiet }
-    }
-
-    fn create_progress_bar(&self, count: usize, message: &str) -> ProgressBar {
-        if self.quiet || count <= 100 {
-            ProgressBar::hidden()
-        } else {
-            let pb = ProgressBar::new(count as u64);
-            pb.set_style(
-                ProgressStyle::default_bar()
-                    .template("{msg} [{bar:40.cyan/blue}] {percent}% ({pos}/{len})")
-                    .expect("Invalid progress bar template")
-                    .progress_chars("=>-"),
-            );
-            pb.set_message(message.to_string());
-            pb
+        Self {
+            quiet,
+            layout: Layout::default(),
         }
     }
 
-    pub fn write_addresses(&self, path: &str, addresses: &[Address]) -> io::Result<()> {
-        // Create parent directories if needed
-        if let Some(parent) = Path::new(path).parent() {
-            std::fs::create_dir_all(parent)?;
-        }
+    /// Writes every file in `format` regardless of its extension.
+    pub fn format(mut self, format: OutputFormat) -> Self {
+        self.layout.format = Some(format);
+        self
+    }
 
-        // Configure pipe delimiter
-        let mut builder = csv::WriterBuilder::new();
-        builder.delimiter(b'|');
-        let mut writer = builder.from_path(path)?;
+    /// Separates CSV and TSV fields with `delimiter` instead of the format's default.
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.layout.csv.delimiter = Some(delimiter);
+        self
+    }
 
-        // Write header
-        writer.write_record(["Address1", "Address2", "City", "State", "Zip"])?;
+    /// Sets when CSV and TSV fields are quoted.
+    pub fn quoting(mut self, quoting: Quoting) -> Self {
+        self.layout.csv.quoting = quoting;
+        self
+    }
+
+    /// Enables or disables the CSV and TSV header row.
+    pub fn header(mut self, header: bool) -> Self {
+        self.layout.csv.header = header;
+        self
+    }
 
-        // Create progress bar
-        let pb = self.create_progress_bar(addresses.len(), "Generating addresses");
+    /// Ends CSV and TSV lines with `\r\n`.
+    pub fn crlf(mut self, crlf: bool) -> Self {
+        self.layout.csv.crlf = crlf;
+        self
+    }
 
-        // Write records
-        for address in addresses {
-            writer.write_record(address.to_record())?;
-            pb.inc(1);
+    /// Renames the record columns in tabular output, e.g. `ADDR_LINE_1`.
+    ///
+    /// One name is required per column of the record being written; a
+    /// mismatch is reported when the file is opened. Ground-truth files use
+    /// the same names for their clean columns.
+    pub fn columns<S: Into<String>>(mut self, columns: impl IntoIterator<Item = S>) -> Self {
+        self.layout.columns = Some(columns.into_iter().map(Into::into).collect());
+        self
+    }
+
+    fn create_progress_bar(&self, count: Option<usize>, message: &str) -> ProgressBar {
+        match count {
+            _ if self.quiet => ProgressBar::hidden(),
+            Some(count) if count <= 100 => ProgressBar::hidden(),
+            Some(count) => {
+                let pb = ProgressBar::new(count as u64);
+                pb.set_style(
+                    ProgressStyle::default_bar()
+                        .template("{msg} [{bar:40.cyan/blue}] {percent}% ({pos}/{len})")
+                        .expect("Invalid progress bar template")
+                        .progress_chars("=>-"),
+                );
+                pb.set_message(message.to_string());
+                pb
+            }
+            None => {
+                let pb = ProgressBar::new_spinner();
+                pb.set_style(
+                    ProgressStyle::default_spinner()
+                        .template("{msg} {spinner} {pos} records")
+                        .expect("Invalid progress bar template"),
+                );
+                pb.set_message(message.to_string());
+                pb
+            }
         }
+    }
 
-        pb.finish_and_clear();
-        writer.flush()?;
+    /// Opens a streaming sink that writes records to `path` as they arrive.
+    ///
+    /// `expected` sizes the progress bar; pass `None` when the record count
+    /// is not known up front (a spinner is shown instead). Because records
+    /// are usually generated lazily as they are written, the progress bar
+    /// tracks generation as well as writing.
+    pub fn open_sink<T: Record>(
+        &self,
+        path: &str,
+        expected: Option<usize>,
+        message: &str,
+    ) -> io::Result<RecordSink<T>> {
+        let header = self.layout.header::<T>()?;
+        let output_to_stdout = path == STDIO_PATH;
+        Ok(RecordSink {
+            writer: self
+                .layout
+                .open(path, &header)
+                .map_err(|e| mark_stdout_closed(output_to_stdout, e))?,
+            ground_truth: None,
+            output_to_stdout,
+            ground_truth_to_stdout: false,
+            layout: self.layout.clone(),
+            pb: self.create_progress_bar(expected, message),
+            rows: 0,
+        })
+    }
 
-        Ok(())
+    pub fn write_addresses(&self, path: &str, addresses: &[Address]) -> io::Result<()> {
+        self.write_records(path, addresses, "Generating addresses")
     }
 
     pub fn write_names(&self, path: &str, names: &[Name]) -> io::Result<()> {
-        // Create parent directories if needed
-        if let Some(parent) = Path::new(path).parent() {
-            std::fs::create_dir_all(parent)?;
+        self.write_records(path, names, "Generating names")
+    }
+
+    fn write_records<T: Record>(&self, path: &str, records: &[T], message: &str) -> io::Result<()> {
+        let mut sink = self.open_sink::<T>(path, Some(records.len()), message)?;
+        for record in records {
+            sink.write_record(record)?;
         }
+        sink.finish()?;
+        Ok(())
+    }
+}
 
-        // Configure pipe delimiter
-        let mut builder = csv::WriterBuilder::new();
-        builder.delimiter(b'|');
-        let mut writer = builder.from_path(path)?;
+/// Marks a broken pipe on standard output: its reader has stopped reading
+/// (e.g. `head` downstream of `-o -`), which is not a failure to write.
+#[derive(Debug)]
+struct StdoutClosed;
 
-        // Write header
-        writer.write_record(["FirstName", "MiddleName", "LastName"])?;
+impl fmt::Display for StdoutClosed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "standard output closed by its reader")
+    }
+}
+
+impl std::error::Error for StdoutClosed {}
+
+/// Whether a [`RecordSink`] error means the reader of standard output
+/// closed it, rather than that writing failed.
+pub fn is_stdout_closed(e: &io::Error) -> bool {
+    e.get_ref().is_some_and(|inner| inner.is::<StdoutClosed>())
+}
 
-        // Create progress bar
-        let pb = self.create_progress_bar(names.len(), "Generating names");
+/// Marks a broken pipe from a writer to standard output as [`StdoutClosed`].
+fn mark_stdout_closed(to_stdout: bool, e: io::Error) -> io::Error {
+    if to_stdout && e.kind() == io::ErrorKind::BrokenPipe {
+        io::Error::new(io::ErrorKind::BrokenPipe, StdoutClosed)
+    } else {
+        e
+    }
+}
+
+/// A streaming writer for one dataset and, optionally, its ground truth.
+///
+/// Created by [`CsvWriter::open_sink`]. Rows are written as they are
+/// received, so memory use does not grow with the dataset. Call
+/// [`RecordSink::finish`] to complete the files, also after a write fails
+/// with [`is_stdout_closed`], so the file not on standard output is
+/// complete.
+pub struct RecordSink<T: Record> {
+    writer: Box<dyn OutputWriter<T>>,
+    ground_truth: Option<Box<dyn OutputWriter<GroundTruthRow<T>>>>,
+    output_to_stdout: bool,
+    ground_truth_to_stdout: bool,
+    layout: Layout,
+    pb: ProgressBar,
+    rows: usize,
+}
+
+impl<T: Record> RecordSink<T> {
+    /// Also writes a ground-truth row to `path` for every traced record.
+    pub fn with_ground_truth(self, path: &str) -> io::Result<Self> {
+        self.attach_ground_truth(path, false)
+    }
+
+    /// Like [`RecordSink::with_ground_truth`], with an `EntityId` column.
+    /// Write records with [`RecordSink::write_entity`].
+    pub fn with_clustered_ground_truth(self, path: &str) -> io::Result<Self> {
+        self.attach_ground_truth(path, true)
+    }
 
-        // Write records
-        for name in names {
-            writer.write_record(name.to_record())?;
-            pb.inc(1);
+    fn attach_ground_truth(mut self, path: &str, entity_ids: bool) -> io::Result<Self> {
+        self.ground_truth_to_stdout = path == STDIO_PATH;
+        match open_ground_truth::<T>(&self.layout, path, entity_ids) {
+            Ok(writer) => {
+                self.ground_truth = Some(writer);
+                Ok(self)
+            }
+            Err(e) => {
+                let e = mark_stdout_closed(self.ground_truth_to_stdout, e);
+                // Still complete the dataset when only stdout was closed
+                if is_stdout_closed(&e) {
+                    self.finish()?;
+                }
+                Err(e)
+            }
         }
+    }
 
-        pb.finish_and_clear();
-        writer.flush()?;
+    /// Writes a traced record and, if enabled, its ground-truth row.
+    pub fn write(&mut self, traced: Traced<T>) -> io::Result<()> {
+        self.write_traced(None, traced)
+    }
+
+    /// Writes one copy of entity `entity_id` (1-based) and, if enabled, its
+    /// ground-truth row.
+    pub fn write_entity(&mut self, entity_id: usize, traced: Traced<T>) -> io::Result<()> {
+        self.write_traced(Some(entity_id), traced)
+    }
+
+    fn write_traced(&mut self, entity_id: Option<usize>, traced: Traced<T>) -> io::Result<()> {
+        self.write_record(&traced.record)?;
+        if let Some(writer) = &mut self.ground_truth {
+            writer
+                .write(&GroundTruthRow {
+                    row: self.rows,
+                    entity_id,
+                    clean: traced.clean,
+                    variances: traced.variances,
+                })
+                .map_err(|e| mark_stdout_closed(self.ground_truth_to_stdout, e))?;
+        }
+        Ok(())
+    }
 
+    /// Writes a record to the main output only.
+    fn write_record(&mut self, record: &T) -> io::Result<()> {
+        self.writer
+            .write(record)
+            .map_err(|e| mark_stdout_closed(self.output_to_stdout, e))?;
+        self.rows += 1;
+        self.pb.inc(1);
         Ok(())
     }
+
+    /// Completes both files and returns the number of records written.
+    ///
+    /// Each file is completed even if the other fails, and a failure
+    /// other than [`is_stdout_closed`] is the one reported.
+    pub fn finish(self) -> io::Result<usize> {
+        self.pb.finish_and_clear();
+        let output = self
+            .writer
+            .finish()
+            .map_err(|e| mark_stdout_closed(self.output_to_stdout, e));
+        let ground_truth = match self.ground_truth {
+            Some(writer) => writer
+                .finish()
+                .map_err(|e| mark_stdout_closed(self.ground_truth_to_stdout, e)),
+            None => Ok(()),
+        };
+        match (output, ground_truth) {
+            (Ok(()), Ok(())) => Ok(self.rows),
+            (Err(e), Ok(())) | (Ok(()), Err(e)) => Err(e),
+            (Err(e), Err(other)) => Err(if is_stdout_closed(&e) { other } else { e }),
+        }
+    }
+}
+
+/// Format and column options shared by every file a [`CsvWriter`] opens.
+#[derive(Debug, Clone, Default)]
+struct Layout {
+    format: Option<OutputFormat>,
+    csv: CsvOptions,
+    columns: Option<Vec<String>>,
+}
+
+impl Layout {
+    /// Column names for `T`, honouring any custom names.
+    fn header<T: Record>(&self) -> io::Result<Vec<String>> {
+        match &self.columns {
+            None => Ok(T::HEADER.iter().map(|name| name.to_string()).collect()),
+            Some(columns) if columns.len() == T::HEADER.len() => Ok(columns.clone()),
+            Some(columns) => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "Expected {} column names for {} ({}), got {}",
+                    T::HEADER.len(),
+                    T::NOUN,
+                    T::HEADER.join(", "),
+                    columns.len()
+                ),
+            )),
+        }
+    }
+
+    fn open<R: Row + 'static>(
+        &self,
+        path: &str,
+        header: &[String],
+    ) -> io::Result<Box<dyn OutputWriter<R>>> {
+        let header: Vec<&str> = header.iter().map(String::as_str).collect();
+        open_writer(
+            OutputFormat::resolve(self.format, path),
+            path,
+            &header,
+            &self.csv,
+        )
+    }
+}
+
+fn open_ground_truth<T: Record>(
+    layout: &Layout,
+    path: &str,
+    entity_ids: bool,
+) -> io::Result<Box<dyn OutputWriter<GroundTruthRow<T>>>> {
+    let mut header = Vec::with_capacity(T::HEADER.len() + 3);
+    header.push("Row".to_string());
+    if entity_ids {
+        header.push("EntityId".to_string());
+    }
+    header.extend(layout.header::<T>()?);
+    header.push("Variances".to_string());
+    layout.open(path, &header)
 }
 
 #[cfg(test)]
@@ -96,20 +517,20 @@ mod tests {
     #[test]
     fn test_csv_writer_creation() {
         let writer = CsvWriter::new(false);
-        assert_eq!(writer.quiet, false);
+        assert!(!writer.quiet);
     }
 
     #[test]
     fn test_create_progress_bar_quiet() {
         let writer = CsvWriter::new(true);
-        let pb = writer.create_progress_bar(1000, "Testing");
+        let pb = writer.create_progress_bar(Some(1000), "Testing");
         pb.finish_and_clear();
     }
 
     #[test]
     fn test_create_progress_bar_not_quiet() {
         let writer = CsvWriter::new(false);
-        let pb = writer.create_progress_bar(1000, "Testing");
+        let pb = writer.create_progress_bar(Some(1000), "Testing");
         pb.finish_and_clear();
     }
 
@@ -180,4 +601,192 @@ mod tests {
         assert!(contents.contains("Joshua|Allen|Caudill"));
         assert!(contents.contains("John||Doe"));
     }
+
+    #[test]
+    fn test_record_sink_streams_records_and_ground_truth() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("names.csv");
+        let truth_path = temp_dir.path().join("truth.csv");
+
+        let clean = Name::new("John".to_string(), "".to_string(), "Doe".to_string());
+        let varied = Traced {
+            record: Name::new("JOHN".to_string(), "".to_string(), "DOE".to_string()),
+            clean: clean.clone(),
+            variances: vec!["uppercase".to_string()],
+        };
+
+        let writer = CsvWriter::new(true);
+        let mut sink = writer
+            .open_sink::<Name>(path.to_str().unwrap(), None, "Testing")
+            .unwrap()
+            .with_ground_truth(truth_path.to_str().unwrap())
+            .unwrap();
+        sink.write(Traced::unchanged(clean)).unwrap();
+        sink.write(varied).unwrap();
+        assert_eq!(sink.finish().unwrap(), 2);
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(
+            contents.lines().collect::<Vec<_>>(),
+            ["FirstName|MiddleName|LastName", "John||Doe", "JOHN||DOE"]
+        );
+        let truth = std::fs::read_to_string(&truth_path).unwrap();
+        assert_eq!(
+            truth.lines().collect::<Vec<_>>(),
+            [
+                "Row|FirstName|MiddleName|LastName|Variances",
+                "1|John||Doe|",
+                "2|John||Doe|uppercase"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_only_a_broken_pipe_on_stdout_is_stdout_closed() {
+        let broken_pipe = || io::Error::from(io::ErrorKind::BrokenPipe);
+        assert!(is_stdout_closed(&mark_stdout_closed(true, broken_pipe())));
+        assert!(!is_stdout_closed(&mark_stdout_closed(false, broken_pipe())));
+        assert!(!is_stdout_closed(&mark_stdout_closed(
+            true,
+            io::Error::from(io::ErrorKind::PermissionDenied)
+        )));
+    }
+
+    #[test]
+    fn test_record_sink_empty_writes_header() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("addresses.csv");
+
+        let sink = CsvWriter::new(true)
+            .open_sink::<Address>(path.to_str().unwrap(), Some(0), "Testing")
+            .unwrap();
+        assert_eq!(sink.finish().unwrap(), 0);
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(contents, "Address1|Address2|City|State|Zip\n");
+    }
+
+    #[test]
+    fn test_record_sink_custom_columns_and_layout() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("names.csv");
+        let truth_path = temp_dir.path().join("truth.csv");
+
+        let writer = CsvWriter::new(true)
+            .delimiter(b',')
+            .quoting(Quoting::Always)
+            .crlf(true)
+            .columns(["FIRST", "MIDDLE", "LAST"]);
+        let mut sink = writer
+            .open_sink::<Name>(path.to_str().unwrap(), None, "Testing")
+            .unwrap()
+            .with_ground_truth(truth_path.to_str().unwrap())
+            .unwrap();
+        let name = Name::new("John".to_string(), "".to_string(), "Doe".to_string());
+        sink.write(Traced::unchanged(name)).unwrap();
+        sink.finish().unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "\"FIRST\",\"MIDDLE\",\"LAST\"\r\n\"John\",\"\",\"Doe\"\r\n"
+        );
+        let truth = std::fs::read_to_string(&truth_path).unwrap();
+        assert!(truth.starts_with("\"Row\",\"FIRST\",\"MIDDLE\",\"LAST\",\"Variances\"\r\n"));
+    }
+
+    #[test]
+    fn test_record_sink_without_header() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("names.csv");
+
+        let mut sink = CsvWriter::new(true)
+            .header(false)
+            .open_sink::<Name>(path.to_str().unwrap(), None, "Testing")
+            .unwrap();
+        let name = Name::new("John".to_string(), "".to_string(), "Doe".to_string());
+        sink.write(Traced::unchanged(name)).unwrap();
+        sink.finish().unwrap();
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "John||Doe\n");
+    }
+
+    #[test]
+    fn test_column_count_mismatch_fails() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("addresses.csv");
+
+        let result = CsvWriter::new(true)
+            .columns(["A", "B"])
+            .open_sink::<Address>(path.to_str().unwrap(), None, "Testing");
+        let err = result.err().expect("mismatched columns should fail");
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert!(err
+            .to_string()
+            .contains("Expected 5 column names for addresses"));
+    }
+
+    #[test]
+    fn test_address_ground_truth() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("addresses.csv");
+        let truth_path = temp_dir.path().join("truth.csv");
+
+        let clean = Address::new(
+            "123 Main Street".to_string(),
+            String::new(),
+            "Springfield".to_string(),
+            "IL".to_string(),
+            "62701".to_string(),
+        );
+        let mut varied = clean.clone();
+        varied.address1 = "123 Main St".to_string();
+        varied.zip = String::new();
+
+        let mut sink = CsvWriter::new(true)
+            .open_sink::<Address>(path.to_str().unwrap(), Some(2), "Testing")
+            .unwrap()
+            .with_ground_truth(truth_path.to_str().unwrap())
+            .unwrap();
+        sink.write(Traced {
+            record: varied,
+            clean: clean.clone(),
+            variances: vec!["abbreviate_suffix".to_string(), "remove_zip".to_string()],
+        })
+        .unwrap();
+        sink.write(Traced::unchanged(clean)).unwrap();
+        sink.finish().unwrap();
+
+        let contents = std::fs::read_to_string(&truth_path).unwrap();
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(lines[0], "Row|Address1|Address2|City|State|Zip|Variances");
+        assert_eq!(
+            lines[1],
+            "1|123 Main Street||Springfield|IL|62701|abbreviate_suffix;remove_zip"
+        );
+        assert_eq!(lines[2], "2|123 Main Street||Springfield|IL|62701|");
+    }
+
+    #[test]
+    fn test_name_ground_truth() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("names.csv");
+        let truth_path = temp_dir.path().join("truth.csv");
+
+        let mut sink = CsvWriter::new(true)
+            .open_sink::<Name>(path.to_str().unwrap(), Some(1), "Testing")
+            .unwrap()
+            .with_ground_truth(truth_path.to_str().unwrap())
+            .unwrap();
+        sink.write(Traced {
+            record: Name::new("Jhon".to_string(), "".to_string(), "Doe".to_string()),
+            clean: Name::new("John".to_string(), "".to_string(), "Doe".to_string()),
+            variances: vec!["typo:transpose".to_string()],
+        })
+        .unwrap();
+        sink.finish().unwrap();
+
+        let contents = std::fs::read_to_string(&truth_path).unwrap();
+        assert!(contents.contains("Row|FirstName|MiddleName|LastName|Variances"));
+        assert!(contents.contains("1|John||Doe|typo:transpose"));
+    }
 }
@@ -40,7 +40,7 @@ fn test_addresses_command_creates_file() {
     let output_str = output_path.to_str().unwrap();
 
     let output = Command::new(get_binary_path())
-        .args(&[
+        .args([
             "addresses",
             "--count",
             "10",
@@ -74,7 +74,7 @@ fn test_addresses_correct_format() {
     let output_str = output_path.to_str().unwrap();
 
     let output = Command::new(get_binary_path())
-        .args(&[
+        .args([
             "addresses",
             "--count",
             "5",
@@ -115,7 +115,7 @@ fn test_addresses_pipe_delimiter() {
     let output_str = output_path.to_str().unwrap();
 
     let output = Command::new(get_binary_path())
-        .args(&[
+        .args([
             "addresses",
             "--count",
             "3",
@@ -145,7 +145,7 @@ fn test_names_command_creates_file() {
     let output_str = output_path.to_str().unwrap();
 
     let output = Command::new(get_binary_path())
-        .args(&[
+        .args([
             "names",
             "--count",
             "10",
@@ -179,7 +179,7 @@ fn test_names_correct_format() {
     let output_str = output_path.to_str().unwrap();
 
     let output = Command::new(get_binary_path())
-        .args(&[
+        .args([
             "names",
             "--count",
             "5",
@@ -220,7 +220,7 @@ fn test_names_pipe_delimiter() {
     let output_str = output_path.to_str().unwrap();
 
     let output = Command::new(get_binary_path())
-        .args(&[
+        .args([
             "names",
             "--count",
             "3",
@@ -258,7 +258,7 @@ fn test_invalid_count_zero() {
     let output_str = output_path.to_str().unwrap();
 
     let output = Command::new(get_binary_path())
-        .args(&["addresses", "--count", "0", "--output", output_str])
+        .args(["addresses", "--count", "0", "--output", output_str])
         .output()
         .expect("Failed to execute command");
 
@@ -277,7 +277,7 @@ fn test_invalid_error_rate_negative() {
     let output_str = output_path.to_str().unwrap();
 
     let output = Command::new(get_binary_path())
-        .args(&[
+        .args([
             "addresses",
             "--count",
             "10",
@@ -309,7 +309,7 @@ fn test_invalid_error_rate_above_one() {
     let output_str = output_path.to_str().unwrap();
 
     let output = Command::new(get_binary_path())
-        .args(&[
+        .args([
             "names",
             "--count",
             "10",
@@ -339,7 +339,7 @@ fn test_addresses_with_subdirectory() {
     let output_str = output_path.to_str().unwrap();
 
     let output = Command::new(get_binary_path())
-        .args(&[
+        .args([
             "addresses",
             "--count",
             "5",
@@ -364,7 +364,7 @@ fn test_names_with_subdirectory() {
     let output_str = output_path.to_str().unwrap();
 
     let output = Command::new(get_binary_path())
-        .args(&["names", "--count", "5", "--output", output_str, "--quiet"])
+        .args(["names", "--count", "5", "--output", output_str, "--quiet"])
         .output()
         .expect("Failed to execute command");
 
@@ -382,7 +382,7 @@ fn test_addresses_zero_error_rate() {
     let output_str = output_path.to_str().unwrap();
 
     let output = Command::new(get_binary_path())
-        .args(&[
+        .args([
             "addresses",
             "--count",
             "10",
@@ -430,7 +430,7 @@ fn test_names_zero_error_rate() {
     let output_str = output_path.to_str().unwrap();
 
     let output = Command::new(get_binary_path())
-        .args(&[
+        .args([
             "names",
             "--count",
             "10",
@@ -489,7 +489,7 @@ fn test_help_command() {
 #[test]
 fn test_addresses_help_command() {
     let output = Command::new(get_binary_path())
-        .args(&["addresses", "--help"])
+        .args(["addresses", "--help"])
         .output()
         .expect("Failed to execute command");
 
@@ -509,7 +509,7 @@ fn test_addresses_help_command() {
 #[test]
 fn test_names_help_command() {
     let output = Command::new(get_binary_path())
-        .args(&["names", "--help"])
+        .args(["names", "--help"])
         .output()
         .expect("Failed to execute command");
 
@@ -548,7 +548,7 @@ fn test_addresses_load_from_csv_standard_columns() {
     let output_str = output_path.to_str().unwrap();
 
     let output = Command::new(get_binary_path())
-        .args(&[
+        .args([
             "addresses",
             "--input",
             &input_csv,
@@ -598,7 +598,7 @@ fn test_addresses_load_from_csv_with_count_limit() {
     let output_str = output_path.to_str().unwrap();
 
     let output = Command::new(get_binary_path())
-        .args(&[
+        .args([
             "addresses",
             "--input",
             &input_csv,
@@ -626,6 +626,102 @@ fn test_addresses_load_from_csv_with_count_limit() {
     assert_eq!(lines.len(), 3, "Expected 3 lines (header + 2 records)");
 }
 
+#[test]
+fn test_addresses_expand_suffix_on_loaded_addresses() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_csv = create_test_csv(
+        &temp_dir,
+        "input.csv",
+        "address1,city,state,zip
+123 Main St,Springfield,IL,62701
+456 N Oak Ave,Chicago,IL,60601
+789 Pine Rd SW,Naperville,IL,60540",
+    );
+
+    let output = Command::new(get_binary_path())
+        .args([
+            "addresses",
+            "--input",
+            &input_csv,
+            "--output",
+            "-",
+            "--error-rate",
+            "1.0",
+            "--variance",
+            "expand_suffix",
+            "--quiet",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let streets: Vec<&str> = stdout
+        .lines()
+        .skip(1)
+        .map(|line| line.split('|').next().unwrap())
+        .collect();
+    assert_eq!(
+        streets,
+        ["123 Main Street", "456 N Oak Avenue", "789 Pine Road SW"]
+    );
+}
+
+#[test]
+fn test_addresses_zip_variance_on_loaded_addresses() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_csv = create_test_csv(
+        &temp_dir,
+        "input.csv",
+        "address1,city,state,zip
+1 Main St,Boston,MA,02108
+2 Main St,Boston,MA,02108-1234
+3 Main St,Chicago,IL,60601",
+    );
+    let truth_path = temp_dir.path().join("truth.csv");
+
+    let output = Command::new(get_binary_path())
+        .args([
+            "addresses",
+            "--input",
+            &input_csv,
+            "--output",
+            "-",
+            "--error-rate",
+            "1.0",
+            "--variance",
+            "drop_leading_zeros,remove_zip4",
+            "--ground-truth",
+            truth_path.to_str().unwrap(),
+            "--seed",
+            "7",
+            "--quiet",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let zips: Vec<&str> = stdout
+        .lines()
+        .skip(1)
+        .map(|line| line.rsplit('|').next().unwrap())
+        .collect();
+    // Chicago's ZIP has no leading zero and no +4, so it is left alone
+    assert_eq!(zips[0], "2108");
+    assert!(["2108", "02108"].contains(&zips[1]), "{}", zips[1]);
+    assert_eq!(zips[2], "60601");
+
+    let truth = read_file_contents(truth_path.to_str().unwrap());
+    let variances: Vec<&str> = truth
+        .lines()
+        .skip(1)
+        .map(|line| line.rsplit('|').next().unwrap())
+        .collect();
+    assert_eq!(variances[0], "drop_leading_zeros");
+    assert_eq!(variances[2], "");
+}
+
 #[test]
 fn test_addresses_load_from_csv_alternate_column_names() {
     let temp_dir = TempDir::new().unwrap();
@@ -643,7 +739,7 @@ fn test_addresses_load_from_csv_alternate_column_names() {
     let output_str = output_path.to_str().unwrap();
 
     let output = Command::new(get_binary_path())
-        .args(&[
+        .args([
             "addresses",
             "--input",
             &input_csv,
@@ -684,7 +780,7 @@ fn test_addresses_load_from_csv_openaddresses_format() {
     let output_str = output_path.to_str().unwrap();
 
     let output = Command::new(get_binary_path())
-        .args(&[
+        .args([
             "addresses",
             "--input",
             &input_csv,
@@ -728,7 +824,7 @@ fn test_addresses_load_from_csv_with_variance() {
 
     // Run with 100% error rate to ensure variance is applied
     let output = Command::new(get_binary_path())
-        .args(&[
+        .args([
             "addresses",
             "--input",
             &input_csv,
@@ -761,7 +857,7 @@ fn test_addresses_load_from_nonexistent_file() {
     let output_str = output_path.to_str().unwrap();
 
     let output = Command::new(get_binary_path())
-        .args(&[
+        .args([
             "addresses",
             "--input",
             "/nonexistent/path/to/file.csv",
@@ -790,7 +886,7 @@ fn test_addresses_no_count_no_input_fails() {
     let output_str = output_path.to_str().unwrap();
 
     let output = Command::new(get_binary_path())
-        .args(&["addresses", "--output", output_str, "--quiet"])
+        .args(["addresses", "--output", output_str, "--quiet"])
         .output()
         .expect("Failed to execute command");
 
@@ -808,7 +904,7 @@ fn test_addresses_no_count_no_input_fails() {
 #[test]
 fn test_addresses_help_mentions_input_flag() {
     let output = Command::new(get_binary_path())
-        .args(&["addresses", "--help"])
+        .args(["addresses", "--help"])
         .output()
         .expect("Failed to execute command");
 
@@ -824,7 +920,7 @@ fn test_addresses_help_mentions_input_flag() {
 #[test]
 fn test_download_help_command() {
     let output = Command::new(get_binary_path())
-        .args(&["download", "--help"])
+        .args(["download", "--help"])
         .output()
         .expect("Failed to execute command");
 
@@ -846,7 +942,7 @@ fn test_download_list_empty() {
     let temp_dir = TempDir::new().unwrap();
 
     let output = Command::new(get_binary_path())
-        .args(&["download", "--list"])
+        .args(["download", "--list"])
         .env("HOME", temp_dir.path())
         .output()
         .expect("Failed to execute command");
@@ -862,7 +958,7 @@ fn test_download_list_empty() {
 #[test]
 fn test_download_invalid_state() {
     let output = Command::new(get_binary_path())
-        .args(&["download", "XX"])
+        .args(["download", "XX"])
         .output()
         .expect("Failed to execute command");
 
@@ -885,7 +981,7 @@ fn test_addresses_state_flag_uncached() {
     let output_str = output_path.to_str().unwrap();
 
     let output = Command::new(get_binary_path())
-        .args(&["addresses", "--state", "IL", "--output", output_str])
+        .args(["addresses", "--state", "IL", "--output", output_str])
         .env("HOME", temp_dir.path())
         .output()
         .expect("Failed to execute command");
@@ -905,7 +1001,7 @@ fn test_addresses_state_and_input_mutual_exclusion() {
     let output_str = output_path.to_str().unwrap();
 
     let output = Command::new(get_binary_path())
-        .args(&[
+        .args([
             "addresses",
             "--state",
             "IL",
@@ -925,10 +1021,42 @@ fn test_addresses_state_and_input_mutual_exclusion() {
     );
 }
 
+#[test]
+fn test_addresses_threads_rejected_for_loaded_addresses() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_csv = create_test_csv(
+        &temp_dir,
+        "input.csv",
+        "address1,city,state,zip\n123 Main St,Springfield,IL,62701\n",
+    );
+    let output_path = temp_dir.path().join("output.csv");
+
+    let output = Command::new(get_binary_path())
+        .args([
+            "addresses",
+            "--input",
+            &input_csv,
+            "--output",
+            output_path.to_str().unwrap(),
+            "--threads",
+            "4",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("--threads only applies to generated addresses"),
+        "{}",
+        stderr
+    );
+}
+
 #[test]
 fn test_addresses_help_mentions_state_flag() {
     let output = Command::new(get_binary_path())
-        .args(&["addresses", "--help"])
+        .args(["addresses", "--help"])
         .output()
         .expect("Failed to execute command");
 
@@ -939,3 +1067,1755 @@ fn test_addresses_help_mentions_state_flag() {
         "Help should mention --state option"
     );
 }
+
+// ============================================================================
+// Seed Tests
+// ============================================================================
+
+/// Runs a subcommand with the given seed and returns the output file contents
+fn run_seeded(subcommand: &str, seed: &str, dir: &TempDir, filename: &str) -> String {
+    let output_path = dir.path().join(filename);
+    let output_str = output_path.to_str().unwrap();
+
+    let output = Command::new(get_binary_path())
+        .args([
+            subcommand,
+            "--count",
+            "200",
+            "--output",
+            output_str,
+            "--error-rate",
+            "0.7",
+            "--seed",
+            seed,
+            "--quiet",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(
+        output.status.success(),
+        "Command failed: {:?}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    read_file_contents(output_str)
+}
+
+#[test]
+fn test_addresses_same_seed_identical_output() {
+    let temp_dir = TempDir::new().unwrap();
+    let first = run_seeded("addresses", "1234", &temp_dir, "a.csv");
+    let second = run_seeded("addresses", "1234", &temp_dir, "b.csv");
+    assert_eq!(first, second, "Same seed should produce identical files");
+}
+
+#[test]
+fn test_names_same_seed_identical_output() {
+    let temp_dir = TempDir::new().unwrap();
+    let first = run_seeded("names", "1234", &temp_dir, "a.csv");
+    let second = run_seeded("names", "1234", &temp_dir, "b.csv");
+    assert_eq!(first, second, "Same seed should produce identical files");
+}
+
+#[test]
+fn test_names_different_seed_different_output() {
+    let temp_dir = TempDir::new().unwrap();
+    let first = run_seeded("names", "1", &temp_dir, "a.csv");
+    let second = run_seeded("names", "2", &temp_dir, "b.csv");
+    assert_ne!(
+        first, second,
+        "Different seeds should produce different files"
+    );
+}
+
+#[test]
+fn test_same_seed_identical_output_across_thread_counts() {
+    let temp_dir = TempDir::new().unwrap();
+
+    for subcommand in ["names", "addresses"] {
+        let mut outputs = Vec::new();
+        for threads in ["1", "4"] {
+            let path = temp_dir
+                .path()
+                .join(format!("{}_{}.csv", subcommand, threads));
+            let output = Command::new(get_binary_path())
+                .args([
+                    subcommand,
+                    "--count",
+                    "25000",
+                    "--output",
+                    path.to_str().unwrap(),
+                    "--seed",
+                    "11",
+                    "--threads",
+                    threads,
+                    "--quiet",
+                ])
+                .output()
+                .expect("Failed to execute command");
+            assert!(
+                output.status.success(),
+                "Command failed: {:?}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+            outputs.push(read_file_contents(path.to_str().unwrap()));
+        }
+        assert_eq!(outputs[0].lines().count(), 25001);
+        assert_eq!(
+            outputs[0], outputs[1],
+            "{} output should not depend on --threads",
+            subcommand
+        );
+    }
+}
+
+#[test]
+fn test_zero_threads_fails() {
+    let temp_dir = TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("names.csv");
+
+    let output = Command::new(get_binary_path())
+        .args([
+            "names",
+            "--count",
+            "5",
+            "--output",
+            output_path.to_str().unwrap(),
+            "--threads",
+            "0",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Threads must be greater than 0"));
+}
+
+// ============================================================================
+// Ground Truth Tests
+// ============================================================================
+
+#[test]
+fn test_addresses_ground_truth_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("addresses.csv");
+    let truth_path = temp_dir.path().join("truth/addresses_truth.csv");
+
+    let output = Command::new(get_binary_path())
+        .args([
+            "addresses",
+            "--count",
+            "20",
+            "--output",
+            output_path.to_str().unwrap(),
+            "--error-rate",
+            "1.0",
+            "--ground-truth",
+            truth_path.to_str().unwrap(),
+            "--quiet",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(
+        output.status.success(),
+        "Command failed: {:?}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let contents = read_file_contents(truth_path.to_str().unwrap());
+    let lines: Vec<&str> = contents.lines().collect();
+
+    assert_eq!(lines[0], "Row|Address1|Address2|City|State|Zip|Variances");
+    assert_eq!(lines.len(), 21, "Expected header + 20 ground-truth rows");
+    for (i, line) in lines.iter().skip(1).enumerate() {
+        let fields: Vec<&str> = line.split('|').collect();
+        assert_eq!(fields[0], (i + 1).to_string());
+        // Clean records always carry city, state and zip
+        assert!(!fields[3].is_empty() && !fields[4].is_empty() && !fields[5].is_empty());
+    }
+}
+
+#[test]
+fn test_names_ground_truth_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("names.csv");
+    let truth_path = temp_dir.path().join("names_truth.csv");
+
+    let output = Command::new(get_binary_path())
+        .args([
+            "names",
+            "--count",
+            "10",
+            "--output",
+            output_path.to_str().unwrap(),
+            "--error-rate",
+            "0.0",
+            "--ground-truth",
+            truth_path.to_str().unwrap(),
+            "--quiet",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+
+    let names = read_file_contents(output_path.to_str().unwrap());
+    let truth = read_file_contents(truth_path.to_str().unwrap());
+    let truth_lines: Vec<&str> = truth.lines().collect();
+    assert_eq!(
+        truth_lines[0],
+        "Row|FirstName|MiddleName|LastName|Variances"
+    );
+
+    // With 0.0 error rate every output row equals its clean record, with no variances
+    for (i, (name_line, truth_line)) in names.lines().zip(truth.lines()).enumerate().skip(1) {
+        assert_eq!(truth_line, format!("{}|{}|", i, name_line));
+    }
+}
+
+// ============================================================================
+// Variance Selection Tests
+// ============================================================================
+
+#[test]
+fn test_addresses_single_variance_pattern() {
+    let temp_dir = TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("addresses.csv");
+    let output_str = output_path.to_str().unwrap();
+
+    let output = Command::new(get_binary_path())
+        .args([
+            "addresses",
+            "--count",
+            "30",
+            "--output",
+            output_str,
+            "--error-rate",
+            "1.0",
+            "--variance",
+            "remove_zip",
+            "--quiet",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(
+        output.status.success(),
+        "Command failed: {:?}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let contents = read_file_contents(output_str);
+    for line in contents.lines().skip(1) {
+        let fields: Vec<&str> = line.split('|').collect();
+        assert!(!fields[2].is_empty(), "City should be untouched: {}", line);
+        assert!(!fields[3].is_empty(), "State should be untouched: {}", line);
+        assert!(fields[4].is_empty(), "Zip should be removed: {}", line);
+    }
+}
+
+#[test]
+fn test_names_exclude_variance() {
+    let temp_dir = TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("names.csv");
+    let truth_path = temp_dir.path().join("truth.csv");
+
+    let output = Command::new(get_binary_path())
+        .args([
+            "names",
+            "--count",
+            "50",
+            "--output",
+            output_path.to_str().unwrap(),
+            "--error-rate",
+            "1.0",
+            "--variance",
+            "uppercase,lowercase,typo",
+            "--exclude-variance",
+            "typo",
+            "--ground-truth",
+            truth_path.to_str().unwrap(),
+            "--quiet",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+
+    let truth = read_file_contents(truth_path.to_str().unwrap());
+    for line in truth.lines().skip(1) {
+        let variances = line.rsplit('|').next().unwrap();
+        for variance in variances.split(';').filter(|v| !v.is_empty()) {
+            assert!(
+                variance == "uppercase" || variance == "lowercase",
+                "Unexpected variance {} in {}",
+                variance,
+                line
+            );
+        }
+    }
+}
+
+#[test]
+fn test_names_locale_variance() {
+    let temp_dir = TempDir::new().unwrap();
+    let truth_path = temp_dir.path().join("truth.csv");
+
+    let output = Command::new(get_binary_path())
+        .args([
+            "names",
+            "--count",
+            "40",
+            "--output",
+            "-",
+            "--locale",
+            "es",
+            "--error-rate",
+            "1.0",
+            "--variance",
+            "drop_second_surname",
+            "--ground-truth",
+            truth_path.to_str().unwrap(),
+            "--quiet",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    for line in stdout.lines().skip(1) {
+        let last = line.rsplit('|').next().unwrap();
+        assert!(!last.contains(' '), "Second surname kept: {}", line);
+    }
+
+    let truth = read_file_contents(truth_path.to_str().unwrap());
+    let dropped = truth
+        .lines()
+        .skip(1)
+        .filter(|line| line.ends_with("|drop_second_surname"))
+        .count();
+    assert!(
+        dropped > 20,
+        "Only {} of 40 names had two surnames",
+        dropped
+    );
+}
+
+#[test]
+fn test_names_unknown_locale_fails() {
+    let output = Command::new(get_binary_path())
+        .args(["names", "--count", "5", "--output", "-", "--locale", "fr"])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Unknown locale 'fr'"), "{}", stderr);
+}
+
+#[test]
+fn test_names_unicode_variance() {
+    let temp_dir = TempDir::new().unwrap();
+    let truth_path = temp_dir.path().join("truth.csv");
+
+    let output = Command::new(get_binary_path())
+        .args([
+            "names",
+            "--count",
+            "30",
+            "--output",
+            "-",
+            "--locale",
+            "en,vi",
+            "--error-rate",
+            "1.0",
+            "--variance",
+            "unicode",
+            "--ground-truth",
+            truth_path.to_str().unwrap(),
+            "--quiet",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).expect("Output should be valid UTF-8");
+    let truth = read_file_contents(truth_path.to_str().unwrap());
+    for (row, line) in stdout.lines().skip(1).zip(truth.lines().skip(1)) {
+        let variances = line.rsplit('|').next().unwrap();
+        assert!(!variances.is_empty(), "No variance recorded: {}", line);
+        for variance in variances.split(';') {
+            assert!(variance.starts_with("unicode:"), "{}", line);
+        }
+        assert!(!line.contains(row), "Row should differ from clean: {}", row);
+    }
+}
+
+#[test]
+fn test_names_nickname_variance_records_substitution() {
+    let temp_dir = TempDir::new().unwrap();
+    let truth_path = temp_dir.path().join("truth.csv");
+
+    let output = Command::new(get_binary_path())
+        .args([
+            "names",
+            "--count",
+            "200",
+            "--output",
+            "-",
+            "--error-rate",
+            "1.0",
+            "--variance",
+            "nickname",
+            "--seed",
+            "3",
+            "--ground-truth",
+            truth_path.to_str().unwrap(),
+            "--quiet",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let truth = read_file_contents(truth_path.to_str().unwrap());
+    let mut swaps = 0;
+    for line in truth.lines().skip(1) {
+        let variances = line.rsplit('|').next().unwrap();
+        for variance in variances.split(';').filter(|v| !v.is_empty()) {
+            let (from, to) = variance
+                .strip_prefix("nickname:")
+                .and_then(|swap| {
+                    swap.strip_prefix("first_name:")
+                        .or_else(|| swap.strip_prefix("middle_name:"))
+                })
+                .and_then(|swap| swap.split_once("->"))
+                .unwrap_or_else(|| panic!("Unexpected variance: {}", line));
+            assert_ne!(from, to);
+            swaps += 1;
+        }
+    }
+    assert!(swaps > 0, "Expected some nickname swaps");
+}
+
+#[test]
+fn test_addresses_typo_models() {
+    let temp_dir = TempDir::new().unwrap();
+    let truth_path = temp_dir.path().join("truth.csv");
+
+    let output = Command::new(get_binary_path())
+        .args([
+            "addresses",
+            "--count",
+            "30",
+            "--output",
+            "-",
+            "--error-rate",
+            "1.0",
+            "--variance",
+            "keyboard_typo,ocr_typo",
+            "--ground-truth",
+            truth_path.to_str().unwrap(),
+            "--quiet",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let truth = read_file_contents(truth_path.to_str().unwrap());
+    for line in truth.lines().skip(1) {
+        let variances = line.rsplit('|').next().unwrap();
+        assert!(!variances.is_empty(), "No variance recorded: {}", line);
+        for variance in variances.split(';') {
+            assert!(
+                variance == "typo:keyboard" || variance == "typo:ocr",
+                "{}",
+                line
+            );
+        }
+    }
+}
+
+#[test]
+fn test_unknown_variance_pattern_fails() {
+    let temp_dir = TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("addresses.csv");
+
+    let output = Command::new(get_binary_path())
+        .args([
+            "addresses",
+            "--count",
+            "5",
+            "--output",
+            output_path.to_str().unwrap(),
+            "--variance",
+            "not_a_pattern",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Unknown variance pattern 'not_a_pattern'"),
+        "Should reject unknown pattern names"
+    );
+}
+
+// ============================================================================
+// Streaming Tests
+// ============================================================================
+
+#[test]
+fn test_addresses_stream_input_with_ground_truth() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut content = String::from("address,city,state,zip\n");
+    for i in 0..250 {
+        content.push_str(&format!("{} Main Street,Springfield,IL,62701\n", i));
+    }
+    let input_path = create_test_csv(&temp_dir, "input.csv", &content);
+    let output_path = temp_dir.path().join("addresses.csv");
+    let truth_path = temp_dir.path().join("truth.csv");
+
+    let output = Command::new(get_binary_path())
+        .args([
+            "addresses",
+            "--input",
+            &input_path,
+            "--output",
+            output_path.to_str().unwrap(),
+            "--ground-truth",
+            truth_path.to_str().unwrap(),
+            "--error-rate",
+            "0.0",
+            "--quiet",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(
+        output.status.success(),
+        "Command failed: {:?}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // Every input row is streamed through, in order
+    let contents = read_file_contents(output_path.to_str().unwrap());
+    let lines: Vec<&str> = contents.lines().collect();
+    assert_eq!(lines.len(), 251);
+    assert_eq!(lines[1], "0 Main Street||Springfield|IL|62701");
+    assert_eq!(lines[250], "249 Main Street||Springfield|IL|62701");
+
+    let truth = read_file_contents(truth_path.to_str().unwrap());
+    assert_eq!(truth.lines().count(), 251);
+    assert!(truth.contains("250|249 Main Street||Springfield|IL|62701|"));
+}
+
+// ============================================================================
+// Output Format Tests
+// ============================================================================
+
+#[test]
+fn test_names_jsonl_format_with_ground_truth() {
+    let temp_dir = TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("names.out");
+    let truth_path = temp_dir.path().join("truth.out");
+
+    let output = Command::new(get_binary_path())
+        .args([
+            "names",
+            "--count",
+            "5",
+            "--output",
+            output_path.to_str().unwrap(),
+            "--format",
+            "jsonl",
+            "--error-rate",
+            "0.0",
+            "--ground-truth",
+            truth_path.to_str().unwrap(),
+            "--quiet",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(
+        output.status.success(),
+        "Command failed: {:?}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let names = read_file_contents(output_path.to_str().unwrap());
+    let truth = read_file_contents(truth_path.to_str().unwrap());
+    assert_eq!(names.lines().count(), 5);
+    for (i, (name, truth)) in names.lines().zip(truth.lines()).enumerate() {
+        let name: serde_json::Value = serde_json::from_str(name).unwrap();
+        let truth: serde_json::Value = serde_json::from_str(truth).unwrap();
+        assert!(name["first_name"].is_string());
+        assert_eq!(truth["row"], i + 1);
+        assert_eq!(truth["clean"], name);
+        assert_eq!(truth["variances"], serde_json::json!([]));
+    }
+}
+
+#[test]
+fn test_addresses_format_from_extension() {
+    let temp_dir = TempDir::new().unwrap();
+    let json_path = temp_dir.path().join("addresses.json");
+    let parquet_path = temp_dir.path().join("addresses.parquet");
+
+    for path in [&json_path, &parquet_path] {
+        let output = Command::new(get_binary_path())
+            .args([
+                "addresses",
+                "--count",
+                "10",
+                "--output",
+                path.to_str().unwrap(),
+                "--quiet",
+            ])
+            .output()
+            .expect("Failed to execute command");
+        assert!(
+            output.status.success(),
+            "Command failed: {:?}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    let json: serde_json::Value =
+        serde_json::from_str(&read_file_contents(json_path.to_str().unwrap())).unwrap();
+    assert_eq!(json.as_array().unwrap().len(), 10);
+    assert!(json[0]["zip"].is_string());
+
+    let parquet = fs::read(&parquet_path).unwrap();
+    assert!(parquet.starts_with(b"PAR1") && parquet.ends_with(b"PAR1"));
+}
+
+#[test]
+fn test_unknown_format_fails() {
+    let temp_dir = TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("names.csv");
+
+    let output = Command::new(get_binary_path())
+        .args([
+            "names",
+            "--count",
+            "5",
+            "--output",
+            output_path.to_str().unwrap(),
+            "--format",
+            "xml",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Unknown output format 'xml'"));
+}
+
+// ============================================================================
+// Variance Profile Tests
+// ============================================================================
+
+#[test]
+fn test_addresses_profile_weights_and_error_rate() {
+    let temp_dir = TempDir::new().unwrap();
+    let profile_path = temp_dir.path().join("profile.toml");
+    fs::write(
+        &profile_path,
+        "[addresses]\nerror_rate = 1.0\nnum_variances = [1.0]\n\n\
+         [addresses.weights]\nremove_zip = 1.0\n",
+    )
+    .unwrap();
+    let output_path = temp_dir.path().join("addresses.csv");
+    let output_str = output_path.to_str().unwrap();
+
+    let output = Command::new(get_binary_path())
+        .args([
+            "addresses",
+            "--count",
+            "20",
+            "--output",
+            output_str,
+            "--variance",
+            "remove_zip",
+            "--profile",
+            profile_path.to_str().unwrap(),
+            "--quiet",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(
+        output.status.success(),
+        "Command failed: {:?}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // The profile's error_rate applies when --error-rate is not given
+    let contents = read_file_contents(output_str);
+    for line in contents.lines().skip(1) {
+        let fields: Vec<&str> = line.split('|').collect();
+        assert!(fields[4].is_empty(), "Zip should be removed: {}", line);
+        assert!(!fields[2].is_empty(), "City should be untouched: {}", line);
+    }
+}
+
+#[test]
+fn test_names_json_profile_field_error_rate() {
+    let temp_dir = TempDir::new().unwrap();
+    let profile_path = temp_dir.path().join("profile.json");
+    fs::write(
+        &profile_path,
+        r#"{"names": {"field_error_rates": {"last_name": 1.0}, "weights": {"add_suffix": 1.0}}}"#,
+    )
+    .unwrap();
+    let output_path = temp_dir.path().join("names.csv");
+    let truth_path = temp_dir.path().join("truth.csv");
+
+    let output = Command::new(get_binary_path())
+        .args([
+            "names",
+            "--count",
+            "20",
+            "--output",
+            output_path.to_str().unwrap(),
+            "--error-rate",
+            "0.0",
+            "--variance",
+            "add_suffix,add_prefix",
+            "--profile",
+            profile_path.to_str().unwrap(),
+            "--ground-truth",
+            truth_path.to_str().unwrap(),
+            "--quiet",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(
+        output.status.success(),
+        "Command failed: {:?}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // Only add_suffix touches last_name, so every row gets exactly that
+    let truth = read_file_contents(truth_path.to_str().unwrap());
+    for line in truth.lines().skip(1) {
+        assert!(
+            line.ends_with("|add_suffix"),
+            "Unexpected variances: {}",
+            line
+        );
+    }
+}
+
+#[test]
+fn test_invalid_profile_fails() {
+    let temp_dir = TempDir::new().unwrap();
+    let profile_path = temp_dir.path().join("profile.toml");
+    fs::write(&profile_path, "[addresses.weights]\nnot_a_pattern = 2.0\n").unwrap();
+    let output_path = temp_dir.path().join("addresses.csv");
+
+    let output = Command::new(get_binary_path())
+        .args([
+            "addresses",
+            "--count",
+            "5",
+            "--output",
+            output_path.to_str().unwrap(),
+            "--profile",
+            profile_path.to_str().unwrap(),
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Unknown variance pattern 'not_a_pattern' in profile"),
+        "Should reject unknown pattern names in profile: {}",
+        stderr
+    );
+}
+
+// ============================================================================
+// CSV Layout Tests
+// ============================================================================
+
+#[test]
+fn test_addresses_custom_csv_layout() {
+    let temp_dir = TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("addresses.csv");
+
+    let output = Command::new(get_binary_path())
+        .args([
+            "addresses",
+            "--count",
+            "5",
+            "--output",
+            output_path.to_str().unwrap(),
+            "--delimiter",
+            ",",
+            "--quote",
+            "always",
+            "--crlf",
+            "--columns",
+            "ADDR_LINE_1,ADDR_LINE_2,CITY,ST,ZIP",
+            "--seed",
+            "3",
+            "--quiet",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(
+        output.status.success(),
+        "Command failed: {:?}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let contents = read_file_contents(output_path.to_str().unwrap());
+    assert!(contents.starts_with("\"ADDR_LINE_1\",\"ADDR_LINE_2\",\"CITY\",\"ST\",\"ZIP\"\r\n"));
+    let rows: Vec<&str> = contents.split_terminator("\r\n").collect();
+    assert_eq!(rows.len(), 6);
+    for row in &rows[1..] {
+        assert!(
+            row.starts_with('"') && row.ends_with('"'),
+            "Unquoted row: {}",
+            row
+        );
+        assert!(!row.contains('\n'), "Bare LF in row: {}", row);
+    }
+}
+
+#[test]
+fn test_names_no_header_tab_delimiter() {
+    let temp_dir = TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("names.txt");
+
+    let output = Command::new(get_binary_path())
+        .args([
+            "names",
+            "--count",
+            "4",
+            "--output",
+            output_path.to_str().unwrap(),
+            "--delimiter",
+            "tab",
+            "--no-header",
+            "--quiet",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let contents = read_file_contents(output_path.to_str().unwrap());
+    let lines: Vec<&str> = contents.lines().collect();
+    assert_eq!(lines.len(), 4, "Header should be omitted");
+    for line in lines {
+        assert_eq!(
+            line.split('\t').count(),
+            3,
+            "Expected 3 tab-separated fields: {}",
+            line
+        );
+    }
+}
+
+#[test]
+fn test_wrong_column_count_fails() {
+    let temp_dir = TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("names.csv");
+
+    let output = Command::new(get_binary_path())
+        .args([
+            "names",
+            "--count",
+            "4",
+            "--output",
+            output_path.to_str().unwrap(),
+            "--columns",
+            "FIRST,LAST",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Expected 3 column names for names"),
+        "Should reject wrong column count: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_invalid_delimiter_fails() {
+    let temp_dir = TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("names.csv");
+
+    let output = Command::new(get_binary_path())
+        .args([
+            "names",
+            "--count",
+            "4",
+            "--output",
+            output_path.to_str().unwrap(),
+            "--delimiter",
+            "::",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Invalid delimiter"));
+}
+
+// ============================================================================
+// Pipeline Tests
+// ============================================================================
+
+#[test]
+fn test_names_output_to_stdout() {
+    let output = Command::new(get_binary_path())
+        .args(["names", "--count", "5", "--output", "-", "--seed", "4"])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 6, "Header plus 5 records: {}", stdout);
+    assert_eq!(lines[0], "FirstName|MiddleName|LastName");
+
+    // Status messages must not corrupt the data stream
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Successfully generated 5 names"));
+}
+
+#[test]
+fn test_addresses_input_from_stdin() {
+    use std::process::Stdio;
+
+    let mut child = Command::new(get_binary_path())
+        .args([
+            "addresses",
+            "--input",
+            "-",
+            "--output",
+            "-",
+            "--error-rate",
+            "0.0",
+            "--quiet",
+        ])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Failed to execute command");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(b"address1,city,state,zip\n123 Main St,Springfield,IL,62701\n456 Oak Ave,Chicago,IL,60601\n")
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(
+        stdout.lines().collect::<Vec<_>>(),
+        [
+            "Address1|Address2|City|State|Zip",
+            "123 Main St||Springfield|IL|62701",
+            "456 Oak Ave||Chicago|IL|60601"
+        ]
+    );
+}
+
+#[test]
+fn test_closed_stdout_still_completes_ground_truth() {
+    use std::io::{BufRead, BufReader};
+    use std::process::Stdio;
+
+    let temp_dir = TempDir::new().unwrap();
+    let truth_path = temp_dir.path().join("truth.json");
+
+    let mut child = Command::new(get_binary_path())
+        .args([
+            "names",
+            "--count",
+            "200000",
+            "--output",
+            "-",
+            "--ground-truth",
+            truth_path.to_str().unwrap(),
+            "--quiet",
+        ])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("Failed to execute command");
+
+    // Read the header, then close stdout the way `head -1` would
+    let mut header = String::new();
+    BufReader::new(child.stdout.take().unwrap())
+        .read_line(&mut header)
+        .unwrap();
+    assert_eq!(header.trim_end(), "FirstName|MiddleName|LastName");
+    let output = child.wait_with_output().unwrap();
+
+    assert!(
+        output.status.success(),
+        "Command failed: {:?}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(output.stderr.is_empty());
+    let truth: serde_json::Value =
+        serde_json::from_str(&read_file_contents(truth_path.to_str().unwrap())).unwrap();
+    assert!(truth.is_array());
+}
+
+#[test]
+fn test_output_and_ground_truth_both_stdout_fails() {
+    let output = Command::new(get_binary_path())
+        .args([
+            "names",
+            "--count",
+            "5",
+            "--output",
+            "-",
+            "--ground-truth",
+            "-",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("cannot both be '-'"));
+}
+
+// ============================================================================
+// People Tests
+// ============================================================================
+
+#[test]
+fn test_people_with_contact_fields() {
+    let temp_dir = TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("people.csv");
+    let truth_path = temp_dir.path().join("truth.csv");
+
+    let output = Command::new(get_binary_path())
+        .args([
+            "people",
+            "--count",
+            "20",
+            "--output",
+            output_path.to_str().unwrap(),
+            "--ground-truth",
+            truth_path.to_str().unwrap(),
+            "--email",
+            "--phone",
+            "--dob",
+            "--seed",
+            "5",
+            "--quiet",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(
+        output.status.success(),
+        "Command failed: {:?}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let contents = read_file_contents(output_path.to_str().unwrap());
+    let lines: Vec<&str> = contents.lines().collect();
+    assert_eq!(
+        lines[0],
+        "FirstName|MiddleName|LastName|Address1|Address2|City|State|Zip|Email|Phone|DOB"
+    );
+    assert_eq!(lines.len(), 21);
+    for line in &lines[1..] {
+        let fields: Vec<&str> = line.split('|').collect();
+        assert_eq!(fields.len(), 11, "Wrong field count: {}", line);
+        assert!(
+            fields[8..].iter().all(|field| !field.is_empty()),
+            "Missing contact field: {}",
+            line
+        );
+    }
+
+    let truth = read_file_contents(truth_path.to_str().unwrap());
+    for line in truth.lines().skip(1) {
+        let variances = line.rsplit('|').next().unwrap();
+        for variance in variances.split(';').filter(|v| !v.is_empty()) {
+            assert!(
+                ["name.", "address.", "email.", "phone.", "dob."]
+                    .iter()
+                    .any(|prefix| variance.starts_with(prefix)),
+                "Unprefixed variance: {}",
+                variance
+            );
+        }
+    }
+}
+
+#[test]
+fn test_people_without_contact_fields_leaves_them_empty() {
+    let output = Command::new(get_binary_path())
+        .args([
+            "people", "--count", "3", "--output", "-", "--format", "jsonl", "--quiet",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    for line in stdout.lines() {
+        let person: serde_json::Value = serde_json::from_str(line).unwrap();
+        assert!(person["first_name"].is_string());
+        assert!(person["zip"].is_string());
+        assert!(person["email"].is_null());
+        assert!(person["phone"].is_null());
+        assert!(person["date_of_birth"].is_null());
+    }
+}
+
+// ============================================================================
+// Duplicate Cluster Tests
+// ============================================================================
+
+#[test]
+fn test_names_fixed_size_clusters() {
+    let temp_dir = TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("names.csv");
+    let truth_path = temp_dir.path().join("truth.csv");
+
+    let output = Command::new(get_binary_path())
+        .args([
+            "names",
+            "--count",
+            "40",
+            "--clusters",
+            "3",
+            "--output",
+            output_path.to_str().unwrap(),
+            "--ground-truth",
+            truth_path.to_str().unwrap(),
+            "--error-rate",
+            "0.9",
+            "--quiet",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(
+        output.status.success(),
+        "Command failed: {:?}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let contents = read_file_contents(output_path.to_str().unwrap());
+    assert_eq!(contents.lines().count(), 121, "40 entities x 3 copies");
+
+    let truth = read_file_contents(truth_path.to_str().unwrap());
+    let mut lines = truth.lines();
+    assert_eq!(
+        lines.next().unwrap(),
+        "Row|EntityId|FirstName|MiddleName|LastName|Variances"
+    );
+    let mut clean_by_entity: std::collections::HashMap<String, (String, usize)> =
+        std::collections::HashMap::new();
+    for line in lines {
+        let fields: Vec<&str> = line.split('|').collect();
+        let clean = fields[2..5].join("|");
+        let entry = clean_by_entity
+            .entry(fields[1].to_string())
+            .or_insert((clean.clone(), 0));
+        assert_eq!(
+            entry.0, clean,
+            "Copies of an entity must share a clean name"
+        );
+        entry.1 += 1;
+    }
+    assert_eq!(clean_by_entity.len(), 40);
+    assert!(clean_by_entity.values().all(|(_, copies)| *copies == 3));
+}
+
+#[test]
+fn test_people_weighted_clusters_jsonl_ground_truth() {
+    let temp_dir = TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("people.jsonl");
+    let truth_path = temp_dir.path().join("truth.jsonl");
+
+    let output = Command::new(get_binary_path())
+        .args([
+            "people",
+            "--count",
+            "30",
+            "--clusters",
+            "1:50,2:30,4:20",
+            "--output",
+            output_path.to_str().unwrap(),
+            "--ground-truth",
+            truth_path.to_str().unwrap(),
+            "--quiet",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let truth = read_file_contents(truth_path.to_str().unwrap());
+    let mut entities = std::collections::HashSet::new();
+    for line in truth.lines() {
+        let row: serde_json::Value = serde_json::from_str(line).unwrap();
+        let entity_id = row["entity_id"].as_u64().expect("entity_id on every row");
+        assert!((1..=30).contains(&entity_id));
+        entities.insert(entity_id);
+    }
+    assert_eq!(entities.len(), 30);
+}
+
+#[test]
+fn test_clusters_require_ground_truth() {
+    let temp_dir = TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("names.csv");
+
+    let output = Command::new(get_binary_path())
+        .args([
+            "names",
+            "--count",
+            "5",
+            "--clusters",
+            "2",
+            "--output",
+            output_path.to_str().unwrap(),
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--clusters requires --ground-truth"));
+}
+
+#[test]
+fn test_invalid_cluster_sizes_fail() {
+    let output = Command::new(get_binary_path())
+        .args([
+            "names",
+            "--count",
+            "5",
+            "--clusters",
+            "1:50,2",
+            "--output",
+            "-",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Invalid cluster sizes"));
+}
+
+// ============================================================================
+// Email Tests
+// ============================================================================
+
+#[test]
+fn test_emails_clean() {
+    let output = Command::new(get_binary_path())
+        .args([
+            "emails",
+            "--count",
+            "50",
+            "--output",
+            "-",
+            "--error-rate",
+            "0.0",
+            "--quiet",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut lines = stdout.lines();
+    assert_eq!(lines.next().unwrap(), "Email");
+    for email in lines {
+        let (local, domain) = email.split_once('@').expect("clean email has @");
+        assert!(!local.is_empty());
+        assert!(domain.contains('.'));
+        assert_eq!(email, email.trim());
+        assert_eq!(email, email.to_lowercase());
+    }
+}
+
+#[test]
+fn test_emails_single_variance_pattern() {
+    let temp_dir = TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("emails.csv");
+
+    let output = Command::new(get_binary_path())
+        .args([
+            "emails",
+            "--count",
+            "30",
+            "--output",
+            output_path.to_str().unwrap(),
+            "--error-rate",
+            "1.0",
+            "--variance",
+            "mailto_prefix",
+            "--quiet",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let contents = read_file_contents(output_path.to_str().unwrap());
+    for email in contents.lines().skip(1) {
+        assert!(email.starts_with("mailto:"), "{}", email);
+    }
+}
+
+// ============================================================================
+// Phone Tests
+// ============================================================================
+
+#[test]
+fn test_phones_state_area_codes() {
+    let output = Command::new(get_binary_path())
+        .args([
+            "phones",
+            "--count",
+            "50",
+            "--state",
+            "IL",
+            "--output",
+            "-",
+            "--error-rate",
+            "0.0",
+            "--quiet",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let illinois = [
+        "217", "224", "309", "312", "618", "630", "708", "773", "815", "847",
+    ];
+    for phone in stdout.lines().skip(1) {
+        assert_eq!(phone.len(), 12, "Expected NNN-NNN-NNNN: {}", phone);
+        assert!(
+            illinois.contains(&&phone[..3]),
+            "Not an IL area code: {}",
+            phone
+        );
+    }
+}
+
+#[test]
+fn test_phones_single_variance_pattern() {
+    let output = Command::new(get_binary_path())
+        .args([
+            "phones",
+            "--count",
+            "30",
+            "--output",
+            "-",
+            "--error-rate",
+            "1.0",
+            "--variance",
+            "international",
+            "--quiet",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    for phone in stdout.lines().skip(1) {
+        assert!(phone.starts_with("+1 "), "{}", phone);
+        assert_eq!(phone.split(' ').count(), 4, "{}", phone);
+    }
+}
+
+#[test]
+fn test_phones_invalid_state_fails() {
+    let output = Command::new(get_binary_path())
+        .args(["phones", "--count", "5", "--state", "ZZ", "--output", "-"])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Invalid state code: ZZ"));
+}
+
+// ============================================================================
+// Date Tests
+// ============================================================================
+
+#[test]
+fn test_dates_follow_age_distribution() {
+    let output = Command::new(get_binary_path())
+        .args([
+            "dates",
+            "--count",
+            "100",
+            "--ages",
+            "30-39",
+            "--as-of",
+            "2024-06-15",
+            "--output",
+            "-",
+            "--error-rate",
+            "0.0",
+            "--quiet",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines[0], "Date");
+    assert_eq!(lines.len(), 101);
+    for date in &lines[1..] {
+        let parts: Vec<&str> = date.split('/').collect();
+        assert_eq!(parts.len(), 3, "Expected MM/DD/YYYY: {}", date);
+        let year: u32 = parts[2].parse().unwrap();
+        assert!((1984..=1994).contains(&year), "{}", date);
+    }
+}
+
+#[test]
+fn test_dates_same_seed_and_as_of_reproduce_output() {
+    let run = || {
+        Command::new(get_binary_path())
+            .args([
+                "dates",
+                "--count",
+                "50",
+                "--as-of",
+                "2024-06-15",
+                "--seed",
+                "9",
+                "--output",
+                "-",
+                "--quiet",
+            ])
+            .output()
+            .expect("Failed to execute command")
+            .stdout
+    };
+    assert_eq!(run(), run());
+}
+
+#[test]
+fn test_dates_reject_as_of_without_room_for_ages() {
+    for as_of in ["0001-01-01", "-262140-01-01"] {
+        let output = Command::new(get_binary_path())
+            .args(["dates", "--count", "3", "--output", "-", "--quiet"])
+            .arg(format!("--as-of={}", as_of))
+            .output()
+            .expect("Failed to execute command");
+
+        assert!(!output.status.success(), "{}", as_of);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("Reference date"), "{}", stderr);
+        assert!(!stderr.contains("panicked"), "{}", stderr);
+        assert!(output.stdout.is_empty(), "{}", as_of);
+    }
+}
+
+#[test]
+fn test_dates_single_variance_pattern() {
+    let output = Command::new(get_binary_path())
+        .args([
+            "dates",
+            "--count",
+            "30",
+            "--output",
+            "-",
+            "--error-rate",
+            "1.0",
+            "--variance",
+            "iso_8601",
+            "--quiet",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    for date in stdout.lines().skip(1) {
+        let parts: Vec<&str> = date.split('-').collect();
+        assert_eq!(parts.len(), 3, "Expected YYYY-MM-DD: {}", date);
+        assert_eq!(parts[0].len(), 4, "{}", date);
+    }
+}
+
+#[test]
+fn test_dates_invalid_ages_fails() {
+    let output = Command::new(get_binary_path())
+        .args(["dates", "--count", "5", "--ages", "65-18", "--output", "-"])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Age range 65-18"));
+}
+
+// ============================================================================
+// TIN Tests
+// ============================================================================
+
+#[test]
+fn test_tins_ssns_use_reserved_areas() {
+    let output = Command::new(get_binary_path())
+        .args([
+            "tins",
+            "--count",
+            "200",
+            "--output",
+            "-",
+            "--error-rate",
+            "0.0",
+            "--quiet",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines[0], "TIN");
+    for ssn in &lines[1..] {
+        assert_eq!(ssn.len(), 11, "Expected NNN-NN-NNNN: {}", ssn);
+        assert!(
+            ssn.starts_with('9') || ssn.starts_with("000"),
+            "Issuable area: {}",
+            ssn
+        );
+    }
+}
+
+#[test]
+fn test_tins_eins_without_dashes() {
+    let output = Command::new(get_binary_path())
+        .args([
+            "tins",
+            "--kind",
+            "ein",
+            "--count",
+            "30",
+            "--output",
+            "-",
+            "--error-rate",
+            "1.0",
+            "--variance",
+            "no_dashes",
+            "--quiet",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    for ein in stdout.lines().skip(1) {
+        assert_eq!(ein.len(), 9, "{}", ein);
+        assert!(ein.chars().all(|c| c.is_ascii_digit()), "{}", ein);
+    }
+}
+
+#[test]
+fn test_tins_invalid_kind_fails() {
+    let output = Command::new(get_binary_path())
+        .args(["tins", "--count", "5", "--kind", "itin", "--output", "-"])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Unknown TIN kind 'itin'"));
+}
+
+// ============================================================================
+// Company Tests
+// ============================================================================
+
+#[test]
+fn test_companies_pair_names_with_addresses() {
+    let output = Command::new(get_binary_path())
+        .args([
+            "companies",
+            "--count",
+            "20",
+            "--output",
+            "-",
+            "--error-rate",
+            "0.0",
+            "--quiet",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines[0], "CompanyName|Address1|Address2|City|State|Zip");
+    assert_eq!(lines.len(), 21);
+    for line in &lines[1..] {
+        let fields: Vec<&str> = line.split('|').collect();
+        assert_eq!(fields.len(), 6, "Wrong field count: {}", line);
+        assert!(!fields[0].is_empty() && !fields[3].is_empty(), "{}", line);
+    }
+}
+
+#[test]
+fn test_companies_single_variance_pattern() {
+    let dir = TempDir::new().unwrap();
+    let truth_path = dir.path().join("truth.csv");
+
+    let output = Command::new(get_binary_path())
+        .args([
+            "companies",
+            "--count",
+            "30",
+            "--output",
+            "-",
+            "--error-rate",
+            "1.0",
+            "--variance",
+            "leading_the",
+            "--ground-truth",
+            truth_path.to_str().unwrap(),
+            "--quiet",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    for line in stdout.lines().skip(1) {
+        assert!(line.starts_with("The "), "{}", line);
+    }
+
+    let truth = read_file_contents(truth_path.to_str().unwrap());
+    for line in truth.lines().skip(1) {
+        let variances = line.rsplit('|').next().unwrap();
+        assert!(variances.contains("company.leading_the"), "{}", line);
+        for variance in variances.split(';') {
+            assert!(
+                variance.starts_with("company.") || variance.starts_with("address."),
+                "{}",
+                variance
+            );
+        }
+    }
+}
+
+// ============================================================================
+// International Address Tests
+// ============================================================================
+
+#[test]
+fn test_intl_addresses_use_local_postal_formats() {
+    let output = Command::new(get_binary_path())
+        .args([
+            "intl-addresses",
+            "--count",
+            "40",
+            "--output",
+            "-",
+            "--country",
+            "ca,de",
+            "--error-rate",
+            "0.0",
+            "--quiet",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines[0], "Address1|Address2|City|Region|PostalCode|Country");
+    assert_eq!(lines.len(), 41);
+    for line in &lines[1..] {
+        let fields: Vec<&str> = line.split('|').collect();
+        assert_eq!(fields.len(), 6, "Wrong field count: {}", line);
+        match fields[5] {
+            "CA" => {
+                assert_eq!(fields[3].len(), 2, "{}", line);
+                assert_eq!(fields[4].len(), 7, "{}", line);
+                assert_eq!(fields[4].as_bytes()[3], b' ', "{}", line);
+            }
+            "DE" => {
+                assert_eq!(fields[4].len(), 5, "{}", line);
+                assert!(fields[4].chars().all(|c| c.is_ascii_digit()), "{}", line);
+            }
+            other => panic!("Unexpected country {}", other),
+        }
+    }
+}
+
+#[test]
+fn test_intl_addresses_country_variance() {
+    let dir = TempDir::new().unwrap();
+    let truth_path = dir.path().join("truth.csv");
+
+    let output = Command::new(get_binary_path())
+        .args([
+            "intl-addresses",
+            "--count",
+            "20",
+            "--output",
+            "-",
+            "--country",
+            "mx",
+            "--error-rate",
+            "1.0",
+            "--variance",
+            "missing_country",
+            "--ground-truth",
+            truth_path.to_str().unwrap(),
+            "--quiet",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    for line in stdout.lines().skip(1) {
+        assert!(line.ends_with('|'), "{}", line);
+    }
+
+    let truth = read_file_contents(truth_path.to_str().unwrap());
+    for line in truth.lines().skip(1) {
+        assert!(line.ends_with("|MX|missing_country"), "{}", line);
+    }
+}
+
+#[test]
+fn test_intl_addresses_reject_unknown_country() {
+    let output = Command::new(get_binary_path())
+        .args([
+            "intl-addresses",
+            "--count",
+            "5",
+            "--output",
+            "-",
+            "--country",
+            "fr",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Unknown country 'fr'"), "{}", stderr);
+}
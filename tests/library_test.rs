@@ -0,0 +1,107 @@
+use std::fs::File;
+use std::io::Write;
+
+use rust_faker::generators::addresses::{
+    load_addresses_from_cache, load_addresses_from_csv, AddressReader, LoadWarning,
+};
+use rust_faker::generators::names::{generate_names, VariancePattern};
+use rust_faker::generators::{create_rng, validate_count, NamedPattern};
+use rust_faker::Error;
+use tempfile::TempDir;
+
+#[test]
+fn test_load_addresses_from_csv_reports_missing_columns() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("input.csv");
+    let mut file = File::create(&path).unwrap();
+    file.write_all(b"address1,city\n123 Main St,Springfield\n")
+        .unwrap();
+
+    let loaded =
+        load_addresses_from_csv(path.to_str().unwrap(), None, &mut rand::thread_rng()).unwrap();
+
+    assert_eq!(loaded.addresses.len(), 1);
+    assert!(loaded
+        .warnings
+        .contains(&LoadWarning::MissingColumn("state")));
+    assert!(loaded.warnings.contains(&LoadWarning::MissingColumn("zip")));
+}
+
+#[test]
+fn test_load_addresses_from_csv_reports_insufficient_records() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("input.csv");
+    let mut file = File::create(&path).unwrap();
+    file.write_all(b"address1,city,state,zip\n123 Main St,Springfield,IL,62701\n")
+        .unwrap();
+
+    let loaded =
+        load_addresses_from_csv(path.to_str().unwrap(), Some(5), &mut rand::thread_rng()).unwrap();
+
+    assert_eq!(
+        loaded.warnings,
+        vec![LoadWarning::InsufficientRecords {
+            requested: 5,
+            available: 1
+        }]
+    );
+}
+
+#[test]
+fn test_load_addresses_from_missing_file_is_io_error() {
+    let result = load_addresses_from_csv(
+        "/nonexistent/path/to/file.csv",
+        None,
+        &mut rand::thread_rng(),
+    );
+    assert!(matches!(result, Err(Error::Io(_))));
+}
+
+#[test]
+fn test_load_addresses_from_cache_uncached_state() {
+    let result = load_addresses_from_cache(&["ZZ".to_string()], None, &mut rand::thread_rng());
+    assert!(matches!(result, Err(Error::StateNotCached(state)) if state == "ZZ"));
+}
+
+#[test]
+fn test_validate_count_is_invalid_argument() {
+    assert!(matches!(validate_count(0), Err(Error::InvalidArgument(_))));
+}
+
+#[test]
+fn test_generate_names_from_library() {
+    let names = generate_names(3, 0.0, VariancePattern::ALL, &mut create_rng(Some(1)));
+    assert_eq!(names.len(), 3);
+}
+
+#[test]
+fn test_load_addresses_from_csv_same_seed_same_sample() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("input.csv");
+    let mut file = File::create(&path).unwrap();
+    writeln!(file, "address1,city,state,zip").unwrap();
+    for i in 0..20 {
+        writeln!(file, "{} Main St,Springfield,IL,62701", i).unwrap();
+    }
+    let path = path.to_str().unwrap();
+
+    let first = load_addresses_from_csv(path, Some(5), &mut create_rng(Some(3))).unwrap();
+    let second = load_addresses_from_csv(path, Some(5), &mut create_rng(Some(3))).unwrap();
+    assert_eq!(first.addresses, second.addresses);
+}
+
+#[test]
+fn test_address_reader_streams_records() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("input.csv");
+    let mut file = File::create(&path).unwrap();
+    writeln!(file, "number|street|city|state|zip").unwrap();
+    writeln!(file, "1|Main St|Springfield|IL|62701").unwrap();
+    writeln!(file, "||||").unwrap();
+    writeln!(file, "2|Oak Ave|Chicago|IL|60601").unwrap();
+
+    let reader = AddressReader::open(path.to_str().unwrap()).unwrap();
+    assert!(reader.warnings().is_empty());
+    let streets: Vec<String> = reader.map(|a| a.unwrap().address1).collect();
+    assert_eq!(streets, ["1 Main St", "2 Oak Ave"]);
+}